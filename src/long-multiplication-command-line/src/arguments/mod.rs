@@ -1,5 +1,23 @@
-use clap::{Arg, ArgMatches, command};
+use std::fmt;
+use std::io::{self, BufRead, IsTerminal};
 
+use clap::{Arg, ArgAction, ArgMatches, command, value_parser};
+
+use crate::breakdown::{compute_product_with_options, normalize_operand, sanitize_operand};
+use crate::length::{digit_count, get_string_length, get_strings_length};
+use crate::multiplication::{self, MAX_DIGITS, MultiplicationError, table_dimensions_with_options};
+use crate::options::{
+    CELL_WIDTH, COMPACT_CELL_WIDTH, CarryStyle, ColorScheme, DigitSet, Footer, Labels, LayoutConfig, ProductAlign, RowFilter, RowOrder, SumVerbosity, TableOptions, TableStyle, TopOperand,
+};
+
+/// An operand parsed by `parse_signed` and `parse_decimal`: whether
+/// it is negative, its digits and its number of fractional digits.
+type SignedOperand = (bool, String, usize);
+
+/// A line skipped by `read_batch`: its number and its error.
+pub type SkippedLine = (usize, OperandError);
+
+#[derive(Clone, Debug)]
 pub struct Args {
     // The first coefficient of the multiplication.
     pub multiplicand: String,
@@ -7,11 +25,324 @@ pub struct Args {
     // The second coefficient of the multiplication.
     pub multiplier: String,
 
+    // The coefficients after the multiplier, multiplied in a chain.
+    pub operands: Vec<String>,
+
+    // The multiplicand is negative.
+    pub multiplicand_negative: bool,
+
+    // The multiplier is negative.
+    pub multiplier_negative: bool,
+
+    // The fractional digits of the multiplicand.
+    pub multiplicand_decimals: usize,
+
+    // The fractional digits of the multiplier.
+    pub multiplier_decimals: usize,
+
     // The output method.
     pub output: String,
 
+    // The format of the table: text, HTML, JSON, LaTeX, Markdown or SVG.
+    pub format: String,
+
+    // The multiplication method: the long (stacked) table, the lattice, the peasant, Karatsuba or plain.
+    pub method: String,
+
+    // When the displayed table is colored: 'auto', 'always' or 'never'.
+    pub color: String,
+
+    // The glyphs of the digits in the cells: 'ascii' or 'arabic'.
+    pub digits: String,
+
+    // The language of the labels and the symbols legend: 'en' or 'es'.
+    pub lang: String,
+
     // The file name and path of the output file.
     pub file: String,
+
+    // Replace the author footer with the statistics footer.
+    pub stats_footer: bool,
+
+    // Drop the author footer.
+    pub no_author: bool,
+
+    // Hide the symbols legend.
+    pub no_symbols: bool,
+
+    // Keep only the legend lines of the sections in the table.
+    pub trim_symbols: bool,
+
+    // Draw the borders with ASCII characters only.
+    pub ascii: bool,
+
+    // The base of the digits of the operands.
+    pub base: u32,
+
+    // The line ending of the output file.
+    pub eol: String,
+
+    // Prepend the UTF-8 byte-order mark to the output file.
+    pub bom: bool,
+
+    // Append to the output file instead of emptying it.
+    pub append: bool,
+
+    // Create the missing parent directories of the output file.
+    pub mkdir: bool,
+
+    // The alignment of the product row.
+    pub product_align: String,
+
+    // The width of each cell, in characters.
+    pub cell_width: Option<usize>,
+
+    // Draw the cells a single character wide.
+    pub compact: bool,
+
+    // The maximum width of the table, in characters.
+    pub max_width: Option<usize>,
+
+    // The number of columns of the product row.
+    pub pad_product_to: Option<usize>,
+
+    // The title centered above the table.
+    pub title: Option<String>,
+
+    // Draw a guide every third column of the product row.
+    pub group_guides: bool,
+
+    // Use a JavaScript object instead of a Python dictionary for 'pyliteral'.
+    pub js: bool,
+
+    // The number of significant digits of the product annotation.
+    pub product_sig: Option<usize>,
+
+    // The partial-product rows displayed: 'all', 'odd' or 'even'.
+    pub rows: String,
+
+    // The order of the partial-product rows: 'lsd' or 'msd'.
+    pub order: String,
+
+    // The maximum number of partial-product rows displayed.
+    pub max_rows: Option<usize>,
+
+    // The position of the column highlighted in the terminal colors.
+    pub highlight_column: Option<usize>,
+
+    // The display of the carries: 'row' or 'superscript'.
+    pub carry_style: String,
+
+    // The operand on the first row of the multiplication block: 'multiplicand' or 'multiplier'.
+    pub top: String,
+
+    // Hide the position header.
+    pub no_position_header: bool,
+
+    // Hide the sum section.
+    pub no_sum_section: bool,
+
+    // The rows of the sum section: 'full' or 'compact'.
+    pub sum: String,
+
+    // List the place value of each column.
+    pub place_values: bool,
+
+    // Display the number of teaching steps.
+    pub step_count: bool,
+
+    // Recompute the product from the partial products.
+    pub verify: bool,
+
+    // List each product of the operations section.
+    pub explain: bool,
+
+    // Write the product in words below the table.
+    pub words: bool,
+
+    // Use the operand with fewer digits as the multiplier.
+    pub optimize_layout: bool,
+
+    // The number of digits above which only the product is displayed.
+    pub auto_answer_threshold: usize,
+
+    // The maximum number of digits of an operand.
+    pub max_digits: usize,
+
+    // Display only the digits of the product, without the table.
+    pub quiet: bool,
+
+    // Display a summary of the table instead of generating it.
+    pub dry_run: bool,
+
+    // The last multiplier of the times table of the multiplicand.
+    pub times_table: Option<usize>,
+
+    // Display the table of each product of the times table.
+    pub times_grids: bool,
+
+    // The digits of the random multiplicand and multiplier.
+    pub random: Option<(usize, usize)>,
+
+    // The number of random problems.
+    pub count: usize,
+
+    // The seed of the random problems.
+    pub seed: Option<u64>,
+
+    // The file with a multiplicand and a multiplier on each line.
+    pub batch: Option<String>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        return Args {
+            multiplicand: String::from("0"),
+            multiplier: String::from("0"),
+            operands: Vec::new(),
+            multiplicand_negative: false,
+            multiplier_negative: false,
+            multiplicand_decimals: 0,
+            multiplier_decimals: 0,
+            output: String::from("display"),
+            format: String::from("text"),
+            method: String::from("long"),
+            color: String::from("auto"),
+            digits: String::from("ascii"),
+            lang: String::from("en"),
+            file: String::from("long-multiplication-output.txt"),
+            stats_footer: false,
+            no_author: false,
+            no_symbols: false,
+            trim_symbols: false,
+            ascii: false,
+            base: 10,
+            eol: String::from("lf"),
+            bom: false,
+            append: false,
+            mkdir: false,
+            product_align: String::from("right"),
+            cell_width: None,
+            compact: false,
+            max_width: None,
+            pad_product_to: None,
+            title: None,
+            group_guides: false,
+            js: false,
+            product_sig: None,
+            rows: String::from("all"),
+            order: String::from("lsd"),
+            max_rows: None,
+            highlight_column: None,
+            carry_style: String::from("row"),
+            top: String::from("multiplicand"),
+            no_position_header: false,
+            no_sum_section: false,
+            sum: String::from("full"),
+            place_values: false,
+            step_count: false,
+            verify: false,
+            explain: false,
+            words: false,
+            optimize_layout: false,
+            auto_answer_threshold: 200,
+            max_digits: MAX_DIGITS,
+            quiet: false,
+            dry_run: false,
+            times_table: None,
+            times_grids: false,
+            random: None,
+            count: 1,
+            seed: None,
+            batch: None,
+        };
+    }
+}
+
+/// The error returned when the operands cannot be resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OperandError {
+    // Only one operand was given in the command line.
+    Ambiguous,
+
+    // The standard input does not hold exactly two values.
+    Stdin(usize),
+
+    // A line of the batch file does not hold exactly two values.
+    BatchLine(usize),
+
+    // The operand is rejected by `multiplication::validate`.
+    Invalid(MultiplicationError),
+
+    // The chained operands are signed, decimal or not in base ten.
+    Chain,
+
+    // The signed or decimal operands are given to an output that draws only whole numbers.
+    Unsigned(&'static str),
+
+    // The operands are not in base ten for an output that draws only base ten.
+    Radix(&'static str),
+
+    // The option is given to a mode that draws its own tables, like the practice sheet.
+    Mode(&'static str, &'static str),
+}
+
+impl fmt::Display for OperandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            OperandError::Ambiguous => write!(
+                f, "ERROR: give both the multiplicand and the multiplier, or none to read them from the standard input."
+            ),
+            OperandError::Stdin(found) => write!(
+                f, "ERROR: the standard input must hold the multiplicand and the multiplier, found {found} values."
+            ),
+            OperandError::BatchLine(found) => write!(
+                f, "ERROR: the line must hold the multiplicand and the multiplier, found {found} values."
+            ),
+            OperandError::Invalid(error) => write!(f, "{error}"),
+            OperandError::Chain => write!(
+                f, "ERROR: more than two operands must be whole base-10 numbers without a sign."
+            ),
+            OperandError::Unsigned(output) => write!(
+                f, "ERROR: the {output} only draws whole numbers without a sign."
+            ),
+            OperandError::Radix(output) => write!(
+                f, "ERROR: the {output} only draws base-10 numbers."
+            ),
+            OperandError::Mode(option, mode) => write!(
+                f, "ERROR: the {option} is not supported with the {mode}."
+            ),
+        };
+    }
+}
+
+/// The error returned when the layout arguments are in conflict.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutError {
+    // The cell width is smaller than three characters.
+    CellWidth(usize),
+
+    // The padded product is narrower than the natural product.
+    PadProductTo { pad: usize, width: usize },
+
+    // The maximum width cannot hold a single row of the table.
+    MaxWidth { max: usize, row: usize },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            LayoutError::CellWidth(width) => write!(
+                f, "ERROR: '--cell-width' must be at least 3, found '{width}'."
+            ),
+            LayoutError::PadProductTo { pad, width } => write!(
+                f, "ERROR: '--pad-product-to' must be at least the product width {width}, found '{pad}'."
+            ),
+            LayoutError::MaxWidth { max, row } => write!(
+                f, "ERROR: '--max-width' must be at least the row width {row}, found '{max}'."
+            ),
+        };
+    }
 }
 
 pub fn get_args() -> Args {
@@ -22,13 +353,21 @@ pub fn get_args() -> Args {
         )
         .arg(
             Arg::new("multiplicand")
-                .required(true)
-                .help("The first coefficient of the multiplication.")
+                .required(false)
+                .allow_negative_numbers(true)
+                .help("The first coefficient of the multiplication. Read from the standard input if missing.")
         )
         .arg(
             Arg::new("multiplier")
-                .required(true)
-                .help("The second coefficient of the multiplication.")
+                .required(false)
+                .allow_negative_numbers(true)
+                .help("The second coefficient of the multiplication. Read from the standard input if missing.")
+        )
+        .arg(
+            Arg::new("operands")
+                .required(false)
+                .num_args(1..)
+                .help("More coefficients, each one multiplied by the product of the previous table.")
         )
         .arg(
             Arg::new("output")
@@ -36,7 +375,47 @@ pub fn get_args() -> Args {
                 .long("output")
                 .required(false)
                 .default_value("display")
-                .help("The options are: 'display', 'store' or 'both'.")
+                .help("The options are: 'display', 'store', 'both', 'pyliteral' or 'mdcode'.")
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .required(false)
+                .default_value("text")
+                .value_parser(["text", "html", "json", "latex", "markdown", "svg", "tsv"])
+                .help("The format of the table: 'text', 'html', 'json', 'latex', 'markdown', 'svg' or 'tsv'.")
+        )
+        .arg(
+            Arg::new("method")
+                .long("method")
+                .required(false)
+                .default_value("long")
+                .value_parser(["long", "lattice", "peasant", "karatsuba", "plain"])
+                .help("The multiplication method: 'long' (the stacked table), 'lattice' (the diagonal grid), 'peasant' (doubling and halving), 'karatsuba' (the three sub-products) or 'plain' (the stacked table without the grid).")
+        )
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .required(false)
+                .default_value("auto")
+                .value_parser(["auto", "always", "never"])
+                .help("Color the carries, the product and the borders: 'auto' (in a terminal), 'always' or 'never'.")
+        )
+        .arg(
+            Arg::new("digits")
+                .long("digits")
+                .required(false)
+                .default_value("ascii")
+                .value_parser(["ascii", "arabic"])
+                .help("The glyphs of the digits in the cells: 'ascii' or 'arabic' (Arabic-Indic).")
+        )
+        .arg(
+            Arg::new("lang")
+                .long("lang")
+                .required(false)
+                .default_value("en")
+                .value_parser(["en", "es"])
+                .help("The language of the labels and the symbols legend: 'en' (English) or 'es' (Spanish).")
         )
         .arg(
             Arg::new("file")
@@ -46,14 +425,1063 @@ pub fn get_args() -> Args {
                 .default_value("long-multiplication-output.txt")
                 .help("The file name and path of the output file.")
         )
+        .arg(
+            Arg::new("stats_footer")
+                .long("stats-footer")
+                .action(ArgAction::SetTrue)
+                .help("Replace the author footer with the statistics of the table.")
+        )
+        .arg(
+            Arg::new("no_author")
+                .long("no-author")
+                .action(ArgAction::SetTrue)
+                .help("Drop the author footer below the table.")
+        )
+        .arg(
+            Arg::new("base")
+                .long("base")
+                .required(false)
+                .default_value("10")
+                .value_parser(value_parser!(u32).range(2..=16))
+                .help("The base of the digits of the operands, from '2' to '16'.")
+        )
+        .arg(
+            Arg::new("no_symbols")
+                .long("no-symbols")
+                .action(ArgAction::SetTrue)
+                .help("Hide the symbols legend above the table.")
+        )
+        .arg(
+            Arg::new("trim_symbols")
+                .long("trim-symbols")
+                .action(ArgAction::SetTrue)
+                .help("Keep only the legend lines of the sections in the table, like 'Sub n.'.")
+        )
+        .arg(
+            Arg::new("ascii")
+                .long("ascii")
+                .action(ArgAction::SetTrue)
+                .help("Draw the borders with '+', '-' and '|' instead of the box-drawing characters.")
+        )
+        .arg(
+            Arg::new("eol")
+                .long("eol")
+                .visible_alias("line-ending")
+                .required(false)
+                .default_value("lf")
+                .value_parser(["lf", "crlf"])
+                .help("The line ending of the output file: 'lf' or 'crlf'.")
+        )
+        .arg(
+            Arg::new("bom")
+                .long("bom")
+                .action(ArgAction::SetTrue)
+                .help("Prepend the UTF-8 byte-order mark to the output file, for the Windows editors.")
+        )
+        .arg(
+            Arg::new("append")
+                .long("append")
+                .action(ArgAction::SetTrue)
+                .help("Append the table to the output file instead of replacing its content.")
+        )
+        .arg(
+            Arg::new("mkdir")
+                .long("mkdir")
+                .action(ArgAction::SetTrue)
+                .help("Create the missing parent directories of the output file.")
+        )
+        .arg(
+            Arg::new("product_align")
+                .long("product-align")
+                .required(false)
+                .default_value("right")
+                .value_parser(["left", "right"])
+                .help("The alignment of the product row: 'left' or 'right'.")
+        )
+        .arg(
+            Arg::new("cell_width")
+                .long("cell-width")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("The width of each cell, in characters.")
+        )
+        .arg(
+            Arg::new("compact")
+                .long("compact")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("cell_width")
+                .help("Draw the cells a single character wide, for the big operands.")
+        )
+        .arg(
+            Arg::new("max_width")
+                .long("max-width")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("The maximum width of the table, in characters. The row labels, the legend and the notes beyond it are cut.")
+        )
+        .arg(
+            Arg::new("pad_product_to")
+                .long("pad-product-to")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("The number of columns of the product row; the other sections are widened to match.")
+        )
+        .arg(
+            Arg::new("title")
+                .long("title")
+                .required(false)
+                .help("The title centered above the table.")
+        )
+        .arg(
+            Arg::new("group_guides")
+                .long("group-guides")
+                .action(ArgAction::SetTrue)
+                .help("Draw a guide every third column of the product row.")
+        )
+        .arg(
+            Arg::new("js")
+                .long("js")
+                .action(ArgAction::SetTrue)
+                .help("Display a JavaScript object instead of a Python dictionary for 'pyliteral'.")
+        )
+        .arg(
+            Arg::new("product_sig")
+                .long("product-sig")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("Annotate the first n significant digits of the product below the table.")
+        )
+        .arg(
+            Arg::new("rows")
+                .long("rows")
+                .required(false)
+                .default_value("all")
+                .value_parser(["all", "odd", "even"])
+                .help("The partial-product rows displayed: 'all', 'odd' or 'even'.")
+        )
+        .arg(
+            Arg::new("order")
+                .long("order")
+                .required(false)
+                .default_value("lsd")
+                .value_parser(["lsd", "msd"])
+                .help("The order of the partial-product rows: 'lsd' (the last digit of the multiplier first) or 'msd' (the first digit first).")
+        )
+        .arg(
+            Arg::new("max_rows")
+                .long("max-rows")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("Display the first n partial-product rows and a '… (m more rows)' marker for the rest, or '…+m' when the table is narrower.")
+        )
+        .arg(
+            Arg::new("highlight_column")
+                .long("highlight-column")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("Tint the cells of the position n in every section, with the terminal colors.")
+        )
+        .arg(
+            Arg::new("carry_style")
+                .long("carry-style")
+                .required(false)
+                .default_value("row")
+                .value_parser(["row", "superscript"])
+                .help("The display of the carries: 'row' (a row above each row of units) or 'superscript' (next to the unit on their left).")
+        )
+        .arg(
+            Arg::new("top")
+                .long("top")
+                .required(false)
+                .default_value("multiplicand")
+                .value_parser(["multiplicand", "multiplier"])
+                .help("The operand on the first row of the multiplication block: 'multiplicand' or 'multiplier'.")
+        )
+        .arg(
+            Arg::new("no_position_header")
+                .long("no-position-header")
+                .action(ArgAction::SetTrue)
+                .help("Hide the position header of the table.")
+        )
+        .arg(
+            Arg::new("no_sum_section")
+                .long("no-sum-section")
+                .action(ArgAction::SetTrue)
+                .help("Hide the sum section of the table.")
+        )
+        .arg(
+            Arg::new("sum")
+                .long("sum")
+                .required(false)
+                .default_value("full")
+                .value_parser(["full", "compact"])
+                .help("The rows of the sum section: 'full' (one row per column) or 'compact' (one row per place).")
+        )
+        .arg(
+            Arg::new("place_values")
+                .long("place-values")
+                .action(ArgAction::SetTrue)
+                .help("List the place value of each column: ones, tens, hundreds, etc.")
+        )
+        .arg(
+            Arg::new("step_count")
+                .long("step-count")
+                .action(ArgAction::SetTrue)
+                .help("Display the number of teaching steps below the table.")
+        )
+        .arg(
+            Arg::new("verify")
+                .long("verify")
+                .action(ArgAction::SetTrue)
+                .help("Recompute the base-ten product from the partial products and mark it with ✓ or ✗.")
+        )
+        .arg(
+            Arg::new("explain")
+                .long("explain")
+                .action(ArgAction::SetTrue)
+                .help("List each base-ten product of the operations with its unit and carry, like 'row 1: 7×6=42 (unit 2, carry 4)'.")
+        )
+        .arg(
+            Arg::new("words")
+                .long("words")
+                .action(ArgAction::SetTrue)
+                .help("Write the base-ten whole product in English words below the table, like 'Product in words: three hundred thirty-eight'.")
+        )
+        .arg(
+            Arg::new("optimize_layout")
+                .long("optimize-layout")
+                .action(ArgAction::SetTrue)
+                .help("Use the operand with fewer digits as the multiplier, to draw fewer rows.")
+        )
+        .arg(
+            Arg::new("auto_answer_threshold")
+                .long("auto-answer-threshold")
+                .required(false)
+                .default_value("200")
+                .value_parser(value_parser!(usize))
+                .help("Display only the product when an operand has more digits than this.")
+        )
+        .arg(
+            Arg::new("max_digits")
+                .long("max-digits")
+                .required(false)
+                .default_value("500")
+                .value_parser(value_parser!(usize))
+                .help("Reject the operands with more digits than this, before the table is generated.")
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .action(ArgAction::SetTrue)
+                .help("Display only the digits of the product, without the table.")
+        )
+        .arg(
+            Arg::new("dry_run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .help("Display the operands, the output and the size of the table, without generating it.")
+        )
+        .arg(
+            Arg::new("times_table")
+                .long("times-table")
+                .required(false)
+                .value_parser(value_parser!(usize))
+                .help("Display the times table of the multiplicand up to n; the multiplier is not used.")
+        )
+        .arg(
+            Arg::new("times_grids")
+                .long("times-grids")
+                .action(ArgAction::SetTrue)
+                .help("Display the table of each product of '--times-table'.")
+        )
+        .arg(
+            Arg::new("random")
+                .long("random")
+                .required(false)
+                .num_args(0..=1)
+                .value_parser(parse_random_digits)
+                .help("Generate random problems with the given digits, e.g. '3x2', or '3' for '3x3'. Without a value, the problems have '--random-digits' by '--random-digits' digits.")
+        )
+        .arg(
+            Arg::new("random_digits")
+                .long("random-digits")
+                .required(false)
+                .requires("random")
+                .default_value("3")
+                .value_parser(value_parser!(u64).range(1..))
+                .help("The digits of both operands of the random problems, for '--random' without a value.")
+        )
+        .arg(
+            Arg::new("count")
+                .long("count")
+                .required(false)
+                .default_value("1")
+                .value_parser(value_parser!(usize))
+                .help("The number of random problems.")
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .required(false)
+                .value_parser(value_parser!(u64))
+                .help("The seed of the random problems, to reproduce them.")
+        )
+        .arg(
+            Arg::new("batch")
+                .long("batch")
+                .required(false)
+                .conflicts_with_all(["random", "times_table"])
+                .help("The file with a multiplicand and a multiplier on each line, to display all their tables.")
+        )
         .get_matches();
 
-    let multiplicand: String = unwrap_args(&matches, "multiplicand", false);
-    let multiplier: String = unwrap_args(&matches, "multiplier", false);
+    // The bare `--random` draws square problems of `--random-digits`.
+    let random_digits: usize = *matches.get_one::<u64>("random_digits").unwrap() as usize;
+    let random: Option<(usize, usize)> = match matches.contains_id("random") {
+        true => Some(matches.get_one::<(usize, usize)>("random").copied().unwrap_or((random_digits, random_digits))),
+        false => None,
+    };
+    let times_table: Option<usize> = matches.get_one::<usize>("times_table").copied();
+    let batch: Option<String> = matches.get_one::<String>("batch").cloned();
+    let max_digits: usize = *matches.get_one::<usize>("max_digits").unwrap();
+    let operands: Result<(String, String), OperandError> = if let Some((multiplicand_digits, multiplier_digits)) = random {
+        // The random operands are drawn later, so only their number of digits is checked.
+        let digits: usize = multiplicand_digits.max(multiplier_digits);
+        match digits > max_digits {
+            true => Err(OperandError::Invalid(MultiplicationError::TooManyDigits { limit: max_digits, digits })),
+            false => Ok((String::new(), String::new())),
+        }
+    } else if batch.is_some() {
+        Ok((String::new(), String::new()))
+    } else if times_table.is_some() {
+        resolve_operands(
+            matches.get_one::<String>("multiplicand").cloned(),
+            Some(String::from("1")),
+            io::empty(),
+        )
+    } else if io::stdin().is_terminal() {
+        resolve_operands(
+            matches.get_one::<String>("multiplicand").cloned(),
+            matches.get_one::<String>("multiplier").cloned(),
+            io::empty(),
+        )
+    } else {
+        resolve_operands(
+            matches.get_one::<String>("multiplicand").cloned(),
+            matches.get_one::<String>("multiplier").cloned(),
+            io::stdin().lock(),
+        )
+    };
+    let base: u32 = *matches.get_one::<u32>("base").unwrap();
+    let operands: Result<(SignedOperand, SignedOperand), OperandError> = operands.and_then(|(multiplicand, multiplier)| {
+        // The grouping separators, like `1,234`, are removed first.
+        let (multiplicand_negative, multiplicand): (bool, String) = parse_signed(&sanitize_operand(&multiplicand));
+        let (multiplier_negative, multiplier): (bool, String) = parse_signed(&sanitize_operand(&multiplier));
+        let (multiplicand, multiplicand_decimals): (String, usize) = parse_decimal(&multiplicand);
+        let (multiplier, multiplier_decimals): (String, usize) = parse_decimal(&multiplier);
+        if random.is_none() && batch.is_none() {
+            validate_operands_with_radix(&multiplicand, &multiplier, base)?;
+            multiplication::validate_max_digits(&multiplicand, &multiplier, max_digits).map_err(OperandError::Invalid)?;
+        }
+
+        // The leading zero of a fraction like `0.1` is kept, so it has a column.
+        let mut multiplicand: String = multiplicand.to_ascii_uppercase();
+        if multiplicand_decimals == 0 {
+            multiplicand = normalize_operand(&multiplicand);
+        }
+        let mut multiplier: String = multiplier.to_ascii_uppercase();
+        if multiplier_decimals == 0 {
+            multiplier = normalize_operand(&multiplier);
+        }
+        return Ok((
+            (multiplicand_negative, multiplicand, multiplicand_decimals),
+            (multiplier_negative, multiplier, multiplier_decimals),
+        ));
+    });
+    let (
+        (multiplicand_negative, multiplicand, multiplicand_decimals),
+        (multiplier_negative, multiplier, multiplier_decimals),
+    ): (SignedOperand, SignedOperand) = match operands {
+        Ok(operands) => operands,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    };
+    // The operands after the multiplier are multiplied in a chain, see `get_table_chain`.
+    let operands: Vec<String> = matches.get_many::<String>("operands")
+        .map_or(Vec::new(), |values| values.map(|value| normalize_operand(&sanitize_operand(value))).collect());
+    if !operands.is_empty() {
+        let signed: bool = multiplicand_negative || multiplier_negative || multiplicand_decimals + multiplier_decimals > 0;
+        let chain: Result<(), OperandError> = match signed || base != 10 {
+            true => Err(OperandError::Chain),
+            false => operands.iter().try_for_each(|operand| {
+                validate_operands(&multiplier, operand)?;
+                return multiplication::validate_max_digits(&multiplier, operand, max_digits).map_err(OperandError::Invalid);
+            }),
+        };
+        if let Err(error) = chain {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    }
     let output: String = unwrap_args(&matches, "output", true);
+    let format: String = unwrap_args(&matches, "format", true);
+    let method: String = unwrap_args(&matches, "method", true);
+    let color: String = unwrap_args(&matches, "color", true);
+    let digits: String = unwrap_args(&matches, "digits", true);
+    let lang: String = unwrap_args(&matches, "lang", true);
     let file: String = unwrap_args(&matches, "file", true);
+    let stats_footer: bool = matches.get_flag("stats_footer");
+    let no_author: bool = matches.get_flag("no_author");
+    let no_symbols: bool = matches.get_flag("no_symbols");
+    let trim_symbols: bool = matches.get_flag("trim_symbols");
+    let ascii: bool = matches.get_flag("ascii");
+    let eol: String = unwrap_args(&matches, "eol", true);
+    let bom: bool = matches.get_flag("bom");
+    let append: bool = matches.get_flag("append");
+    let mkdir: bool = matches.get_flag("mkdir");
+    let product_align: String = unwrap_args(&matches, "product_align", true);
+    let cell_width: Option<usize> = matches.get_one::<usize>("cell_width").copied();
+    let compact: bool = matches.get_flag("compact");
+    let max_width: Option<usize> = matches.get_one::<usize>("max_width").copied();
+    let pad_product_to: Option<usize> = matches.get_one::<usize>("pad_product_to").copied();
+    let title: Option<String> = matches.get_one::<String>("title").cloned();
+    let group_guides: bool = matches.get_flag("group_guides");
+    let js: bool = matches.get_flag("js");
+    let product_sig: Option<usize> = matches.get_one::<usize>("product_sig").copied();
+    let rows: String = unwrap_args(&matches, "rows", true);
+    let order: String = unwrap_args(&matches, "order", true);
+    let max_rows: Option<usize> = matches.get_one::<usize>("max_rows").copied();
+    let highlight_column: Option<usize> = matches.get_one::<usize>("highlight_column").copied();
+    let carry_style: String = unwrap_args(&matches, "carry_style", true);
+    let top: String = unwrap_args(&matches, "top", true);
+    let no_position_header: bool = matches.get_flag("no_position_header");
+    let no_sum_section: bool = matches.get_flag("no_sum_section");
+    let sum: String = unwrap_args(&matches, "sum", true);
+    let place_values: bool = matches.get_flag("place_values");
+    let step_count: bool = matches.get_flag("step_count");
+    let verify: bool = matches.get_flag("verify");
+    let explain: bool = matches.get_flag("explain");
+    let words: bool = matches.get_flag("words");
+    let optimize_layout: bool = matches.get_flag("optimize_layout");
+    let auto_answer_threshold: usize = *matches.get_one::<usize>("auto_answer_threshold").unwrap();
+    let quiet: bool = matches.get_flag("quiet");
+    let dry_run: bool = matches.get_flag("dry_run");
+    let times_grids: bool = matches.get_flag("times_grids");
+    let count: usize = *matches.get_one::<usize>("count").unwrap();
+    let seed: Option<u64> = matches.get_one::<u64>("seed").copied();
+
+    let args: Args = Args {
+        multiplicand,
+        multiplier,
+        operands,
+        multiplicand_negative,
+        multiplier_negative,
+        multiplicand_decimals,
+        multiplier_decimals,
+        output,
+        format,
+        method,
+        color,
+        digits,
+        lang,
+        file,
+        stats_footer,
+        no_author,
+        no_symbols,
+        trim_symbols,
+        ascii,
+        base,
+        eol,
+        bom,
+        append,
+        mkdir,
+        product_align,
+        cell_width,
+        compact,
+        max_width,
+        pad_product_to,
+        title,
+        group_guides,
+        js,
+        product_sig,
+        rows,
+        order,
+        max_rows,
+        highlight_column,
+        carry_style,
+        top,
+        no_position_header,
+        no_sum_section,
+        sum,
+        place_values,
+        step_count,
+        verify,
+        explain,
+        words,
+        optimize_layout,
+        auto_answer_threshold,
+        max_digits,
+        quiet,
+        dry_run,
+        times_table,
+        times_grids,
+        random,
+        count,
+        seed,
+        batch,
+    };
+    if let Err(error) = validate_support(&args) {
+        eprintln!("{error}");
+        std::process::exit(2);
+    }
+    return args;
+}
+
+/// Validate that the output draws the kind of operands given.
+///
+/// Some outputs only draw whole numbers without a sign, or only
+/// base-10 numbers, so the other operands are rejected for them,
+/// instead of dropping the sign, the decimal point or the base
+/// silently.
+/// - The `tsv` format is a grid of digits.
+/// - The `peasant`, `plain` and `karatsuba` methods work on the
+///   whole numbers.
+/// - The `pyliteral` output, the times table, the practice sheet,
+///   the batch file, the `--verify` row and the methods other
+///   than `long` and `lattice` only draw base-10 numbers.
+/// - The practice sheet of `--random`, the batch file and the
+///   chain of more than two operands draw the text tables of the
+///   long method, so the other formats and methods are rejected
+///   for them, like `--quiet` but for the chain, which prints
+///   the product of all operands with it.
+/// - The summary of `--dry-run` describes a single table, so it
+///   is rejected for the times table and the modes above.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{Args, OperandError, validate_support};
+/// let args: Args = Args {
+///     format: String::from("tsv"),
+///     multiplicand_negative: true,
+///     ..Args::default()
+/// };
+///
+/// assert_eq!(Err(OperandError::Unsigned("'tsv' format")), validate_support(&args));
+/// ```
+pub fn validate_support(args: &Args) -> Result<(), OperandError> {
+    let method: Option<&'static str> = match args.method.as_str() {
+        "peasant" => Some("'peasant' method"),
+        "plain" => Some("'plain' method"),
+        "karatsuba" => Some("'karatsuba' method"),
+        _ => None,
+    };
+    let signed: bool = args.multiplicand_negative || args.multiplier_negative
+        || args.multiplicand_decimals + args.multiplier_decimals > 0;
+
+    if signed && args.format == "tsv" {
+        return Err(OperandError::Unsigned("'tsv' format"));
+    }
+    if let Some(method) = method.filter(|_| signed) {
+        return Err(OperandError::Unsigned(method));
+    }
+
+    let mode: Option<&'static str> = match (&args.random, &args.batch) {
+        (Some(_), _) => Some("practice sheet"),
+        (None, Some(_)) => Some("batch file"),
+        (None, None) if !args.operands.is_empty() => Some("chain of operands"),
+        (None, None) => None,
+    };
+    if let Some(mode) = mode {
+        if args.format != "text" {
+            return Err(OperandError::Mode("'--format' option", mode));
+        }
+        if args.method != "long" {
+            return Err(OperandError::Mode("'--method' option", mode));
+        }
+        if args.quiet && args.operands.is_empty() {
+            return Err(OperandError::Mode("'--quiet' option", mode));
+        }
+    }
+    if args.dry_run {
+        if args.times_table.is_some() {
+            return Err(OperandError::Mode("'--dry-run' option", "times table"));
+        }
+        if let Some(mode) = mode {
+            return Err(OperandError::Mode("'--dry-run' option", mode));
+        }
+    }
+
+    if args.base != 10 {
+        if args.output == "pyliteral" {
+            return Err(OperandError::Radix("'pyliteral' output"));
+        }
+        if args.times_table.is_some() {
+            return Err(OperandError::Radix("times table"));
+        }
+        if args.random.is_some() {
+            return Err(OperandError::Radix("practice sheet"));
+        }
+        if args.batch.is_some() {
+            return Err(OperandError::Radix("batch file"));
+        }
+        if args.verify {
+            return Err(OperandError::Radix("'--verify' row"));
+        }
+        if let Some(method) = method {
+            return Err(OperandError::Radix(method));
+        }
+    }
+    return Ok(());
+}
+
+/// Parse the digits of the random problems.
+///
+/// The value is the number of digits of the multiplicand and
+/// the multiplier separated by an `x`, e.g. `3x2`. A single
+/// number is the digits of both operands, e.g. `3` is `3x3`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::parse_random_digits;
+///
+/// assert_eq!(Ok((3, 2)), parse_random_digits("3x2"));
+/// assert_eq!(Ok((3, 3)), parse_random_digits("3"));
+/// assert!(parse_random_digits("0").is_err());
+/// ```
+pub fn parse_random_digits(value: &str) -> Result<(usize, usize), String> {
+    let error: String = format!("ERROR: '--random' must be like '3x2' or '3', found '{value}'.");
+
+    let value: String = value.to_lowercase();
+    let (multiplicand, multiplier): (&str, &str) = value.split_once('x').unwrap_or((&value, &value));
+    return match (multiplicand.parse::<usize>(), multiplier.parse::<usize>()) {
+        (Ok(a), Ok(b)) if a > 0 && b > 0 => Ok((a, b)),
+        _ => Err(error),
+    };
+}
+
+/// Parse the sign off an operand.
+///
+/// It returns whether the operand is negative and its digits
+/// without the leading `-`, so the absolute value is fed through
+/// the long multiplication. The zero has no sign, so `-0` is not
+/// negative.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::parse_signed;
+///
+/// assert_eq!((true, String::from("12")), parse_signed("-12"));
+/// assert_eq!((false, String::from("34")), parse_signed("34"));
+/// assert_eq!((false, String::from("0")), parse_signed("-0"));
+/// ```
+pub fn parse_signed(value: &str) -> (bool, String) {
+    return match value.strip_prefix('-') {
+        Some(digits) => (!digits.is_empty() && !digits.chars().all(|c| c == '0'), String::from(digits)),
+        None => (false, String::from(value)),
+    };
+}
+
+/// Parse the decimal point off an operand.
+///
+/// It returns the digits of the operand without the decimal
+/// point and its number of fractional digits, so the integer
+/// long multiplication can be used. The leading zeros of the
+/// integer part are removed, but at least one digit is kept.
+/// Only the first point is removed, so an operand with more
+/// than one point fails the validation, like a bare point
+/// without digits.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::parse_decimal;
+///
+/// assert_eq!((String::from("1234"), 2), parse_decimal("12.34"));
+/// assert_eq!((String::from("01"), 1), parse_decimal("0.1"));
+/// assert_eq!((String::from("05"), 1), parse_decimal(".5"));
+/// assert_eq!((String::from("15"), 0), parse_decimal("15"));
+/// assert_eq!((String::from("."), 0), parse_decimal("."));
+/// ```
+pub fn parse_decimal(value: &str) -> (String, usize) {
+    return match value.split_once('.') {
+        Some(("", "")) => (String::from(value), 0),
+        Some((integer, fraction)) => {
+            let integer: &str = integer.trim_start_matches('0');
+            let integer: &str = if integer.is_empty() { "0" } else { integer };
+            (format!("{integer}{fraction}"), fraction.chars().count())
+        }
+        None => (String::from(value), 0),
+    };
+}
 
-    return Args { multiplicand, multiplier, output, file };
+/// Resolve the operands from the command line or the standard input.
+///
+/// The operands given in the command line take precedence.
+/// The standard input is only read when both operands are
+/// missing, and it must hold exactly two values separated
+/// by whitespace: the multiplicand and the multiplier.
+/// Giving only one operand in the command line is an error
+/// because it is ambiguous.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::resolve_operands;
+/// let stdin: &[u8] = b"123 456\n";
+/// let expected: (String, String) = (String::from("123"), String::from("456"));
+///
+/// assert_eq!(Ok(expected), resolve_operands(None, None, stdin));
+/// ```
+pub fn resolve_operands<R: BufRead>(
+    multiplicand: Option<String>,
+    multiplier: Option<String>,
+    mut stdin: R,
+) -> Result<(String, String), OperandError> {
+    match (multiplicand, multiplier) {
+        (Some(multiplicand), Some(multiplier)) => return Ok((multiplicand, multiplier)),
+        (None, None) => {}
+        _ => return Err(OperandError::Ambiguous),
+    }
+
+    let mut content: String = String::new();
+    if stdin.read_to_string(&mut content).is_err() {
+        return Err(OperandError::Stdin(0));
+    }
+
+    let values: Vec<&str> = content.split_whitespace().collect();
+    if values.len() != 2 {
+        return Err(OperandError::Stdin(values.len()));
+    }
+
+    return Ok((values[0].to_string(), values[1].to_string()));
+}
+
+/// Read the pairs of operands of a batch file.
+///
+/// Each line holds the multiplicand and the multiplier separated
+/// by whitespace. The empty lines are ignored, and the malformed
+/// ones are skipped and returned with their line number (starting
+/// at one) and the error, so the rest of the batch is rendered.
+/// The grouping separators, like `1,234`, and the leading zeros
+/// of the operands are removed. The operands are limited to
+/// `MAX_DIGITS`, see `read_batch_with_max_digits`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{OperandError, read_batch};
+/// let file: &[u8] = b"5 7\n12\n\n013 26\n";
+/// let (pairs, skipped) = read_batch(file);
+///
+/// assert_eq!(vec![(String::from("5"), String::from("7")), (String::from("13"), String::from("26"))], pairs);
+/// assert_eq!(vec![(2, OperandError::BatchLine(1))], skipped);
+/// ```
+pub fn read_batch<R: BufRead>(file: R) -> (Vec<(String, String)>, Vec<SkippedLine>) {
+    return read_batch_with_max_digits(file, MAX_DIGITS);
+}
+
+/// Read the pairs of operands of a batch file up to a number of digits.
+///
+/// It works like `read_batch`, but the lines with an operand
+/// longer than `max_digits` are skipped too, like the other
+/// malformed lines, so a single long line does not exhaust the
+/// memory while the batch is rendered.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{OperandError, read_batch_with_max_digits};
+/// use long_multiplication_command_line::MultiplicationError;
+/// let file: &[u8] = b"5 7\n1234 5\n";
+/// let (pairs, skipped) = read_batch_with_max_digits(file, 3);
+///
+/// assert_eq!(vec![(String::from("5"), String::from("7"))], pairs);
+/// assert_eq!(vec![(2, OperandError::Invalid(MultiplicationError::TooManyDigits { limit: 3, digits: 4 }))], skipped);
+/// ```
+pub fn read_batch_with_max_digits<R: BufRead>(file: R, max_digits: usize) -> (Vec<(String, String)>, Vec<SkippedLine>) {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    let mut skipped: Vec<SkippedLine> = Vec::new();
+
+    for (index, line) in file.lines().enumerate() {
+        let line: String = line.unwrap_or_default();
+        let values: Vec<&str> = line.split_whitespace().collect();
+        if values.is_empty() {
+            continue;
+        }
+        if values.len() != 2 {
+            skipped.push((index + 1, OperandError::BatchLine(values.len())));
+            continue;
+        }
+        let multiplicand: String = sanitize_operand(values[0]);
+        let multiplier: String = sanitize_operand(values[1]);
+        let valid: Result<(), OperandError> = validate_operands(&multiplicand, &multiplier).and_then(|()| {
+            multiplication::validate_max_digits(&multiplicand, &multiplier, max_digits).map_err(OperandError::Invalid)
+        });
+        match valid {
+            Ok(()) => pairs.push((normalize_operand(&multiplicand), normalize_operand(&multiplier))),
+            Err(error) => skipped.push((index + 1, error)),
+        }
+    }
+
+    return (pairs, skipped);
+}
+
+/// Validate that the operands contain only digits.
+///
+/// The operands are checked by `multiplication::validate`, so
+/// the command line and the library accept the same input. A
+/// sign, a separator, a space or any other digit, like the
+/// Unicode ones, is rejected.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{OperandError, validate_operands};
+/// use long_multiplication_command_line::MultiplicationError;
+///
+/// assert_eq!(Ok(()), validate_operands("13", "26"));
+/// assert_eq!(
+///     Err(OperandError::Invalid(MultiplicationError::NonDigitCharacter('a'))),
+///     validate_operands("12a", "26"),
+/// );
+/// ```
+pub fn validate_operands(multiplicand: &str, multiplier: &str) -> Result<(), OperandError> {
+    return validate_operands_with_radix(multiplicand, multiplier, 10);
+}
+
+/// Validate that the operands contain only digits of the given base.
+///
+/// It works like `validate_operands`, but for a base (radix)
+/// other than ten, only the digits of that base are accepted,
+/// e.g. `0` and `1` for base two, or `0` to `9` and `A` to `F`
+/// (in either case) for base sixteen.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{OperandError, validate_operands_with_radix};
+/// use long_multiplication_command_line::MultiplicationError;
+///
+/// assert_eq!(Ok(()), validate_operands_with_radix("101", "11", 2));
+/// assert_eq!(
+///     Err(OperandError::Invalid(MultiplicationError::NonDigitCharacter('2'))),
+///     validate_operands_with_radix("101", "12", 2),
+/// );
+/// assert_eq!(Ok(()), validate_operands_with_radix("FF", "2", 16));
+/// ```
+pub fn validate_operands_with_radix(multiplicand: &str, multiplier: &str, radix: u32) -> Result<(), OperandError> {
+    return multiplication::validate_with_radix(multiplicand, multiplier, radix).map_err(OperandError::Invalid);
+}
+
+/// Validate the layout arguments together.
+///
+/// It checks the interdependencies of the numeric layout
+/// arguments and returns the validated layout configuration.
+/// - The cell width must be at least three characters.
+/// - The padded product must be at least the product width.
+/// - The maximum width must hold at least one row of the table.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{Args, LayoutError, validate_layout_opts};
+/// let args: Args = Args {
+///     multiplicand: String::from("13"),
+///     multiplier: String::from("26"),
+///     pad_product_to: Some(3),
+///     ..Args::default()
+/// };
+/// let expected: LayoutError = LayoutError::PadProductTo { pad: 3, width: 4 };
+///
+/// assert_eq!(Err(expected), validate_layout_opts(&args));
+/// ```
+pub fn validate_layout_opts(args: &Args) -> Result<LayoutConfig, LayoutError> {
+    let mut layout: LayoutConfig = LayoutConfig::default();
+    let width: usize = get_strings_length(&args.multiplicand, &args.multiplier);
+
+    if let Some(cell_width) = args.cell_width {
+        if cell_width < CELL_WIDTH {
+            return Err(LayoutError::CellWidth(cell_width));
+        }
+        layout.cell_width = cell_width;
+    }
+    if args.compact {
+        layout.cell_width = COMPACT_CELL_WIDTH;
+    }
+
+    if let Some(pad) = args.pad_product_to {
+        if pad < width {
+            return Err(LayoutError::PadProductTo { pad, width });
+        }
+        layout.pad_product_to = Some(pad);
+    }
+
+    if let Some(max) = args.max_width {
+        let columns: usize = layout.pad_product_to.unwrap_or(width);
+        let cell_width: usize = layout.fit_positions(width).cell_width;
+        // A huge cell width or padding saturates, so it is rejected instead of overflowing.
+        let row: usize = columns.saturating_mul(cell_width.saturating_add(1)).saturating_add(1);
+        if max < row {
+            return Err(LayoutError::MaxWidth { max, row });
+        }
+        layout.max_width = Some(max);
+    }
+
+    let layout: LayoutConfig = layout;
+    return Ok(layout);
+}
+
+/// Get the table options from the command line arguments.
+///
+/// It converts the rendering arguments into the options
+/// used to generate the table of the long multiplication.
+pub fn get_table_options(args: &Args) -> Result<TableOptions, LayoutError> {
+    let mut options: TableOptions = TableOptions {
+        layout: validate_layout_opts(args)?,
+        ..TableOptions::default()
+    };
+
+    if args.stats_footer {
+        options.footer = Footer::Stats;
+    } else if args.no_author {
+        options.footer = Footer::None;
+    }
+
+    if args.product_align == "left" {
+        options.product_align = ProductAlign::Left;
+    }
+
+    options.symbols = !args.no_symbols;
+    options.trim_symbols = args.trim_symbols;
+    options.radix = args.base;
+    options.max_digits = args.max_digits;
+    options.title = args.title.clone();
+    options.group_guides = args.group_guides;
+    options.product_sig = args.product_sig;
+
+    options.rows = match args.rows.as_str() {
+        "odd" => RowFilter::Odd,
+        "even" => RowFilter::Even,
+        _ => RowFilter::All,
+    };
+    if args.order == "msd" {
+        options.order = RowOrder::Msd;
+    }
+    options.max_rows = args.max_rows;
+    if args.carry_style == "superscript" {
+        options.carry_style = CarryStyle::Superscript;
+    }
+    if args.top == "multiplier" {
+        options.top = TopOperand::Multiplier;
+    }
+    options.position_header = !args.no_position_header;
+    options.sum_section = !args.no_sum_section;
+    if args.sum == "compact" {
+        options.sum = SumVerbosity::Compact;
+    }
+    options.place_values = args.place_values;
+    options.step_count = args.step_count;
+    options.verify = args.verify;
+    options.explain = args.explain;
+    options.optimize_layout = args.optimize_layout;
+    options.signs = (args.multiplicand_negative, args.multiplier_negative);
+    options.decimals = (args.multiplicand_decimals, args.multiplier_decimals);
+    if args.ascii {
+        options.style = TableStyle::ascii();
+    }
+    if args.digits == "arabic" {
+        options.digits = DigitSet::ArabicIndic;
+    }
+    if args.lang == "es" {
+        options.labels = Labels::spanish();
+    }
+
+    // Only the displayed table is colored, the stored file stays plain.
+    let displayed: bool = args.output == "display" || args.output == "both";
+    let colored: bool = match args.color.as_str() {
+        "always" => true,
+        "auto" => io::stdout().is_terminal(),
+        _ => false,
+    };
+    if displayed && colored {
+        options.colors = Some(ColorScheme { highlight_column: args.highlight_column, ..ColorScheme::default() });
+    }
+
+    return Ok(options);
+}
+
+/// Get the one-line summary of the table that would be produced.
+///
+/// It describes the operands, their digits, the output target and
+/// the rows and columns of the table, drawn with the options of
+/// `get_table_options`, from `table_dimensions_with_options`,
+/// without generating nor storing the table. When only the
+/// product is printed, with `quiet` or the operands beyond the
+/// `auto_answer_threshold`, it counts the single line of the
+/// product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::arguments::{Args, dry_run_summary};
+/// let args: Args = Args {
+///     multiplicand: String::from("5"),
+///     multiplier: String::from("7"),
+///     ..Args::default()
+/// };
+/// let summary: String = dry_run_summary(&args);
+///
+/// assert!(summary.starts_with("5 x 7: 1 and 1 digits, output display, "));
+/// ```
+pub fn dry_run_summary(args: &Args) -> String {
+    let target: String = match args.output.as_str() {
+        "store" | "both" => format!("{} to '{}'", args.output, args.file),
+        output => output.to_string(),
+    };
+    let options: TableOptions = get_table_options(args).unwrap_or_default();
+    let threshold: usize = args.auto_answer_threshold;
+    let product_only: bool = args.quiet || get_string_length(&args.multiplicand) > threshold || get_string_length(&args.multiplier) > threshold;
+    let (rows, columns): (usize, usize) = if product_only {
+        (1, compute_product_with_options(&args.multiplicand, &args.multiplier, &options).chars().count())
+    } else {
+        table_dimensions_with_options(&args.multiplicand, &args.multiplier, &options)
+    };
+
+    return format!(
+        "{} x {}: {} and {} digits, output {target}, {rows} rows and {columns} columns.",
+        args.multiplicand,
+        args.multiplier,
+        digit_count(&args.multiplicand),
+        digit_count(&args.multiplier),
+    );
 }
 
 fn unwrap_args(matches: &ArgMatches, id: &str, lowercase: bool) -> String {
@@ -65,3 +1493,722 @@ fn unwrap_args(matches: &ArgMatches, id: &str, lowercase: bool) -> String {
 
     return value;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multiplication::table_dimensions;
+
+    // # -----------------------------------------------------------------------
+    // # Function: parse_signed
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_parse_signed_negative() {
+        // Arrange
+        let value: &str = "-12";
+        let expected: (bool, String) = (true, String::from("12"));
+
+        // Action
+        let result: (bool, String) = parse_signed(value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_signed_positive() {
+        // Arrange
+        let value: &str = "34";
+        let expected: (bool, String) = (false, String::from("34"));
+
+        // Action
+        let result: (bool, String) = parse_signed(value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_signed_negative_zero() {
+        // Arrange
+        let value: &str = "-000";
+        let expected: (bool, String) = (false, String::from("000"));
+
+        // Action
+        let result: (bool, String) = parse_signed(value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: parse_decimal
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_parse_decimal_fraction() {
+        // Arrange
+        let value: &str = "12.34";
+        let expected: (String, usize) = (String::from("1234"), 2);
+
+        // Action
+        let result: (String, usize) = parse_decimal(value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_decimal_keeps_one_integer_digit() {
+        // Arrange
+        let value: &str = "000.1";
+        let expected: (String, usize) = (String::from("01"), 1);
+
+        // Action
+        let result: (String, usize) = parse_decimal(value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_decimal_bare_point_is_rejected() {
+        // Arrange
+        let value: &str = ".";
+        let expected: &str = "ERROR: the operands must contain only digits, found '.'.";
+
+        // Action
+        let (digits, _): (String, usize) = parse_decimal(value);
+
+        // Assert
+        assert_eq!(expected, validate_operands(&digits, "7").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_parse_decimal_second_point_is_kept() {
+        // Arrange
+        let value: &str = "1.2.3";
+
+        // Action
+        let (digits, _): (String, usize) = parse_decimal(value);
+
+        // Assert
+        assert!(validate_operands(&digits, &String::from("2")).is_err());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: resolve_operands
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_resolve_operands_from_arguments() {
+        // Arrange
+        let stdin: &[u8] = b"7 8\n";
+        let expected: (String, String) = (String::from("13"), String::from("26"));
+
+        // Action
+        let operands: Result<(String, String), OperandError> = resolve_operands(
+            Some(String::from("13")),
+            Some(String::from("26")),
+            stdin,
+        );
+
+        // Assert
+        assert_eq!(Ok(expected), operands);
+    }
+
+    #[test]
+    fn test_resolve_operands_from_stdin() {
+        // Arrange
+        let stdin: &[u8] = b"  13597\n8642\n";
+        let expected: (String, String) = (String::from("13597"), String::from("8642"));
+
+        // Action
+        let operands: Result<(String, String), OperandError> = resolve_operands(None, None, stdin);
+
+        // Assert
+        assert_eq!(Ok(expected), operands);
+    }
+
+    #[test]
+    fn test_resolve_operands_with_one_argument() {
+        // Arrange
+        let stdin: &[u8] = b"7 8\n";
+
+        // Action
+        let operands: Result<(String, String), OperandError> = resolve_operands(
+            Some(String::from("13")),
+            None,
+            stdin,
+        );
+
+        // Assert
+        assert_eq!(Err(OperandError::Ambiguous), operands);
+    }
+
+    #[test]
+    fn test_resolve_operands_with_invalid_stdin() {
+        // Arrange
+        let stdin: &[u8] = b"1 2 3\n";
+        let expected: &str = "ERROR: the standard input must hold the multiplicand and the multiplier, found 3 values.";
+
+        // Action
+        let operands: Result<(String, String), OperandError> = resolve_operands(None, None, stdin);
+
+        // Assert
+        assert_eq!(Err(OperandError::Stdin(3)), operands);
+        assert_eq!(expected, operands.unwrap_err().to_string());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: read_batch
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_read_batch_pairs() {
+        // Arrange
+        let file: &[u8] = b"5 7\n  13\t26  \n007 3\n";
+        let expected: Vec<(String, String)> = vec![
+            (String::from("5"), String::from("7")),
+            (String::from("13"), String::from("26")),
+            (String::from("7"), String::from("3")),
+        ];
+
+        // Action
+        let (pairs, skipped): (Vec<(String, String)>, Vec<SkippedLine>) = read_batch(file);
+
+        // Assert
+        assert_eq!(expected, pairs);
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_read_batch_skips_the_malformed_lines() {
+        // Arrange
+        let file: &[u8] = b"1 2 3\n5 7\n-1 2\n";
+        let expected: Vec<SkippedLine> = vec![
+            (1, OperandError::BatchLine(3)),
+            (3, OperandError::Invalid(MultiplicationError::NonDigitCharacter('-'))),
+        ];
+
+        // Action
+        let (pairs, skipped): (Vec<(String, String)>, Vec<SkippedLine>) = read_batch(file);
+
+        // Assert
+        assert_eq!(vec![(String::from("5"), String::from("7"))], pairs);
+        assert_eq!(expected, skipped);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: read_batch_with_max_digits
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_read_batch_with_max_digits_skips_the_long_lines() {
+        // Arrange
+        let line: String = format!("{} 7\n5 7\n", "9".repeat(MAX_DIGITS + 200));
+        let expected: Vec<SkippedLine> = vec![
+            (1, OperandError::Invalid(MultiplicationError::TooManyDigits { limit: MAX_DIGITS, digits: MAX_DIGITS + 200 })),
+        ];
+
+        // Action
+        let (pairs, skipped): (Vec<(String, String)>, Vec<SkippedLine>) = read_batch_with_max_digits(line.as_bytes(), MAX_DIGITS);
+
+        // Assert
+        assert_eq!(vec![(String::from("5"), String::from("7"))], pairs);
+        assert_eq!(expected, skipped);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: validate_operands
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_validate_operands_digits() {
+        // Arrange
+        let multiplicand: &str = "13597";
+        let multiplier: &str = "8642";
+
+        // Action
+        let result: Result<(), OperandError> = validate_operands(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_validate_operands_empty() {
+        // Arrange
+        let expected: &str = "ERROR: the multiplicand and the multiplier must not be empty.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_operands("5", "");
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_operands_embedded_space() {
+        // Arrange
+        let expected: &str = "ERROR: the operands must contain only digits, found ' '.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_operands("12 3", "7");
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_operands_unicode_digits() {
+        // Arrange
+        let multiplier: &str = "\u{0663}\u{0664}";
+
+        // Action
+        let result: Result<(), OperandError> = validate_operands("5", multiplier);
+
+        // Assert
+        assert_eq!(
+            Err(OperandError::Invalid(MultiplicationError::NonDigitCharacter('\u{0663}'))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_operands_leading_plus() {
+        // Arrange
+        let expected: &str = "ERROR: the operands must contain only digits, found '+'.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_operands("+12", "3");
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_operands_with_radix_binary() {
+        // Arrange
+        let expected: &str = "ERROR: the operands must contain only digits, found '2'.";
+
+        // Action
+        let valid: Result<(), OperandError> = validate_operands_with_radix("101", "11", 2);
+        let invalid: Result<(), OperandError> = validate_operands_with_radix("102", "11", 2);
+
+        // Assert
+        assert_eq!(Ok(()), valid);
+        assert_eq!(expected, invalid.unwrap_err().to_string());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: dry_run_summary
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_dry_run_summary() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("12345"),
+            multiplier: String::from("678"),
+            output: String::from("both"),
+            file: String::from("table.txt"),
+            ..Args::default()
+        };
+        let (rows, columns): (usize, usize) = table_dimensions("12345", "678");
+        let expected: String = format!(
+            "12345 x 678: 5 and 3 digits, output both to 'table.txt', {rows} rows and {columns} columns."
+        );
+
+        // Action
+        let summary: String = dry_run_summary(&args);
+
+        // Assert
+        assert_eq!(expected, summary);
+    }
+
+    #[test]
+    fn test_dry_run_summary_with_the_table_options() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("12345"),
+            multiplier: String::from("678"),
+            compact: true,
+            no_symbols: true,
+            no_author: true,
+            ..Args::default()
+        };
+        let expected: &str = "12345 x 678: 5 and 3 digits, output display, 44 rows and 21 columns.";
+
+        // Action
+        let summary: String = dry_run_summary(&args);
+
+        // Assert
+        assert_eq!(expected, summary);
+    }
+
+    #[test]
+    fn test_dry_run_summary_with_the_product_only() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("12345"),
+            multiplier: String::from("678"),
+            auto_answer_threshold: 4,
+            ..Args::default()
+        };
+        let expected: &str = "12345 x 678: 5 and 3 digits, output display, 1 rows and 7 columns.";
+
+        // Action
+        let summary: String = dry_run_summary(&args);
+
+        // Assert
+        assert_eq!(expected, summary);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: validate_support
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_validate_support_tsv_whole_numbers() {
+        // Arrange
+        let args: Args = Args { format: String::from("tsv"), ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_validate_support_tsv_decimals() {
+        // Arrange
+        let args: Args = Args { format: String::from("tsv"), multiplier_decimals: 1, ..Args::default() };
+        let expected: &str = "ERROR: the 'tsv' format only draws whole numbers without a sign.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_method_negative() {
+        // Arrange
+        let args: Args = Args { method: String::from("karatsuba"), multiplier_negative: true, ..Args::default() };
+        let expected: &str = "ERROR: the 'karatsuba' method only draws whole numbers without a sign.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_lattice_negative() {
+        // Arrange
+        let args: Args = Args { method: String::from("lattice"), multiplier_negative: true, ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_validate_support_times_table_base_sixteen() {
+        // Arrange
+        let args: Args = Args { base: 16, times_table: Some(3), ..Args::default() };
+        let expected: &str = "ERROR: the times table only draws base-10 numbers.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_random_base_two() {
+        // Arrange
+        let args: Args = Args { base: 2, random: Some((2, 2)), ..Args::default() };
+        let expected: &str = "ERROR: the practice sheet only draws base-10 numbers.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_batch_method() {
+        // Arrange
+        let args: Args = Args { batch: Some(String::from("pairs.txt")), method: String::from("peasant"), ..Args::default() };
+        let expected: &str = "ERROR: the '--method' option is not supported with the batch file.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_verify_base_sixteen() {
+        // Arrange
+        let args: Args = Args { base: 16, verify: true, ..Args::default() };
+        let expected: &str = "ERROR: the '--verify' row only draws base-10 numbers.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_chain_format() {
+        // Arrange
+        let args: Args = Args { format: String::from("json"), operands: vec![String::from("4")], ..Args::default() };
+        let expected: &str = "ERROR: the '--format' option is not supported with the chain of operands.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_chain_quiet() {
+        // Arrange
+        let args: Args = Args { quiet: true, operands: vec![String::from("4")], ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_validate_support_dry_run_batch() {
+        // Arrange
+        let args: Args = Args { dry_run: true, batch: Some(String::from("pairs.txt")), ..Args::default() };
+        let expected: &str = "ERROR: the '--dry-run' option is not supported with the batch file.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_dry_run_times_table() {
+        // Arrange
+        let args: Args = Args { dry_run: true, times_table: Some(7), ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Err(OperandError::Mode("'--dry-run' option", "times table")), result);
+    }
+
+    #[test]
+    fn test_validate_support_method_base_sixteen() {
+        // Arrange
+        let args: Args = Args { base: 16, method: String::from("peasant"), ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Err(OperandError::Radix("'peasant' method")), result);
+    }
+
+    #[test]
+    fn test_validate_support_random_format() {
+        // Arrange
+        let args: Args = Args { random: Some((3, 2)), format: String::from("json"), ..Args::default() };
+        let expected: &str = "ERROR: the '--format' option is not supported with the practice sheet.";
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(expected, result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_support_random_quiet() {
+        // Arrange
+        let args: Args = Args { random: Some((3, 2)), quiet: true, ..Args::default() };
+
+        // Action
+        let result: Result<(), OperandError> = validate_support(&args);
+
+        // Assert
+        assert_eq!(Err(OperandError::Mode("'--quiet' option", "practice sheet")), result);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: validate_layout_opts
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_validate_layout_opts_default() {
+        // Arrange
+        let args: Args = Args::default();
+        let expected: LayoutConfig = LayoutConfig::default();
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(Ok(expected), layout);
+    }
+
+    #[test]
+    fn test_validate_layout_opts_all_valid() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("13"),
+            multiplier: String::from("26"),
+            cell_width: Some(5),
+            max_width: Some(31),
+            pad_product_to: Some(5),
+            ..Args::default()
+        };
+        let expected: LayoutConfig = LayoutConfig {
+            cell_width: 5,
+            max_width: Some(31),
+            pad_product_to: Some(5),
+        };
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(Ok(expected), layout);
+    }
+
+    #[test]
+    fn test_validate_layout_opts_cell_width_too_small() {
+        // Arrange
+        let args: Args = Args { cell_width: Some(2), ..Args::default() };
+        let expected: &str = "ERROR: '--cell-width' must be at least 3, found '2'.";
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(Err(LayoutError::CellWidth(2)), layout);
+        assert_eq!(expected, layout.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_layout_opts_compact_fits_a_narrower_max_width() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("99"),
+            multiplier: String::from("99"),
+            compact: true,
+            max_width: Some(9),
+            ..Args::default()
+        };
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(COMPACT_CELL_WIDTH, layout.unwrap().cell_width);
+    }
+
+    #[test]
+    fn test_validate_layout_opts_pad_smaller_than_product() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("13597"),
+            multiplier: String::from("8642"),
+            pad_product_to: Some(8),
+            ..Args::default()
+        };
+        let expected: &str = "ERROR: '--pad-product-to' must be at least the product width 9, found '8'.";
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(Err(LayoutError::PadProductTo { pad: 8, width: 9 }), layout);
+        assert_eq!(expected, layout.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_layout_opts_max_width_smaller_than_padded_row() {
+        // Arrange
+        let args: Args = Args {
+            multiplicand: String::from("5"),
+            multiplier: String::from("7"),
+            cell_width: Some(4),
+            max_width: Some(15),
+            pad_product_to: Some(4),
+            ..Args::default()
+        };
+        let expected: &str = "ERROR: '--max-width' must be at least the row width 21, found '15'.";
+
+        // Action
+        let layout: Result<LayoutConfig, LayoutError> = validate_layout_opts(&args);
+
+        // Assert
+        assert_eq!(Err(LayoutError::MaxWidth { max: 15, row: 21 }), layout);
+        assert_eq!(expected, layout.unwrap_err().to_string());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_options_rows() {
+        // Arrange
+        let args: Args = Args {
+            rows: String::from("even"),
+            ..Args::default()
+        };
+
+        // Action
+        let options: TableOptions = get_table_options(&args).unwrap();
+
+        // Assert
+        assert_eq!(RowFilter::Even, options.rows);
+    }
+
+    #[test]
+    fn test_get_table_options_color_always() {
+        // Arrange
+        let args: Args = Args {
+            color: String::from("always"),
+            ..Args::default()
+        };
+
+        // Action
+        let options: TableOptions = get_table_options(&args).unwrap();
+
+        // Assert
+        assert_eq!(Some(ColorScheme::default()), options.colors);
+    }
+
+    #[test]
+    fn test_get_table_options_color_never_when_stored() {
+        // Arrange
+        let args: Args = Args {
+            color: String::from("always"),
+            output: String::from("store"),
+            ..Args::default()
+        };
+
+        // Action
+        let options: TableOptions = get_table_options(&args).unwrap();
+
+        // Assert
+        assert_eq!(None, options.colors);
+    }
+}
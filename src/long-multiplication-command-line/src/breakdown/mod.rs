@@ -1,4 +1,83 @@
+use std::fmt;
+use std::ops::Range;
+
 use crate::length::{get_string_length, get_strings_length};
+use crate::options::TableOptions;
+
+/// The units and the carriers of the long multiplication.
+///
+/// Each product of a multiplicand digit by a multiplier digit is
+/// split into its unit and its carry. The rows start from the last
+/// digit of the multiplier, and each row has one cell for each
+/// digit of the multiplicand, from left to right. The `Display`
+/// prints a row per line, the carriers and then the units.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+///
+/// use long_multiplication_command_line::breakdown::{Breakdown, break_down_multiplication};
+/// let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+///
+/// assert_eq!(&[6, 8, 2, 6], breakdown.units());
+/// assert_eq!(&[0, 1, 0, 0], breakdown.carries());
+/// assert_eq!(2, breakdown.rows());
+/// assert_eq!("0 1 | 6 8\n0 0 | 2 6\n", breakdown.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakdown {
+    // The unit of each product.
+    pub units: Vec<usize>,
+
+    // The carry of each product.
+    pub carries: Vec<usize>,
+
+    // The number of cells of each row: the digits of the multiplicand.
+    pub columns: usize,
+}
+
+impl Breakdown {
+    /// The unit of each product, row after row.
+    pub fn units(&self) -> &[usize] {
+        return &self.units;
+    }
+
+    /// The carry of each product, row after row.
+    pub fn carries(&self) -> &[usize] {
+        return &self.carries;
+    }
+
+    /// The number of rows: the digits of the multiplier.
+    pub fn rows(&self) -> usize {
+        if self.columns == 0 {
+            return 0;
+        }
+
+        return self.units.len() / self.columns;
+    }
+
+    /// The units and the carriers, in the order of the former tuple.
+    pub fn into_parts(self) -> (Vec<usize>, Vec<usize>) {
+        return (self.units, self.carries);
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let columns: usize = self.columns.max(1);
+        for (carries, units) in self.carries.chunks(columns).zip(self.units.chunks(columns)) {
+            let carries: Vec<String> = carries.iter().map(|carry| carry.to_string()).collect();
+            let units: Vec<String> = units.iter().map(|unit| unit.to_string()).collect();
+            writeln!(f, "{} | {}", carries.join(" "), units.join(" "))?;
+        }
+
+        return Ok(());
+    }
+}
 
 /// Get a list of the sum for the rows in each column.
 ///
@@ -45,24 +124,44 @@ use crate::length::{get_string_length, get_strings_length};
 /// assert_eq!(expected_addition, addition);
 /// ```
 pub fn break_down_addition(multiplicand: &String, multiplier: &String) -> Vec<usize> {
+    return break_down_addition_with_radix(multiplicand, multiplier, 10);
+}
+
+/// Get a list of the sum for the rows in each column in the given base.
+///
+/// It works like `break_down_addition`, but the products of
+/// the digits are split into units and carriers in the given
+/// base (radix) instead of base ten.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("101");
+/// let multiplier: String = String::from("11");
+/// let expected: Vec<usize> = vec![1, 1, 1, 1, 0];
+///
+/// use long_multiplication_command_line::breakdown::break_down_addition_with_radix;
+/// let result: Vec<usize> = break_down_addition_with_radix(&multiplicand, &multiplier, 2);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn break_down_addition_with_radix(multiplicand: &String, multiplier: &String, radix: u32) -> Vec<usize> {
     let multiplicand_len: usize = get_string_length(multiplicand);
     let length: usize = get_strings_length(multiplicand, multiplier);
     let step: usize = multiplicand_len;
 
-    let units: Vec<usize>;
-    let carriers: Vec<usize>;
     let multiplicand_str: String = multiplicand.to_string();
     let multiplier_str: String = multiplier.to_string();
-    (units, carriers) = break_down_multiplication(&multiplicand_str, &multiplier_str);
+    let breakdown: Breakdown = break_down_multiplication_with_radix(&multiplicand_str, &multiplier_str, radix);
+    let units: &[usize] = breakdown.units();
+    let carriers: &[usize] = breakdown.carries();
 
-    let mut addition: Vec<usize> = Vec::new();
-    for _ in 0..length {
-        addition.push(0);
-    }
+    let mut addition: Vec<usize> = vec![0; length];
 
-    let mut iteration: usize = 0;
     let total_units: usize = units.len();
-    for start in (0..total_units).step_by(step) {
+    for (iteration, start) in (0..total_units).step_by(step).enumerate() {
         for sub_index in start..start + step {
             let carry_index: usize = start + step + iteration - sub_index;
             let carry: usize = carriers[sub_index];
@@ -71,7 +170,6 @@ pub fn break_down_addition(multiplicand: &String, multiplier: &String) -> Vec<us
             let unit: usize = units[sub_index];
             addition[unit_index] += unit;
         }
-        iteration += 1;
     }
 
     let addition: Vec<usize> = addition;
@@ -88,7 +186,7 @@ pub fn break_down_addition(multiplicand: &String, multiplier: &String) -> Vec<us
 /// for each multiplicand by multiplier.
 ///
 /// This information (result of the products and the carriers) is
-/// returned as a collection of vectors.
+/// returned as a `Breakdown`, with the `units` and the `carries`.
 ///
 /// Examples
 /// --------
@@ -113,19 +211,14 @@ pub fn break_down_addition(multiplicand: &String, multiplier: &String) -> Vec<us
 /// ```rust
 /// let multiplicand: String = String::from("25");
 /// let multiplier: String = String::from("3");
-/// let operation_unit: Vec<usize>;
-/// let operation_carry: Vec<usize>;
 /// let expected_unit: Vec<usize> = vec![6, 5];
 /// let expected_carry: Vec<usize> = vec![0, 1];
 ///
-/// use long_multiplication_command_line::breakdown::break_down_multiplication;
-/// (
-///     operation_unit,
-///     operation_carry
-/// ) = break_down_multiplication(&multiplicand, &multiplier);
+/// use long_multiplication_command_line::breakdown::{Breakdown, break_down_multiplication};
+/// let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
 ///
-/// assert_eq!(expected_unit, operation_unit);
-/// assert_eq!(expected_carry, operation_carry);
+/// assert_eq!(expected_unit, breakdown.units);
+/// assert_eq!(expected_carry, breakdown.carries);
 /// ```
 ///
 /// Example #2
@@ -152,21 +245,41 @@ pub fn break_down_addition(multiplicand: &String, multiplier: &String) -> Vec<us
 /// ```rust
 /// let multiplicand: String = String::from("13");
 /// let multiplier: String = String::from("26");
-/// let operation_unit: Vec<usize>;
-/// let operation_carry: Vec<usize>;
 /// let expected_unit: Vec<usize> = vec![6, 8, 2, 6];
 /// let expected_carry: Vec<usize> = vec![0, 1, 0, 0];
 ///
-/// use long_multiplication_command_line::breakdown::break_down_multiplication;
-/// (
-///     operation_unit,
-///     operation_carry
-/// ) = break_down_multiplication(&multiplicand, &multiplier);
+/// use long_multiplication_command_line::breakdown::{Breakdown, break_down_multiplication};
+/// let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+///
+/// assert_eq!(expected_unit, breakdown.units);
+/// assert_eq!(expected_carry, breakdown.carries);
+/// ```
+pub fn break_down_multiplication(multiplicand: &String, multiplier: &String) -> Breakdown {
+    return break_down_multiplication_with_radix(multiplicand, multiplier, 10);
+}
+
+/// Get a list of the units and carriers in the given base.
+///
+/// It works like `break_down_multiplication`, but each product
+/// is split into units (`product % radix`) and carriers
+/// (`product / radix`) of the given base.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("11");
+/// let multiplier: String = String::from("11");
+/// let expected: (Vec<usize>, Vec<usize>) = (vec![1, 1, 1, 1], vec![0, 0, 0, 0]);
 ///
-/// assert_eq!(expected_unit, operation_unit);
-/// assert_eq!(expected_carry, operation_carry);
+/// use long_multiplication_command_line::breakdown::{Breakdown, break_down_multiplication_with_radix};
+/// let result: Breakdown = break_down_multiplication_with_radix(&multiplicand, &multiplier, 2);
+///
+/// assert_eq!(expected, result.into_parts());
 /// ```
-pub fn break_down_multiplication(multiplicand: &String, multiplier: &String) -> (Vec<usize>, Vec<usize>) {
+pub fn break_down_multiplication_with_radix(multiplicand: &String, multiplier: &String, radix: u32) -> Breakdown {
+    let radix: usize = radix as usize;
     let mut operation_unit: Vec<usize> = Vec::new();
     let mut operation_carry: Vec<usize> = Vec::new();
 
@@ -174,11 +287,11 @@ pub fn break_down_multiplication(multiplicand: &String, multiplier: &String) ->
         let mut units: Vec<usize> = Vec::new();
         let mut carriers: Vec<usize> = Vec::new();
         for b in multiplicand.chars().rev() {
-            let multiplicand_digit: usize = a as usize - 0x30;
-            let multiplier_digit: usize = b as usize - 0x30;
+            let multiplicand_digit: usize = char_to_digit(a, radix as u32);
+            let multiplier_digit: usize = char_to_digit(b, radix as u32);
             let product: usize = multiplicand_digit * multiplier_digit;
-            let unit: usize = product % 10;
-            let carry: usize = product / 10;
+            let unit: usize = product % radix;
+            let carry: usize = product / radix;
             units.push(unit);
             carriers.push(carry);
         }
@@ -194,7 +307,11 @@ pub fn break_down_multiplication(multiplicand: &String, multiplier: &String) ->
         }
     }
 
-    return (operation_unit, operation_carry);
+    return Breakdown {
+        units: operation_unit,
+        carries: operation_carry,
+        columns: get_string_length(multiplicand),
+    };
 }
 
 /// Get a list of the last sum and sum again removing
@@ -231,371 +348,1781 @@ pub fn break_down_multiplication(multiplicand: &String, multiplier: &String) ->
 /// assert_eq!(expected, result);
 /// ```
 pub fn break_down_subtotal(addition: &Vec<usize>) -> Vec<usize> {
-    let mut new_addition: Vec<usize> = Vec::new();
-    for _ in 0..addition.len() {
-        new_addition.push(0);
-    }
+    return break_down_subtotal_with_radix(addition, 10);
+}
+
+/// Get a list of the last sum and sum again in the given base.
+///
+/// It works like `break_down_subtotal`, but it carries every
+/// column that is equal to or greater than the base (radix).
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let value: Vec<usize> = vec![1, 1, 2, 1, 0];
+/// let expected: Vec<usize> = vec![1, 1, 0, 2, 0];
+///
+/// use long_multiplication_command_line::breakdown::break_down_subtotal_with_radix;
+/// let result: Vec<usize> = break_down_subtotal_with_radix(&value, 2);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn break_down_subtotal_with_radix(addition: &Vec<usize>, radix: u32) -> Vec<usize> {
+    let radix: usize = radix as usize;
+    let mut new_addition: Vec<usize> = vec![0; addition.len()];
 
     for index in 0..addition.len() {
         let number: usize = addition[index];
-        if number < 10 {
+        if number < radix {
             new_addition[index] += number;
         } else {
-            let decimal: usize = number / 10;
-            let unit: usize = number % 10;
+            let decimal: usize = number / radix;
+            let unit: usize = number % radix;
             new_addition[index + 1] += decimal;
             new_addition[index] += unit;
         }
     }
 
+    debug_assert!(vector_value_with_radix(addition, radix as u32) == vector_value_with_radix(&new_addition, radix as u32));
+
     let new_addition: Vec<usize> = new_addition;
     return new_addition;
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // # -----------------------------------------------------------------------
-    // # Function: break_down_multiplication
-    // # -----------------------------------------------------------------------
-    #[test]
-    fn test_break_down_multiplication_with_three_digits_multiplicand_is_greater() {
-        // Arrange
-        let multiplicand: String = String::from("25");
-        let multiplier: String = String::from("3");
-        let operation_unit: Vec<usize>;
-        let operation_carry: Vec<usize>;
-        let expected_unit: Vec<usize> = vec![6, 5];
-        let expected_carry: Vec<usize> = vec![0, 1];
-
-        // Action
-        (
-            operation_unit,
-            operation_carry
-        ) = break_down_multiplication(&multiplicand, &multiplier);
+/// Get the product from the sum of the columns, fully carried.
+///
+/// Given a list of the sum of each column, starting from the units
+/// column like `break_down_addition`, it calls `break_down_subtotal`
+/// until every column holds one digit, and returns the digits of
+/// the product, starting from the units.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let value: Vec<usize> = vec![1, 10, 19, 27, 27, 27, 26, 17, 8];
+/// let expected: Vec<usize> = vec![1, 0, 0, 9, 9, 9, 8, 9, 9];
+///
+/// use long_multiplication_command_line::breakdown::fully_reduce;
+/// let result: Vec<usize> = fully_reduce(&value);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn fully_reduce(addition: &Vec<usize>) -> Vec<usize> {
+    return fully_reduce_with_radix(addition, 10);
+}
 
-        // Assert
-        assert_eq!(expected_unit, operation_unit);
-        assert_eq!(expected_carry, operation_carry);
+/// Get the product from the sum of the columns, fully carried in the given base.
+///
+/// It works like `fully_reduce`, but it carries every column
+/// that is equal to or greater than the base (radix).
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let value: Vec<usize> = vec![1, 2, 1, 0];
+/// let expected: Vec<usize> = vec![1, 0, 0, 1];
+///
+/// use long_multiplication_command_line::breakdown::fully_reduce_with_radix;
+/// let result: Vec<usize> = fully_reduce_with_radix(&value, 2);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn fully_reduce_with_radix(addition: &Vec<usize>, radix: u32) -> Vec<usize> {
+    let mut sub_addition: Vec<usize> = break_down_subtotal_with_radix(addition, radix);
+    while sub_addition.iter().any(|number| *number >= radix as usize) {
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
     }
 
-    #[test]
-    fn test_break_down_multiplication_with_three_digits_multiplier_is_greater() {
-        // Arrange
-        let multiplicand: String = String::from("3");
-        let multiplier: String = String::from("25");
-        let operation_unit: Vec<usize>;
-        let operation_carry: Vec<usize>;
-        let expected_unit: Vec<usize> = vec![5, 6];
-        let expected_carry: Vec<usize> = vec![1, 0];
-
-        // Action
-        (
-            operation_unit,
-            operation_carry
-        ) = break_down_multiplication(&multiplicand, &multiplier);
+    return sub_addition;
+}
 
-        // Assert
-        assert_eq!(expected_unit, operation_unit);
-        assert_eq!(expected_carry, operation_carry);
+/// Get the operand without its leading zeros.
+///
+/// The leading zeros are removed before the table is rendered,
+/// so every column of the table holds a significant digit. At
+/// least one digit is kept, so an operand made only of zeros
+/// becomes `"0"`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::normalize_operand;
+///
+/// assert_eq!("7", normalize_operand("007"));
+/// assert_eq!("0", normalize_operand("000"));
+/// ```
+pub fn normalize_operand(operand: &str) -> String {
+    let normalized: &str = operand.trim_start_matches('0');
+    if normalized.is_empty() && !operand.is_empty() {
+        return String::from("0");
     }
 
-    #[test]
-    fn test_break_down_multiplication_with_four_digit() {
-        // Arrange
-        let multiplicand: String = String::from("13");
-        let multiplier: String = String::from("26");
-        let operation_unit: Vec<usize>;
-        let operation_carry: Vec<usize>;
-        let expected_unit: Vec<usize> = vec![6, 8, 2, 6];
-        let expected_carry: Vec<usize> = vec![0, 1, 0, 0];
-
-        // Action
-        (
-            operation_unit,
-            operation_carry
-        ) = break_down_multiplication(&multiplicand, &multiplier);
-
-        // Assert
-        assert_eq!(expected_unit, operation_unit);
-        assert_eq!(expected_carry, operation_carry);
-    }
+    return normalized.to_string();
+}
 
-    #[test]
-    fn test_break_down_multiplication_with_six_digit() {
-        // Arrange
-        let multiplicand: String = String::from("123");
-        let multiplier: String = String::from("456");
-        let operation_unit: Vec<usize>;
-        let operation_carry: Vec<usize>;
-        let expected_unit: Vec<usize> = vec![6, 2, 8, 5, 0, 5, 4, 8, 2];
-        let expected_carry: Vec<usize> = vec![0, 1, 1, 0, 1, 1, 0, 0, 1];
+/// Get the operand without its grouping separators.
+///
+/// The numbers copied from a spreadsheet, like `1,234,567`, are
+/// grouped with commas, underscores or spaces. They are removed
+/// wherever they are, so the groups need not be of three digits:
+/// `12,34` is `1234`. The decimal point is kept.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::sanitize_operand;
+///
+/// assert_eq!("1234567", sanitize_operand("1,234,567"));
+/// assert_eq!("1000", sanitize_operand("1_000"));
+/// assert_eq!("1000.5", sanitize_operand("1 000.5"));
+/// ```
+pub fn sanitize_operand(operand: &str) -> String {
+    let separators: [char; 3] = [',', '_', ' '];
 
-        // Action
-        (
-            operation_unit,
-            operation_carry
-        ) = break_down_multiplication(&multiplicand, &multiplier);
+    return operand.chars().filter(|character| !separators.contains(character)).collect();
+}
 
-        // Assert
-        assert_eq!(expected_unit, operation_unit);
-        assert_eq!(expected_carry, operation_carry);
-    }
+/// Get the product of the long multiplication.
+///
+/// Given two numbers that are multiplied, it sums the columns
+/// with `break_down_addition`, carries them with
+/// `break_down_subtotal` until every column holds one digit,
+/// and returns the product without the leading zeros (except
+/// for `"0"` itself).
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::compute_product;
+///
+/// assert_eq!("338", compute_product("13", "26"));
+/// assert_eq!("0", compute_product("0", "999"));
+/// ```
+pub fn compute_product(multiplicand: &str, multiplier: &str) -> String {
+    return compute_product_with_options(multiplicand, multiplier, &TableOptions::default());
+}
 
-    // # -----------------------------------------------------------------------
-    // # Function: break_down_addition
-    // # -----------------------------------------------------------------------
-    #[test]
-    fn test_break_down_addition_product_one_digit() {
-        // Arrange
-        let multiplicand: String = String::from("2");
-        let multiplier: String = String::from("3");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![6, 0];
+/// Get the product of the long multiplication using the given options.
+///
+/// It works like `compute_product`, but the operands and the
+/// product are in the base (radix) of the options, the decimal
+/// point is inserted for the fractional digits of both operands,
+/// and the product is prefixed with `-` when exactly one operand
+/// is negative. The zero has no sign.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::compute_product_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+///
+/// let options: TableOptions = TableOptions { radix: 2, ..TableOptions::default() };
+/// assert_eq!("1001", compute_product_with_options("11", "11", &options));
+///
+/// let options: TableOptions = TableOptions { signs: (true, false), decimals: (1, 0), ..TableOptions::default() };
+/// assert_eq!("-3.0", compute_product_with_options("15", "2", &options));
+/// ```
+pub fn compute_product_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+    let radix: u32 = options.radix;
 
-        // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+    let addition: Vec<usize> = fully_reduce_with_radix(&break_down_addition_with_radix(&multiplicand_str, &multiplier_str, radix), radix);
 
-        // Assert
-        assert_eq!(expected_addition, addition);
+    let mut product: String = addition.iter().rev().map(|digit| digit_to_char(*digit, radix)).collect();
+    while product.len() > 1 && product.starts_with('0') {
+        product.remove(0);
     }
 
-    #[test]
-    fn test_break_down_addition_product_two_digits() {
-        // Arrange
-        let multiplicand: String = String::from("9");
-        let multiplier: String = String::from("8");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![2, 7];
-
-        // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
-
-        // Assert
-        assert_eq!(expected_addition, addition);
+    let negative: bool = options.signs.0 != options.signs.1 && product != "0";
+    let product: String = reinsert_decimal(&product, options.product_decimals());
+    if negative {
+        return format!("-{product}");
     }
 
-    #[test]
-    fn test_break_down_addition_with_three_digits() {
-        // Arrange
-        let multiplicand: String = String::from("37");
-        let multiplier: String = String::from("8");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![6, 9, 2];
+    return product;
+}
 
-        // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+fn columns_to_product(columns: &[usize], radix: u32) -> String {
+    let base: usize = radix as usize;
+    let mut digits: Vec<u8> = Vec::with_capacity(columns.len() + 1);
 
-        // Assert
-        assert_eq!(expected_addition, addition);
+    let mut carry: usize = 0;
+    for column in columns {
+        let total: usize = column + carry;
+        digits.push((total % base) as u8);
+        carry = total / base;
+    }
+    while carry > 0 {
+        digits.push((carry % base) as u8);
+        carry /= base;
+    }
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(0);
     }
 
-    #[test]
-    fn test_break_down_addition_with_three_digits_switch() {
-        // Arrange
-        let multiplicand: String = String::from("8");
-        let multiplier: String = String::from("37");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![6, 9, 2];
-
-        // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+    return digits.iter().rev().map(|digit| digit_to_char(*digit as usize, radix)).collect();
+}
 
-        // Assert
-        assert_eq!(expected_addition, addition);
+/// Get the text of a number in the given base.
+///
+/// Given a number and a base (radix) from 2 to 16, it returns
+/// the digits of the number in that base, from the most
+/// significant to the least significant.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::to_radix_string;
+///
+/// assert_eq!("1111", to_radix_string(15, 2));
+/// assert_eq!("15", to_radix_string(15, 10));
+/// assert_eq!("FF", to_radix_string(255, 16));
+/// ```
+pub fn to_radix_string(number: usize, radix: u32) -> String {
+    let radix_size: usize = radix as usize;
+
+    let mut digits: Vec<char> = Vec::new();
+    let mut number: usize = number;
+    loop {
+        digits.push(digit_to_char(number % radix_size, radix));
+        number /= radix_size;
+        if number == 0 {
+            break;
+        }
     }
 
-    #[test]
+    return digits.iter().rev().collect();
+}
+
+/// Get the numeric value represented by a list of columns.
+///
+/// Given a list of columns, starting from the units column like
+/// `break_down_addition` and `break_down_subtotal`, it adds each
+/// column multiplied by its place value (1, 10, 100, ...).
+/// The columns may hold numbers greater than nine.
+///
+/// Values that do not fit in 128 bits wrap around, so two lists
+/// can still be compared for long multiplications.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let value: Vec<usize> = vec![8, 12, 2];
+/// let expected: u128 = 328;
+///
+/// use long_multiplication_command_line::breakdown::vector_value;
+/// let result: u128 = vector_value(&value);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn vector_value(digits: &[usize]) -> u128 {
+    return vector_value_with_radix(digits, 10);
+}
+
+/// Get the numeric value represented by a list of columns in the given base.
+///
+/// It works like `vector_value`, but the place value of each
+/// column is a power of the base (radix), e.g. 1, 2, 4, ... for
+/// base two.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let value: Vec<usize> = vec![1, 3, 1];
+/// let expected: u128 = 11;
+///
+/// use long_multiplication_command_line::breakdown::vector_value_with_radix;
+/// let result: u128 = vector_value_with_radix(&value, 2);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn vector_value_with_radix(digits: &[usize], radix: u32) -> u128 {
+    let mut value: u128 = 0;
+    let mut place: u128 = 1;
+    for digit in digits {
+        value = value.wrapping_add((*digit as u128).wrapping_mul(place));
+        place = place.wrapping_mul(radix as u128);
+    }
+
+    return value;
+}
+
+/// Get the sum of each column in the order of the table.
+///
+/// Given two numbers that are multiplied, it returns the sums
+/// of `break_down_addition` from left to right, as the columns
+/// are displayed: the first item is the most significant column
+/// (the row `n C` with the highest `n`) and the last item is the
+/// units column (the row `1 C`). The sums are not reduced, so a
+/// column can hold more than one digit.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: &str = "13";
+/// let multiplier: &str = "26";
+/// let expected: Vec<usize> = vec![0, 2, 13, 8];
+///
+/// use long_multiplication_command_line::breakdown::column_sums;
+/// let sums: Vec<usize> = column_sums(multiplicand, multiplier);
+///
+/// assert_eq!(expected, sums);
+/// ```
+pub fn column_sums(multiplicand: &str, multiplier: &str) -> Vec<usize> {
+    let mut sums: Vec<usize> = break_down_addition(&multiplicand.to_string(), &multiplier.to_string());
+    sums.reverse();
+
+    return sums;
+}
+
+/// Get the number of significant digits of the product.
+///
+/// Given two numbers that are multiplied, it reduces the sums of
+/// the columns to the digits of the product, without the table,
+/// and counts them without the leading zeros. The product zero
+/// has one digit.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::product_digit_count;
+///
+/// assert_eq!(3, product_digit_count("13", "26"));
+/// assert_eq!(1, product_digit_count("0", "999"));
+/// ```
+pub fn product_digit_count(multiplicand: &str, multiplier: &str) -> usize {
+    let additions: Vec<usize> = break_down_addition(&multiplicand.to_string(), &multiplier.to_string());
+    let digits: Vec<usize> = fully_reduce(&additions);
+
+    // The digits start from the units, so the last non-zero digit is the most significant.
+    return match digits.iter().rposition(|digit| *digit != 0) {
+        Some(index) => index + 1,
+        None => 1,
+    };
+}
+
+/// Get the units of the products for each digit of the multiplier.
+///
+/// Given two numbers that are multiplied, it gets the units of the
+/// products of each multiplier digit by each multiplicand digit.
+///
+/// The outer list starts from the least significant digit of the
+/// multiplier (the row `1 R`). Each inner list follows the order of
+/// the multiplicand digits, from the most significant to the least
+/// significant, like `break_down_multiplication` does.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: &str = "13";
+/// let multiplier: &str = "26";
+/// let expected: Vec<Vec<usize>> = vec![vec![6, 8], vec![2, 6]];
+///
+/// use long_multiplication_command_line::breakdown::units_per_multiplier;
+/// let units: Vec<Vec<usize>> = units_per_multiplier(multiplicand, multiplier);
+///
+/// assert_eq!(expected, units);
+/// ```
+pub fn units_per_multiplier(multiplicand: &str, multiplier: &str) -> Vec<Vec<usize>> {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+    let step: usize = get_string_length(&multiplicand_str);
+
+    let breakdown: Breakdown = break_down_multiplication(&multiplicand_str, &multiplier_str);
+
+    let units_per_multiplier: Vec<Vec<usize>> = breakdown.units()
+        .chunks(step)
+        .map(|row| row.to_vec())
+        .collect();
+    return units_per_multiplier;
+}
+
+/// Get the shifted partial products of the multiplication.
+///
+/// Given two numbers that are multiplied, it returns the product
+/// of the multiplicand by each multiplier digit, shifted to the
+/// position of that digit. The list follows the multiplication
+/// order, from the least significant digit of the multiplier
+/// (the row `1 R`), and the sum of the list is the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: &str = "13";
+/// let multiplier: &str = "26";
+/// let expected: Vec<String> = vec!["78".to_string(), "260".to_string()];
+///
+/// use long_multiplication_command_line::breakdown::partial_products;
+/// let partials: Vec<String> = partial_products(multiplicand, multiplier);
+///
+/// assert_eq!(expected, partials);
+/// ```
+pub fn partial_products(multiplicand: &str, multiplier: &str) -> Vec<String> {
+    return partial_products_with_radix(multiplicand, multiplier, 10);
+}
+
+/// Get the shifted partial products of the multiplication in the given base.
+///
+/// It works like `partial_products`, but the digits of the
+/// operands and the partial products are in the given base
+/// (radix), from 2 to 16.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: &str = "FF";
+/// let multiplier: &str = "12";
+/// let expected: Vec<String> = vec!["1FE".to_string(), "FF0".to_string()];
+///
+/// use long_multiplication_command_line::breakdown::partial_products_with_radix;
+/// let partials: Vec<String> = partial_products_with_radix(multiplicand, multiplier, 16);
+///
+/// assert_eq!(expected, partials);
+/// ```
+pub fn partial_products_with_radix(multiplicand: &str, multiplier: &str, radix: u32) -> Vec<String> {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+
+    let breakdown: Breakdown = break_down_multiplication_with_radix(&multiplicand_str, &multiplier_str, radix);
+    let units: &[usize] = breakdown.units();
+    let carries: &[usize] = breakdown.carries();
+    let step: usize = breakdown.columns;
+
+    let mut partials: Vec<String> = Vec::new();
+    for row in 0..breakdown.rows() {
+        // The columns start from the least significant, shifted by the row.
+        let mut columns: Vec<usize> = vec![0; step + 1 + row];
+        for cell in 0..step {
+            let index: usize = row * step + cell;
+            let position: usize = row + step - 1 - cell;
+            columns[position] += units[index];
+            columns[position + 1] += carries[index];
+        }
+        partials.push(columns_to_product(&columns, radix));
+    }
+    return partials;
+}
+
+/// Get the product from the sum of the partial products.
+///
+/// Given two numbers that are multiplied, it adds the shifted
+/// partial products of `partial_products` one by one, as digit
+/// strings. It does not use the sums of the columns, so it is
+/// an independent check of `compute_product`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: &str = "13597";
+/// let multiplier: &str = "8642";
+/// let expected: &str = "117505274";
+///
+/// use long_multiplication_command_line::breakdown::sum_partial_products;
+/// let product: String = sum_partial_products(multiplicand, multiplier);
+///
+/// assert_eq!(expected, product);
+/// ```
+pub fn sum_partial_products(multiplicand: &str, multiplier: &str) -> String {
+    let mut product: String = String::from("0");
+    for partial in partial_products(multiplicand, multiplier) {
+        product = add_digit_strings(&product, &partial);
+    }
+    return product;
+}
+
+/// Add two numbers written as digit strings.
+///
+/// The digits are added from the units, carrying the tens to
+/// the next digit, like the addition on paper. The sum has no
+/// leading zeros, except for `"0"` itself.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::add_digit_strings;
+///
+/// assert_eq!("1338", add_digit_strings("78", "1260"));
+/// ```
+pub fn add_digit_strings(number_a: &str, number_b: &str) -> String {
+    let digits_a: Vec<usize> = number_a.chars().rev().map(|c| char_to_digit(c, 10)).collect();
+    let digits_b: Vec<usize> = number_b.chars().rev().map(|c| char_to_digit(c, 10)).collect();
+    let length: usize = digits_a.len().max(digits_b.len());
+
+    let mut sum: Vec<char> = Vec::new();
+    let mut carry: usize = 0;
+    for index in 0..length {
+        let column: usize = digits_a.get(index).unwrap_or(&0) + digits_b.get(index).unwrap_or(&0) + carry;
+        sum.push(digit_to_char(column % 10, 10));
+        carry = column / 10;
+    }
+    if carry > 0 {
+        sum.push(digit_to_char(carry, 10));
+    }
+
+    let sum: String = sum.iter().rev().collect();
+    return normalize_operand(&sum);
+}
+
+/// Subtract two numbers written as digit strings.
+///
+/// The digits are subtracted from the units, borrowing from the
+/// next digit, like the subtraction on paper. The subtrahend
+/// must not be greater than the minuend. The difference has no
+/// leading zeros, except for `"0"` itself.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::subtract_digit_strings;
+///
+/// assert_eq!("2840", subtract_digit_strings("6164", "3324"));
+/// ```
+pub fn subtract_digit_strings(minuend: &str, subtrahend: &str) -> String {
+    let digits_a: Vec<usize> = minuend.chars().rev().map(|c| char_to_digit(c, 10)).collect();
+    let digits_b: Vec<usize> = subtrahend.chars().rev().map(|c| char_to_digit(c, 10)).collect();
+
+    let mut difference: Vec<char> = Vec::new();
+    let mut borrow: usize = 0;
+    for (index, digit) in digits_a.iter().enumerate() {
+        let taken: usize = digits_b.get(index).unwrap_or(&0) + borrow;
+        if *digit >= taken {
+            difference.push(digit_to_char(digit - taken, 10));
+            borrow = 0;
+        } else {
+            difference.push(digit_to_char(digit + 10 - taken, 10));
+            borrow = 1;
+        }
+    }
+
+    let difference: String = difference.iter().rev().collect();
+    return normalize_operand(&difference);
+}
+
+/// Halve a number written as a digit string.
+///
+/// The digits are divided by two from the most significant,
+/// carrying the remainder to the next digit, like the division
+/// on paper. The remainder of the last digit is dropped, so the
+/// odd numbers are rounded down.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::halve_digit_string;
+///
+/// assert_eq!("9", halve_digit_string("18"));
+/// assert_eq!("4", halve_digit_string("9"));
+/// ```
+pub fn halve_digit_string(number: &str) -> String {
+    let mut half: String = String::with_capacity(number.len());
+    let mut remainder: usize = 0;
+    for character in number.chars() {
+        let current: usize = remainder * 10 + char_to_digit(character, 10);
+        half.push(digit_to_char(current / 2, 10));
+        remainder = current % 2;
+    }
+
+    return normalize_operand(&half);
+}
+
+/// Check if the multiplication requires any carrying.
+///
+/// Given two numbers that are multiplied, it returns true
+/// when any product of a multiplicand digit by a multiplier
+/// digit has a carry, or when the sum of any column is
+/// greater than nine. Otherwise, the multiplication is a
+/// "no-carry" problem.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::requires_carry;
+///
+/// assert_eq!(false, requires_carry("11", "11"));
+/// ```
+///
+/// Example #2
+/// ```rust
+/// use long_multiplication_command_line::breakdown::requires_carry;
+///
+/// assert_eq!(true, requires_carry("9", "9"));
+/// ```
+pub fn requires_carry(multiplicand: &str, multiplier: &str) -> bool {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+
+    let breakdown: Breakdown = break_down_multiplication(&multiplicand_str, &multiplier_str);
+    if breakdown.carries().iter().any(|carry| carry > &0) {
+        return true;
+    }
+
+    let addition: Vec<usize> = break_down_addition(&multiplicand_str, &multiplier_str);
+    return addition.iter().any(|number| number > &9);
+}
+
+/// Get the columns where the digits of a partial-product row land.
+///
+/// Given the index of the row (starting at zero for the row `1 R`)
+/// and the length of the multiplicand, it returns the range of the
+/// columns occupied by the units and the carry-over of that row.
+///
+/// The columns are counted from the right, starting at zero for
+/// the units column, like the list of `break_down_addition`. Each
+/// row is shifted one column to the left from the previous one,
+/// and its last carry-over takes one more column than its units.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::row_column_span;
+///
+/// assert_eq!(0..3, row_column_span(0, 2));
+/// assert_eq!(1..4, row_column_span(1, 2));
+/// ```
+pub fn row_column_span(row_index: usize, a_len: usize) -> Range<usize> {
+    let start: usize = row_index;
+    let end: usize = row_index + a_len + 1;
+
+    return start..end;
+}
+
+
+/// Get the value of a digit character in the given base.
+///
+/// Given a character and a base (radix) from 2 to 16, it
+/// returns the value of the digit, accepting both uppercase
+/// and lowercase letters for the digits above nine.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::char_to_digit;
+///
+/// assert_eq!(7, char_to_digit('7', 10));
+/// assert_eq!(15, char_to_digit('F', 16));
+/// assert_eq!(15, char_to_digit('f', 16));
+/// ```
+pub fn char_to_digit(character: char, radix: u32) -> usize {
+    return character.to_digit(radix).unwrap() as usize;
+}
+
+/// Get the character of a digit in the given base.
+///
+/// Given a digit and a base (radix) from 2 to 16, it returns
+/// the character of the digit, using the uppercase letters
+/// `A` to `F` for the digits above nine.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::digit_to_char;
+///
+/// assert_eq!('7', digit_to_char(7, 10));
+/// assert_eq!('F', digit_to_char(15, 16));
+/// ```
+pub fn digit_to_char(digit: usize, radix: u32) -> char {
+    return char::from_digit(digit as u32, radix).unwrap().to_ascii_uppercase();
+}
+
+/// Get the product with its decimal point.
+///
+/// Given the digits of the product of the operands without
+/// their decimal points, and the combined number of fractional
+/// digits of the operands, it inserts the decimal point at the
+/// right position, padding the product with leading zeros when
+/// it has fewer digits than the fractional part.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::breakdown::reinsert_decimal;
+///
+/// assert_eq!("3.0", reinsert_decimal("30", 1));
+/// assert_eq!("0.01", reinsert_decimal("1", 2));
+/// assert_eq!("338", reinsert_decimal("338", 0));
+/// ```
+pub fn reinsert_decimal(product: &str, fractional_digits: usize) -> String {
+    if fractional_digits == 0 {
+        return product.to_string();
+    }
+
+    let padded: String = format!("{product:0>width$}", width = fractional_digits + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - fractional_digits);
+
+    return format!("{integer}.{fraction}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // # -----------------------------------------------------------------------
+    // # Function: break_down_multiplication
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_break_down_multiplication_with_three_digits_multiplicand_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("25");
+        let multiplier: String = String::from("3");
+        let expected_unit: Vec<usize> = vec![6, 5];
+        let expected_carry: Vec<usize> = vec![0, 1];
+
+        // Action
+        let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_unit, breakdown.units);
+        assert_eq!(expected_carry, breakdown.carries);
+    }
+
+    #[test]
+    fn test_break_down_multiplication_with_three_digits_multiplier_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("25");
+        let expected_unit: Vec<usize> = vec![5, 6];
+        let expected_carry: Vec<usize> = vec![1, 0];
+
+        // Action
+        let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_unit, breakdown.units);
+        assert_eq!(expected_carry, breakdown.carries);
+    }
+
+    #[test]
+    fn test_break_down_multiplication_with_four_digit() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let expected_unit: Vec<usize> = vec![6, 8, 2, 6];
+        let expected_carry: Vec<usize> = vec![0, 1, 0, 0];
+
+        // Action
+        let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_unit, breakdown.units);
+        assert_eq!(expected_carry, breakdown.carries);
+    }
+
+    #[test]
+    fn test_break_down_multiplication_with_six_digit() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+        let expected_unit: Vec<usize> = vec![6, 2, 8, 5, 0, 5, 4, 8, 2];
+        let expected_carry: Vec<usize> = vec![0, 1, 1, 0, 1, 1, 0, 0, 1];
+
+        // Action
+        let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_unit, breakdown.units);
+        assert_eq!(expected_carry, breakdown.carries);
+    }
+
+    #[test]
+    fn test_break_down_multiplication_fields_match_the_tuple() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+
+        // Action
+        let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+        let (units, carries): (Vec<usize>, Vec<usize>) = breakdown.clone().into_parts();
+
+        // Assert
+        assert_eq!(units, breakdown.units);
+        assert_eq!(carries, breakdown.carries);
+        assert_eq!(vec![6, 2, 8, 5, 0, 5, 4, 8, 2], units);
+        assert_eq!(3, breakdown.rows());
+        assert_eq!("0 1 1 | 6 2 8\n0 1 1 | 5 0 5\n0 0 1 | 4 8 2\n", breakdown.to_string());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: break_down_addition
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_break_down_addition_product_one_digit() {
+        // Arrange
+        let multiplicand: String = String::from("2");
+        let multiplier: String = String::from("3");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![6, 0];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_product_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("9");
+        let multiplier: String = String::from("8");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![2, 7];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_with_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("37");
+        let multiplier: String = String::from("8");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![6, 9, 2];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_with_three_digits_switch() {
+        // Arrange
+        let multiplicand: String = String::from("8");
+        let multiplier: String = String::from("37");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![6, 9, 2];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
     fn test_break_down_addition_with_four_digit() {
         // Arrange
-        let multiplicand: String = String::from("13");
-        let multiplier: String = String::from("26");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![8, 13, 2, 0];
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![8, 13, 2, 0];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_with_six_digit() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![8, 8, 10, 15, 4, 0];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_with_eleven_digits_multiplier_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("78924358");
+        let multiplier: String = String::from("357");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![6, 10, 17, 24, 17, 8, 25, 25, 19, 6, 2];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    #[test]
+    fn test_break_down_addition_with_eleven_digits_multiplier_is_less() {
+        // Arrange
+        let multiplicand: String = String::from("357");
+        let multiplier: String = String::from("78924358");
+        let addition: Vec<usize>;
+        let expected_addition: Vec<usize> = vec![6, 10, 17, 24, 17, 8, 25, 25, 19, 6, 2];
+
+        // Action
+        addition = break_down_addition(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected_addition, addition);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: break_down_subtotal
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_break_down_subtotal_result_two_digits_with_zero() {
+        // Arrange
+        let value: Vec<usize> = vec![6, 0];
+        let expected: Vec<usize> = vec![6, 0];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_two_digits_without_zero() {
+        // Arrange
+        let value: Vec<usize> = vec![2, 4];
+        let expected: Vec<usize> = vec![2, 4];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_three_digits_with_zero() {
+        // Arrange
+        let value: Vec<usize> = vec![2, 9, 0];
+        let expected: Vec<usize> = vec![2, 9, 0];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_three_digits_without_zero() {
+        // Arrange
+        let value: Vec<usize> = vec![5, 8, 2];
+        let expected: Vec<usize> = vec![5, 8, 2];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_four_digits_with_zero() {
+        // Arrange
+        let value: Vec<usize> = vec![4, 8, 4, 0];
+        let expected: Vec<usize> = vec![4, 8, 4, 0];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_four_digits_with_zero_and_carry() {
+        // Arrange
+        let value: Vec<usize> = vec![4, 11, 6, 0];
+        let expected: Vec<usize> = vec![4, 1, 7, 0];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_four_digits_without_zero_and_carry() {
+        // Arrange
+        let value: Vec<usize> = vec![6, 12, 6, 2];
+        let expected: Vec<usize> = vec![6, 2, 7, 2];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_nine_digits_with_zero_and_carry() {
+        // Arrange
+        let value: Vec<usize> = vec![1, 10, 19, 27, 27, 27, 26, 17, 8];
+        let expected: Vec<usize> = vec![1, 0, 10, 8, 9, 9, 8, 9, 9];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_nine_digits_without_zero_and_carry() {
+        // Arrange
+        let value: Vec<usize> = vec![5, 10, 10, 10, 5, 16, 4, 0];
+        let expected: Vec<usize> = vec![5, 0, 1, 1, 6, 6, 5, 0];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_break_down_subtotal_result_eleven_digits_without_zero_and_carry() {
+        // Arrange
+        let value: Vec<usize> = vec![5, 12, 17, 14, 13, 8, 11, 26, 12, 10, 1];
+        let expected: Vec<usize> = vec![5, 2, 8, 5, 4, 9, 1, 7, 4, 1, 2];
+
+        // Action
+        let result: Vec<usize> = break_down_subtotal(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: fully_reduce
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_fully_reduce_without_passes() {
+        // Arrange
+        let value: Vec<usize> = vec![6, 0];
+        let expected: Vec<usize> = vec![6, 0];
+
+        // Action
+        let result: Vec<usize> = fully_reduce(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_fully_reduce_with_one_pass() {
+        // Arrange
+        let value: Vec<usize> = vec![12, 0];
+        let expected: Vec<usize> = vec![2, 1];
+
+        // Action
+        let result: Vec<usize> = fully_reduce(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_fully_reduce_with_two_passes() {
+        // Arrange
+        let value: Vec<usize> = vec![1, 10, 19, 27, 27, 27, 26, 17, 8];
+        let expected: Vec<usize> = vec![1, 0, 0, 9, 9, 9, 8, 9, 9];
+
+        // Action
+        let result: Vec<usize> = fully_reduce(&value);
+
+        // Assert
+        assert_eq!(expected, result);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: column_sums
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_column_sums_two_digits() {
+        // Arrange
+        let multiplicand: &str = "13";
+        let multiplier: &str = "26";
+        let expected: Vec<usize> = vec![0, 2, 13, 8];
+
+        // Action
+        let sums: Vec<usize> = column_sums(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, sums);
+    }
+
+    #[test]
+    fn test_column_sums_three_digits() {
+        // Arrange
+        let multiplicand: &str = "123";
+        let multiplier: &str = "456";
+        let expected: Vec<usize> = vec![0, 4, 15, 10, 8, 8];
+
+        // Action
+        let sums: Vec<usize> = column_sums(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, sums);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: product_digit_count
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_product_digit_count_fewer_than_the_operands() {
+        // Arrange
+        let multiplicand: &str = "2";
+        let multiplier: &str = "3";
+
+        // Action
+        let count: usize = product_digit_count(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn test_product_digit_count_equal_to_the_operands() {
+        // Arrange
+        let multiplicand: &str = "9";
+        let multiplier: &str = "9";
+
+        // Action
+        let count: usize = product_digit_count(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn test_product_digit_count_three_digits() {
+        // Arrange
+        let multiplicand: &str = "13";
+        let multiplier: &str = "26";
+
+        // Action
+        let count: usize = product_digit_count(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(3, count);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: units_per_multiplier
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_units_per_multiplier_with_four_digits() {
+        // Arrange
+        let multiplicand: &str = "13";
+        let multiplier: &str = "26";
+        let expected: Vec<Vec<usize>> = vec![vec![6, 8], vec![2, 6]];
+
+        // Action
+        let units: Vec<Vec<usize>> = units_per_multiplier(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, units);
+    }
+
+    #[test]
+    fn test_units_per_multiplier_with_one_multiplier_digit() {
+        // Arrange
+        let multiplicand: &str = "579";
+        let multiplier: &str = "8";
+        let expected: Vec<Vec<usize>> = vec![vec![0, 6, 2]];
+
+        // Action
+        let units: Vec<Vec<usize>> = units_per_multiplier(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, units);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: partial_products
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_partial_products_with_four_digits() {
+        // Arrange
+        let multiplicand: &str = "13";
+        let multiplier: &str = "26";
+        let expected: Vec<String> = vec!["78".to_string(), "260".to_string()];
+
+        // Action
+        let partials: Vec<String> = partial_products(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, partials);
+    }
+
+    #[test]
+    fn test_partial_products_with_one_multiplier_digit() {
+        // Arrange
+        let multiplicand: &str = "25";
+        let multiplier: &str = "3";
+        let expected: Vec<String> = vec!["75".to_string()];
+
+        // Action
+        let partials: Vec<String> = partial_products(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, partials);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: sum_partial_products
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_sum_partial_products_with_nine_digits() {
+        // Arrange
+        let multiplicand: &str = "13597";
+        let multiplier: &str = "8642";
+        let expected: &str = "117505274";
 
         // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+        let product: String = sum_partial_products(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected_addition, addition);
+        assert_eq!(expected, product);
     }
 
     #[test]
-    fn test_break_down_addition_with_six_digit() {
+    fn test_sum_partial_products_with_zero() {
         // Arrange
-        let multiplicand: String = String::from("123");
-        let multiplier: String = String::from("456");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![8, 8, 10, 15, 4, 0];
+        let multiplicand: &str = "0";
+        let multiplier: &str = "26";
+        let expected: &str = "0";
 
         // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+        let product: String = sum_partial_products(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected_addition, addition);
+        assert_eq!(expected, product);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: subtract_digit_strings
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_addition_with_eleven_digits_multiplier_is_greater() {
+    fn test_subtract_digit_strings_with_borrow() {
         // Arrange
-        let multiplicand: String = String::from("78924358");
-        let multiplier: String = String::from("357");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![6, 10, 17, 24, 17, 8, 25, 25, 19, 6, 2];
+        let minuend: &str = "1000";
+        let subtrahend: &str = "1";
+        let expected: &str = "999";
 
         // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+        let difference: String = subtract_digit_strings(minuend, subtrahend);
 
         // Assert
-        assert_eq!(expected_addition, addition);
+        assert_eq!(expected, difference);
     }
 
     #[test]
-    fn test_break_down_addition_with_eleven_digits_multiplier_is_less() {
+    fn test_subtract_digit_strings_equal() {
         // Arrange
-        let multiplicand: String = String::from("357");
-        let multiplier: String = String::from("78924358");
-        let addition: Vec<usize>;
-        let expected_addition: Vec<usize> = vec![6, 10, 17, 24, 17, 8, 25, 25, 19, 6, 2];
+        let minuend: &str = "338";
+        let subtrahend: &str = "338";
+        let expected: &str = "0";
 
         // Action
-        addition = break_down_addition(&multiplicand, &multiplier);
+        let difference: String = subtract_digit_strings(minuend, subtrahend);
 
         // Assert
-        assert_eq!(expected_addition, addition);
+        assert_eq!(expected, difference);
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: break_down_subtotal
+    // # Function: add_digit_strings
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_subtotal_result_two_digits_with_zero() {
+    fn test_add_digit_strings_with_carry() {
         // Arrange
-        let value: Vec<usize> = vec![6, 0];
-        let expected: Vec<usize> = vec![6, 0];
+        let multiplicand: &str = "999";
+        let multiplier: &str = "1";
+        let expected: &str = "1000";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let product: String = add_digit_strings(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(expected, product);
     }
 
     #[test]
-    fn test_break_down_subtotal_result_two_digits_without_zero() {
+    fn test_add_digit_strings_with_leading_zeros() {
         // Arrange
-        let value: Vec<usize> = vec![2, 4];
-        let expected: Vec<usize> = vec![2, 4];
+        let multiplicand: &str = "0";
+        let multiplier: &str = "0075";
+        let expected: &str = "75";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let product: String = add_digit_strings(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(expected, product);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: halve_digit_string
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_subtotal_result_three_digits_with_zero() {
+    fn test_halve_digit_string_odd() {
         // Arrange
-        let value: Vec<usize> = vec![2, 9, 0];
-        let expected: Vec<usize> = vec![2, 9, 0];
+        let number: &str = "1001";
+        let expected: &str = "500";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let half: String = halve_digit_string(number);
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(expected, half);
     }
 
     #[test]
-    fn test_break_down_subtotal_result_three_digits_without_zero() {
+    fn test_halve_digit_string_one() {
         // Arrange
-        let value: Vec<usize> = vec![5, 8, 2];
-        let expected: Vec<usize> = vec![5, 8, 2];
+        let number: &str = "1";
+        let expected: &str = "0";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let half: String = halve_digit_string(number);
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(expected, half);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: requires_carry
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_subtotal_result_four_digits_with_zero() {
+    fn test_requires_carry_without_carry() {
         // Arrange
-        let value: Vec<usize> = vec![4, 8, 4, 0];
-        let expected: Vec<usize> = vec![4, 8, 4, 0];
+        let multiplicand: &str = "11";
+        let multiplier: &str = "11";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let result: bool = requires_carry(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected, result);
+        assert!(!result);
     }
 
     #[test]
-    fn test_break_down_subtotal_result_four_digits_with_zero_and_carry() {
+    fn test_requires_carry_with_product_carry() {
         // Arrange
-        let value: Vec<usize> = vec![4, 11, 6, 0];
-        let expected: Vec<usize> = vec![4, 1, 7, 0];
+        let multiplicand: &str = "9";
+        let multiplier: &str = "9";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let result: bool = requires_carry(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected, result);
+        assert!(result);
     }
 
     #[test]
-    fn test_break_down_subtotal_result_four_digits_without_zero_and_carry() {
+    fn test_requires_carry_with_column_sum_carry() {
         // Arrange
-        let value: Vec<usize> = vec![6, 12, 6, 2];
-        let expected: Vec<usize> = vec![6, 2, 7, 2];
+        let multiplicand: &str = "33333";
+        let multiplier: &str = "3333";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let result: bool = requires_carry(multiplicand, multiplier);
 
         // Assert
-        assert_eq!(expected, result);
+        assert!(result);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: row_column_span
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_subtotal_result_nine_digits_with_zero_and_carry() {
+    fn test_row_column_span_five_digits_third_row() {
         // Arrange
-        let value: Vec<usize> = vec![1, 10, 19, 27, 27, 27, 26, 17, 8];
-        let expected: Vec<usize> = vec![1, 0, 10, 8, 9, 9, 8, 9, 9];
+        let row_index: usize = 2;
+        let a_len: usize = get_string_length(&String::from("13597"));
+        let expected: Range<usize> = 2..8;
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let span: Range<usize> = row_column_span(row_index, a_len);
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(expected, span);
     }
 
     #[test]
-    fn test_break_down_subtotal_result_nine_digits_without_zero_and_carry() {
+    fn test_row_column_span_matches_break_down_addition() {
         // Arrange
-        let value: Vec<usize> = vec![5, 10, 10, 10, 5, 16, 4, 0];
-        let expected: Vec<usize> = vec![5, 0, 1, 1, 6, 6, 5, 0];
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("246");
+        let columns: usize = break_down_addition(&multiplicand, &multiplier).len();
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let span: Range<usize> = row_column_span(2, get_string_length(&multiplicand));
 
         // Assert
-        assert_eq!(expected, result);
+        assert_eq!(columns, span.end);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: sanitize_operand
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_break_down_subtotal_result_eleven_digits_without_zero_and_carry() {
+    fn test_sanitize_operand_commas() {
         // Arrange
-        let value: Vec<usize> = vec![5, 12, 17, 14, 13, 8, 11, 26, 12, 10, 1];
-        let expected: Vec<usize> = vec![5, 2, 8, 5, 4, 9, 1, 7, 4, 1, 2];
+        let operand: &str = "1,234";
+        let expected: &str = "1234";
 
         // Action
-        let result: Vec<usize> = break_down_subtotal(&value);
+        let sanitized: String = sanitize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, sanitized);
+    }
+
+    #[test]
+    fn test_sanitize_operand_underscores() {
+        // Arrange
+        let operand: &str = "1_000";
+        let expected: &str = "1000";
+
+        // Action
+        let sanitized: String = sanitize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, sanitized);
+    }
+
+    #[test]
+    fn test_sanitize_operand_misplaced_separator() {
+        // Arrange
+        let operand: &str = "12,34";
+        let expected: &str = "1234";
+
+        // Action
+        let sanitized: String = sanitize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, sanitized);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: normalize_operand
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_normalize_operand_leading_zeros() {
+        // Arrange
+        let operand: &str = "007";
+        let expected: &str = "7";
+
+        // Action
+        let normalized: String = normalize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, normalized);
+    }
+
+    #[test]
+    fn test_normalize_operand_zero() {
+        // Arrange
+        let operand: &str = "0";
+        let expected: &str = "0";
+
+        // Action
+        let normalized: String = normalize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, normalized);
+    }
+
+    #[test]
+    fn test_normalize_operand_only_zeros() {
+        // Arrange
+        let operand: &str = "000";
+        let expected: &str = "0";
+
+        // Action
+        let normalized: String = normalize_operand(operand);
+
+        // Assert
+        assert_eq!(expected, normalized);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: compute_product_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_compute_product_with_options_hexadecimal() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+        let expected: &str = "FE01";
+
+        // Action
+        let product: String = compute_product_with_options("FF", "FF", &options);
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    #[test]
+    fn test_compute_product_with_options_signs() {
+        // Arrange
+        let negative: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
+        let positive: TableOptions = TableOptions { signs: (true, true), ..TableOptions::default() };
+
+        // Action
+        let products: (String, String, String) = (
+            compute_product_with_options("12", "3", &negative),
+            compute_product_with_options("12", "3", &positive),
+            compute_product_with_options("0", "3", &negative),
+        );
+
+        // Assert
+        assert_eq!((String::from("-36"), String::from("36"), String::from("0")), products);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: compute_product
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_compute_product_zero() {
+        // Arrange
+        let expected: &str = "0";
+
+        // Action
+        let product: String = compute_product("0", "999");
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    #[test]
+    fn test_compute_product_leading_zeros() {
+        // Arrange
+        let expected: &str = "21";
+
+        // Action
+        let product: String = compute_product("007", "3");
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    #[test]
+    fn test_compute_product_thirty_digits() {
+        // Arrange
+        let multiplicand: &str = "123456789012345678901234567890";
+        let multiplier: &str = "987654321098765432109876543210";
+        let expected: &str = "121932631137021795226185032733622923332237463801111263526900";
+
+        // Action
+        let product: String = compute_product(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: columns_to_product
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_columns_to_product_forty_digits() {
+        // Arrange
+        let multiplicand: String = String::from("1234567890123456789012345678901234567890");
+        let multiplier: String = String::from("9876543210987654321098765432109876543210");
+        let expected: &str = "12193263113702179522618503273386678859448712086533622923332237463801111263526900";
+
+        // Action
+        let product: String = columns_to_product(&break_down_addition(&multiplicand, &multiplier), 10);
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    #[test]
+    fn test_columns_to_product_zero() {
+        // Arrange
+        let columns: Vec<usize> = vec![0, 0, 0];
+        let expected: &str = "0";
+
+        // Action
+        let product: String = columns_to_product(&columns, 10);
+
+        // Assert
+        assert_eq!(expected, product);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: vector_value
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_vector_value_digits() {
+        // Arrange
+        let value: Vec<usize> = vec![8, 3, 3, 0];
+        let expected: u128 = 338;
+
+        // Action
+        let result: u128 = vector_value(&value);
 
         // Assert
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_vector_value_preserved_by_break_down_subtotal() {
+        // Arrange
+        let values: Vec<Vec<usize>> = vec![
+            vec![6, 0],
+            vec![1, 10, 19, 27, 27, 27, 26, 17, 8, 0],
+            vec![81, 162, 243, 324, 405, 324, 243, 162, 81, 0, 0, 0],
+            vec![9, 99, 999, 0, 0, 0],
+        ];
+
+        for value in values {
+            // Action
+            let result: Vec<usize> = break_down_subtotal(&value);
+
+            // Assert
+            assert_eq!(vector_value(&value), vector_value(&result));
+        }
+    }
+
+    #[test]
+    fn test_vector_value_preserved_by_break_down_addition() {
+        // Arrange
+        let multiplicand: String = String::from("987654321");
+        let multiplier: String = String::from("123456789");
+        let expected: u128 = 987654321 * 123456789;
+
+        // Action
+        let mut addition: Vec<usize> = break_down_addition(&multiplicand, &multiplier);
+        let before: u128 = vector_value(&addition);
+        while addition.iter().any(|number| number > &9) {
+            addition = break_down_subtotal(&addition);
+        }
+
+        // Assert
+        assert_eq!(expected, before);
+        assert_eq!(expected, vector_value(&addition));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: vector_value_with_radix
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_vector_value_with_radix_preserved_by_break_down_subtotal() {
+        // Arrange
+        let values: Vec<(Vec<usize>, u32)> = vec![
+            (vec![2, 3, 1, 0], 2),
+            (vec![15, 30, 225, 0, 0], 16),
+            (vec![7, 14, 8, 0], 8),
+        ];
+
+        for (value, radix) in values {
+            // Action
+            let result: Vec<usize> = break_down_subtotal_with_radix(&value, radix);
+
+            // Assert
+            assert_eq!(vector_value_with_radix(&value, radix), vector_value_with_radix(&result, radix));
+        }
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: reinsert_decimal
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_reinsert_decimal_one_and_a_half_times_two() {
+        // Arrange
+        let product: String = compute_product("15", "2");
+        let expected: &str = "3.0";
+
+        // Action
+        let decimal: String = reinsert_decimal(&product, 1);
+
+        // Assert
+        assert_eq!(expected, decimal);
+    }
+
+    #[test]
+    fn test_reinsert_decimal_one_tenth_squared() {
+        // Arrange
+        let product: String = compute_product("01", "01");
+        let expected: &str = "0.01";
+
+        // Action
+        let decimal: String = reinsert_decimal(&product, 2);
+
+        // Assert
+        assert_eq!(expected, decimal);
+    }
+
+    #[test]
+    fn test_reinsert_decimal_twelve_point_thirty_four_times_five() {
+        // Arrange
+        let product: String = compute_product("1234", "5");
+        let expected: &str = "61.70";
+
+        // Action
+        let decimal: String = reinsert_decimal(&product, 2);
+
+        // Assert
+        assert_eq!(expected, decimal);
+    }
 }
@@ -0,0 +1,843 @@
+use crate::breakdown::{
+    Breakdown, break_down_addition_with_radix, break_down_multiplication_with_radix,
+    break_down_subtotal_with_radix, char_to_digit,
+    compute_product_with_options, digit_to_char, fully_reduce_with_radix,
+    reinsert_decimal, to_radix_string,
+};
+use crate::generate;
+use crate::length::{get_string_length, get_strings_length};
+use crate::multiplication::step_count_with_radix;
+use crate::options::{ColorScheme, Footer, LegendContext, TableOptions};
+
+/// A cell of the table: its type and its digit, if any.
+type Cell = (&'static str, Option<usize>);
+
+/// The sections of the long multiplication in a given output format.
+///
+/// Each method stores its section in a text variable, in the
+/// order: symbols, operands, operations, sum, product and footer.
+/// The multiplication module chains them to build the table.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+///
+/// use long_multiplication_command_line::format::Markdown;
+/// use long_multiplication_command_line::multiplication::get_table_as;
+/// let text: String = get_table_as(&multiplicand, &multiplier, &Markdown::default());
+///
+/// assert!(text.contains("| P | 3 | 5 |\n"));
+/// ```
+pub trait OutputFormat {
+    /// Store the symbol description.
+    fn symbols(&self, text: &mut String);
+
+    /// Store the heading with the multiplicand and the multiplier.
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String);
+
+    /// Store the carry-over and the rows of each multiplier digit.
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String);
+
+    /// Store the sum of each column.
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String);
+
+    /// Store the product of the multiplication.
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String);
+
+    /// Store the footer, if the format has one.
+    fn footer(&self, _multiplicand: &String, _multiplier: &String, _text: &mut String) {}
+}
+
+/// The box-drawing text table, the default output.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Text {
+    // The options of the table.
+    pub options: TableOptions,
+}
+
+/// A Markdown document with the table as a pipe table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Markdown {
+    // The options of the table.
+    pub options: TableOptions,
+}
+
+/// An HTML fragment with the table as a `<table>` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Html {
+    // The options of the table.
+    pub options: TableOptions,
+}
+
+/// A LaTeX `array` environment with the carries as superscripts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Latex {
+    // The options of the table.
+    pub options: TableOptions,
+}
+
+/// A JSON object with the operands, the product, the partial-product
+/// rows, the sums of the columns and every cell.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Json {
+    // The options of the table.
+    pub options: TableOptions,
+}
+
+/// An SVG image with a `<rect>` per cell and a `<text>` per digit.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Svg {
+    // The options of the table, without colors the default `ColorScheme` is used.
+    pub options: TableOptions,
+}
+
+/// The size of the square cells of the SVG image, in pixels.
+const SVG_CELL: usize = 24;
+
+/// The width of the label column of the SVG image, in pixels.
+const SVG_LABEL: usize = 48;
+
+impl OutputFormat for Text {
+    fn symbols(&self, text: &mut String) {
+        // The trimmed legend needs the operands, see `operands`.
+        if self.options.symbols && !self.options.trim_symbols {
+            generate::symbols_with_options(&self.options, text);
+        }
+    }
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        if self.options.symbols && self.options.trim_symbols {
+            let context: LegendContext = generate::legend_context(multiplicand, multiplier, &self.options);
+            generate::symbols_with_context(&self.options, &context, text);
+        }
+        if self.options.place_values {
+            generate::place_values(multiplicand, multiplier, text);
+        }
+        if let Some(title) = &self.options.title {
+            generate::title_with_options(multiplicand, multiplier, title, &self.options, text);
+        }
+        generate::top_border_with_options(multiplicand, multiplier, &self.options, text);
+        if self.options.position_header {
+            generate::position_title_with_options(multiplicand, multiplier, &self.options, text);
+        }
+        generate::operation_title_with_options(multiplicand, multiplier, &self.options, text);
+        generate::multiplication_with_options(multiplicand, multiplier, &self.options, text);
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        generate::operations_with_options(multiplicand, multiplier, &self.options, text);
+        // The products are explained next to their rows, before the sum.
+        if self.options.explain {
+            generate::explanation_with_options(multiplicand, multiplier, &self.options, text);
+        }
+    }
+
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        if self.options.sum_section {
+            generate::sum_title_with_options(multiplicand, multiplier, &self.options, text);
+            generate::sum_rows_with_options(multiplicand, multiplier, &self.options, text);
+        }
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        generate::product_with_options(multiplicand, multiplier, &self.options, text);
+        generate::bottom_border_with_options(multiplicand, multiplier, &self.options, text);
+        generate::rows_note(self.options.rows, text);
+        if self.options.step_count {
+            text.push_str(&format!("Steps: {}\n", step_count_with_radix(multiplicand, multiplier, self.options.radix)));
+        }
+        if self.options.verify && self.options.radix == 10 {
+            generate::verification(multiplicand, multiplier, text);
+        }
+        if let Some(significant) = self.options.product_sig {
+            let radix: u32 = self.options.radix;
+            let mut digits: Vec<usize> = fully_reduce_with_radix(&break_down_addition_with_radix(multiplicand, multiplier, radix), radix);
+            digits.reverse();
+            generate::product_significant_with_radix(&digits, significant, radix, text);
+        }
+    }
+
+    fn footer(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        match self.options.footer {
+            Footer::Author => generate::author(text),
+            Footer::Stats => generate::stats_with_radix(multiplicand, multiplier, text.len(), self.options.radix, text),
+            Footer::None => {}
+        }
+    }
+}
+
+impl OutputFormat for Markdown {
+    fn symbols(&self, text: &mut String) {
+        text.push_str("## Symbols\n\n");
+        for line in symbol_lines() {
+            text.push_str(&format!("- {}\n", line.replace('*', "\\*")));
+        }
+        text.push('\n');
+    }
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let (multiplicand_label, multiplier_label): (String, String) = signed_operands(multiplicand, multiplier, &self.options);
+
+        text.push_str(&format!("## {multiplicand_label} x {multiplier_label}\n\n"));
+        push_markdown_header(multiplicand, multiplier, text);
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let grid: Vec<Vec<Cell>> = operation_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            push_markdown_row(&operation_label(index), cells, radix, text);
+        }
+    }
+
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let grid: Vec<Vec<Cell>> = sum_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            push_markdown_row(&format!("{} C", index + 1), cells, radix, text);
+        }
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let product: String = compute_product_with_options(multiplicand, multiplier, &self.options);
+
+        push_markdown_row("P", &product_cells(multiplicand, multiplier, radix), radix, text);
+        text.push_str(&format!("\n**Product:** {product}\n"));
+    }
+}
+
+impl OutputFormat for Html {
+    fn symbols(&self, text: &mut String) {
+        if !self.options.symbols {
+            return;
+        }
+        text.push_str("<h2>Symbols</h2>\n<ul>\n");
+        for line in symbol_lines() {
+            text.push_str(&format!("<li>{line}</li>\n"));
+        }
+        text.push_str("</ul>\n");
+    }
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let length: usize = get_strings_length(multiplicand, multiplier);
+        let radix: u32 = self.options.radix;
+        let (multiplicand_label, multiplier_label): (String, String) = signed_operands(multiplicand, multiplier, &self.options);
+
+        text.push_str(&format!("<h2>{multiplicand_label} &times; {multiplier_label}</h2>\n"));
+        text.push_str("<table>\n");
+        if self.options.position_header {
+            text.push_str(&format!("<tr><th>{}</th>", self.options.labels.position));
+            for position in (1..length + 1).rev() {
+                text.push_str(&format!("<th>{position}</th>"));
+            }
+            text.push_str("</tr>\n");
+        }
+        push_html_title(self.options.labels.operations, length, text);
+        push_html_row("operand", "", &operand_cells(multiplicand, length, radix), radix, text);
+        push_html_row("operand", "x", &operand_cells(multiplier, length, radix), radix, text);
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let grid: Vec<Vec<Cell>> = operation_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            let class: &str = if index.is_multiple_of(2) { "carry" } else { "row" };
+            push_html_row(class, &operation_label(index), cells, radix, text);
+        }
+    }
+
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        if !self.options.sum_section {
+            return;
+        }
+        let length: usize = get_strings_length(multiplicand, multiplier);
+        let radix: u32 = self.options.radix;
+
+        push_html_title(self.options.labels.sum, length, text);
+        let grid: Vec<Vec<Cell>> = sum_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            push_html_row("sum", &format!("{} C", index + 1), cells, radix, text);
+        }
+        for (round, grid) in subtotal_cells(multiplicand, multiplier, radix).iter().enumerate() {
+            push_html_title(&format!("{} {}.", self.options.labels.subtotal, round + 1), length, text);
+            for (index, cells) in grid.iter().enumerate() {
+                push_html_row("sum", &format!("{} C", index + 1), cells, radix, text);
+            }
+        }
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let length: usize = get_strings_length(multiplicand, multiplier);
+        let radix: u32 = self.options.radix;
+        let product: String = compute_product_with_options(multiplicand, multiplier, &self.options);
+
+        push_html_title(self.options.labels.product, length, text);
+        push_html_row("product", "P", &product_cells(multiplicand, multiplier, radix), radix, text);
+        text.push_str("</table>\n");
+        text.push_str(&format!("<p>Product: {product}</p>\n"));
+    }
+}
+
+impl OutputFormat for Json {
+    fn symbols(&self, text: &mut String) {
+        text.push('{');
+    }
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let (multiplicand_label, multiplier_label): (String, String) = signed_operands(multiplicand, multiplier, &self.options);
+        let product: String = compute_product_with_options(multiplicand, multiplier, &self.options);
+
+        text.push_str(&format!("\"multiplicand\":\"{multiplicand_label}\","));
+        text.push_str(&format!("\"multiplier\":\"{multiplier_label}\","));
+        text.push_str(&format!("\"product\":\"{product}\","));
+        text.push_str(&format!("\"base\":{radix},"));
+        push_json_rows(multiplicand, multiplier, radix, text);
+        push_json_sums(multiplicand, multiplier, radix, text);
+        text.push_str("\"cells\":[");
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        push_json_cells(&operation_cells(multiplicand, multiplier, self.options.radix), 0, text);
+    }
+
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let first_row: usize = operation_cells(multiplicand, multiplier, radix).len();
+
+        text.push(',');
+        push_json_cells(&sum_cells(multiplicand, multiplier, radix), first_row, text);
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let first_row: usize = operation_cells(multiplicand, multiplier, radix).len() + sum_cells(multiplicand, multiplier, radix).len();
+
+        text.push(',');
+        push_json_cells(&[product_cells(multiplicand, multiplier, radix)], first_row, text);
+        text.push_str("]}");
+    }
+}
+
+impl OutputFormat for Latex {
+    fn symbols(&self, _text: &mut String) {}
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let length: usize = get_strings_length(multiplicand, multiplier);
+        let (multiplicand_negative, multiplier_negative): (bool, bool) = self.options.signs;
+        let (multiplicand_decimals, multiplier_decimals): (usize, usize) = self.options.decimals;
+
+        text.push_str("\\[\n");
+        text.push_str(&format!("\\begin{{array}}{{r{}}}\n", "c".repeat(length)));
+        push_latex_row("", &latex_operand_cells(multiplicand, length, multiplicand_negative, multiplicand_decimals), text);
+        push_latex_row("\\times", &latex_operand_cells(multiplier, length, multiplier_negative, multiplier_decimals), text);
+        text.push_str("\\hline\n");
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let grid: Vec<Vec<Cell>> = operation_cells(multiplicand, multiplier, radix);
+        for rows in grid.chunks(2) {
+            // The carry is a superscript before the unit of the same column.
+            let mut cells: Vec<String> = Vec::new();
+            for ((_, carry), (_, unit)) in rows[0].iter().zip(rows[1].iter()) {
+                let mut cell: String = String::from("");
+                if let Some(digit) = carry {
+                    cell.push_str(&format!("{{}}^{{{}}}", digit_to_char(*digit, radix)));
+                }
+                if let Some(digit) = unit {
+                    cell.push(digit_to_char(*digit, radix));
+                }
+                cells.push(cell);
+            }
+            push_latex_row("", &cells, text);
+        }
+    }
+
+    fn sum(&self, _multiplicand: &String, _multiplier: &String, text: &mut String) {
+        text.push_str("\\hline\n");
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let cells: Vec<String> = product_labels(multiplicand, multiplier, &self.options);
+
+        push_latex_row("", &cells, text);
+        text.push_str("\\end{array}\n");
+        text.push_str("\\]\n");
+    }
+}
+
+impl OutputFormat for Svg {
+    fn symbols(&self, _text: &mut String) {}
+
+    fn operands(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let length: usize = get_strings_length(multiplicand, multiplier);
+        let radix: u32 = self.options.radix;
+        let rows: usize = operation_cells(multiplicand, multiplier, radix).len() + sum_cells(multiplicand, multiplier, radix).len() + 2;
+        let width: usize = SVG_LABEL + length * SVG_CELL;
+        let height: usize = rows * SVG_CELL;
+        let colors: ColorScheme = self.options.colors.unwrap_or_default();
+
+        text.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\" width=\"{width}\" height=\"{height}\">\n"
+        ));
+        text.push_str("<style>\n");
+        text.push_str("text { font-family: monospace; font-size: 14px; text-anchor: middle; dominant-baseline: central; }\n");
+        text.push_str(&format!("rect {{ fill: none; stroke: black; {} }}\n", sgr_to_css(colors.border)));
+        text.push_str(&format!("text.carry {{ {} }}\n", sgr_to_css(colors.carry)));
+        text.push_str(&format!("text.product {{ {} }}\n", sgr_to_css(colors.product)));
+        text.push_str("</style>\n");
+
+        let y: usize = SVG_CELL / 2;
+        text.push_str(&format!("<text class=\"label\" x=\"{}\" y=\"{y}\">{}</text>\n", SVG_LABEL / 2, self.options.labels.position));
+        for column in 0..length {
+            let x: usize = SVG_LABEL + column * SVG_CELL + SVG_CELL / 2;
+            text.push_str(&format!("<text class=\"label\" x=\"{x}\" y=\"{y}\">{}</text>\n", length - column));
+        }
+    }
+
+    fn operations(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let grid: Vec<Vec<Cell>> = operation_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            push_svg_row(index + 1, &operation_label(index), &cell_labels(cells, radix), text);
+        }
+    }
+
+    fn sum(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let first_row: usize = operation_cells(multiplicand, multiplier, radix).len() + 1;
+
+        let grid: Vec<Vec<Cell>> = sum_cells(multiplicand, multiplier, radix);
+        for (index, cells) in grid.iter().enumerate() {
+            push_svg_row(first_row + index, &format!("{} C", index + 1), &cell_labels(cells, radix), text);
+        }
+    }
+
+    fn product(&self, multiplicand: &String, multiplier: &String, text: &mut String) {
+        let radix: u32 = self.options.radix;
+        let row: usize = operation_cells(multiplicand, multiplier, radix).len() + sum_cells(multiplicand, multiplier, radix).len() + 1;
+        let labels: Vec<(&str, Option<String>)> = product_labels(multiplicand, multiplier, &self.options).into_iter()
+            .map(|label| ("product", Some(label)))
+            .collect();
+
+        push_svg_row(row, "P", &labels, text);
+        text.push_str("</svg>\n");
+    }
+}
+
+fn symbol_lines() -> Vec<String> {
+    let mut symbols: String = String::from("");
+    generate::symbols(&mut symbols);
+
+    return symbols.lines().skip(2).filter(|line| !line.is_empty()).map(String::from).collect();
+}
+
+fn operation_label(index: usize) -> String {
+    if index.is_multiple_of(2) {
+        return format!("{} ^", index / 2 + 1);
+    }
+
+    return format!("{} R", index / 2 + 1);
+}
+
+pub(crate) fn signed_operands(multiplicand: &String, multiplier: &String, options: &TableOptions) -> (String, String) {
+    let mut labels: Vec<String> = Vec::new();
+    for (operand, negative, decimals) in [
+        (multiplicand, options.signs.0, options.decimals.0),
+        (multiplier, options.signs.1, options.decimals.1),
+    ] {
+        let sign: &str = if negative { "-" } else { "" };
+        labels.push(format!("{sign}{}", reinsert_decimal(operand, decimals)));
+    }
+
+    return (labels[0].clone(), labels[1].clone());
+}
+
+fn product_labels(multiplicand: &String, multiplier: &String, options: &TableOptions) -> Vec<String> {
+    let radix: u32 = options.radix;
+    let mut labels: Vec<String> = product_cells(multiplicand, multiplier, radix).iter()
+        .map(|(_, value)| value.map_or(String::from(""), |digit| digit_to_char(digit, radix).to_string()))
+        .collect();
+
+    // The point follows the units digit, and the sign takes the place of the leading zero.
+    let decimals: usize = options.product_decimals();
+    let units: usize = labels.len() - 1 - decimals.min(labels.len() - 1);
+    if decimals > 0 {
+        labels[units].push('.');
+    }
+    let first: usize = labels.iter().position(|label| !label.starts_with('0')).unwrap_or(units).min(units);
+    let negative: bool = compute_product_with_options(multiplicand, multiplier, options).starts_with('-');
+    if negative && first > 0 {
+        labels[first - 1] = String::from("-");
+    } else if negative {
+        labels[0].insert(0, '-');
+    }
+
+    return labels;
+}
+
+fn operand_cells(operand: &String, length: usize, radix: u32) -> Vec<Cell> {
+    let mut cells: Vec<Cell> = vec![("blank", None); length - get_string_length(operand)];
+    cells.extend(operand.chars().map(|digit| ("operand", Some(char_to_digit(digit, radix)))));
+
+    return cells;
+}
+
+fn operation_cells(multiplicand: &String, multiplier: &String, radix: u32) -> Vec<Vec<Cell>> {
+    let length: usize = get_strings_length(multiplicand, multiplier);
+    let step: usize = get_string_length(multiplicand);
+
+    let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, radix);
+    let units: &[usize] = breakdown.units();
+    let carriers: &[usize] = breakdown.carries();
+
+    let mut grid: Vec<Vec<Cell>> = Vec::new();
+    for (index, start) in (0..units.len()).step_by(step).enumerate() {
+        let mut carry_row: Vec<Cell> = vec![("blank", None); length];
+        let mut unit_row: Vec<Cell> = vec![("blank", None); length];
+        let first_column: usize = length - step - index - 1;
+        for offset in 0..step {
+            carry_row[first_column + offset] = ("carry", Some(carriers[start + offset]));
+            unit_row[first_column + offset + 1] = ("unit", Some(units[start + offset]));
+        }
+        grid.push(carry_row);
+        grid.push(unit_row);
+    }
+
+    return grid;
+}
+
+fn sum_cells(multiplicand: &String, multiplier: &String, radix: u32) -> Vec<Vec<Cell>> {
+    let length: usize = get_strings_length(multiplicand, multiplier);
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+
+    return column_cells(&additions, length, radix);
+}
+
+fn subtotal_cells(multiplicand: &String, multiplier: &String, radix: u32) -> Vec<Vec<Vec<Cell>>> {
+    let length: usize = get_strings_length(multiplicand, multiplier);
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+
+    // Like the text table, a round is drawn while a column is not a single digit.
+    let mut rounds: Vec<Vec<Vec<Cell>>> = Vec::new();
+    let mut sub_addition: Vec<usize> = break_down_subtotal_with_radix(&additions, radix);
+    while sub_addition.iter().any(|number| *number >= radix as usize) {
+        rounds.push(column_cells(&sub_addition, length, radix));
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
+    }
+
+    return rounds;
+}
+
+fn column_cells(additions: &[usize], length: usize, radix: u32) -> Vec<Vec<Cell>> {
+    let mut grid: Vec<Vec<Cell>> = Vec::new();
+    for (index, number) in additions.iter().enumerate() {
+        let mut sum_row: Vec<Cell> = vec![("blank", None); length];
+        let digits: Vec<usize> = to_radix_string(*number, radix).chars().map(|c| char_to_digit(c, radix)).collect();
+        let first_column: usize = length - index - digits.len();
+        for (offset, digit) in digits.iter().enumerate() {
+            sum_row[first_column + offset] = ("sum", Some(*digit));
+        }
+        grid.push(sum_row);
+    }
+
+    return grid;
+}
+
+fn product_cells(multiplicand: &String, multiplier: &String, radix: u32) -> Vec<Cell> {
+    let mut product: Vec<usize> = fully_reduce_with_radix(&break_down_addition_with_radix(multiplicand, multiplier, radix), radix);
+    product.reverse();
+
+    return product.iter().map(|digit| ("product", Some(*digit))).collect();
+}
+
+pub(crate) fn push_markdown_header(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let length: usize = get_strings_length(multiplicand, multiplier);
+
+    text.push_str("| Pos. |");
+    for position in (1..length + 1).rev() {
+        text.push_str(&format!(" {position} |"));
+    }
+    text.push('\n');
+    text.push_str("| --- |");
+    for _ in 0..length {
+        text.push_str(" --- |");
+    }
+    text.push('\n');
+}
+
+fn push_markdown_row(label: &str, cells: &[Cell], radix: u32, text: &mut String) {
+    text.push_str(&format!("| {label} |"));
+    for (_, value) in cells {
+        match value {
+            Some(digit) => text.push_str(&format!(" {} |", digit_to_char(*digit, radix))),
+            None => text.push_str("   |"),
+        }
+    }
+    text.push('\n');
+}
+
+fn cell_labels(cells: &[Cell], radix: u32) -> Vec<(&'static str, Option<String>)> {
+    return cells.iter().map(|(kind, value)| (*kind, value.map(|digit| digit_to_char(digit, radix).to_string()))).collect();
+}
+
+fn push_svg_row(row: usize, label: &str, cells: &[(&str, Option<String>)], text: &mut String) {
+    let top: usize = row * SVG_CELL;
+    let y: usize = top + SVG_CELL / 2;
+
+    text.push_str(&format!("<text class=\"label\" x=\"{}\" y=\"{y}\">{label}</text>\n", SVG_LABEL / 2));
+    for (column, (kind, value)) in cells.iter().enumerate() {
+        let left: usize = SVG_LABEL + column * SVG_CELL;
+        text.push_str(&format!("<rect x=\"{left}\" y=\"{top}\" width=\"{SVG_CELL}\" height=\"{SVG_CELL}\"/>\n"));
+        if let Some(digit) = value {
+            let x: usize = left + SVG_CELL / 2;
+            text.push_str(&format!("<text class=\"{kind}\" x=\"{x}\" y=\"{y}\">{digit}</text>\n"));
+        }
+    }
+}
+
+fn sgr_to_css(code: &str) -> String {
+    // The SGR parameters of the color scheme, like `1;33`, as CSS.
+    let names: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+    let mut declarations: Vec<String> = Vec::new();
+    for parameter in code.split(';') {
+        match parameter.parse::<usize>() {
+            Ok(1) => declarations.push(String::from("font-weight: bold;")),
+            Ok(2) => declarations.push(String::from("opacity: 0.5;")),
+            Ok(color @ 30..=37) => declarations.push(format!("fill: {};", names[color - 30])),
+            _ => {}
+        }
+    }
+    return declarations.join(" ");
+}
+
+fn push_html_title(title: &str, length: usize, text: &mut String) {
+    text.push_str(&format!("<tr><th colspan=\"{}\">{title}</th></tr>\n", length + 1));
+}
+
+fn push_html_row(class: &str, label: &str, cells: &[Cell], radix: u32, text: &mut String) {
+    text.push_str(&format!("<tr class=\"{class}\"><th>{label}</th>"));
+    for (kind, value) in cells {
+        match value {
+            Some(digit) => text.push_str(&format!("<td class=\"{kind}\">{}</td>", digit_to_char(*digit, radix))),
+            None => text.push_str("<td></td>"),
+        }
+    }
+    text.push_str("</tr>\n");
+}
+
+fn latex_operand_cells(operand: &String, length: usize, negative: bool, decimals: usize) -> Vec<String> {
+    let mut digits: Vec<String> = operand.chars().map(String::from).collect();
+    if decimals > 0 && decimals < digits.len() {
+        let units: usize = digits.len() - 1 - decimals;
+        digits[units].push('.');
+    }
+    if negative {
+        digits[0].insert(0, '-');
+    }
+
+    let mut cells: Vec<String> = vec![String::from(""); length - get_string_length(operand)];
+    cells.extend(digits);
+
+    return cells;
+}
+
+fn push_latex_row(label: &str, cells: &[String], text: &mut String) {
+    text.push_str(label);
+    for cell in cells {
+        text.push_str(&format!(" & {cell}"));
+    }
+    text.push_str(" \\\\\n");
+}
+
+fn push_json_rows(multiplicand: &String, multiplier: &String, radix: u32, text: &mut String) {
+    let step: usize = get_string_length(multiplicand);
+
+    let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, radix);
+    let units: &[usize] = breakdown.units();
+    let carriers: &[usize] = breakdown.carries();
+
+    let mut rows: Vec<String> = Vec::new();
+    for start in (0..units.len()).step_by(step) {
+        let carries: String = json_array(&carriers[start..start + step]);
+        let row_units: String = json_array(&units[start..start + step]);
+        rows.push(format!("{{\"carries\":{carries},\"units\":{row_units}}}"));
+    }
+    text.push_str(&format!("\"rows\":[{}],", rows.join(",")));
+}
+
+fn push_json_sums(multiplicand: &String, multiplier: &String, radix: u32, text: &mut String) {
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+
+    let mut subtotals: Vec<String> = Vec::new();
+    let mut sub_addition: Vec<usize> = additions.clone();
+    while sub_addition.iter().any(|number| *number >= radix as usize) {
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
+        subtotals.push(json_array(&sub_addition));
+    }
+    text.push_str(&format!("\"columnSums\":{},", json_array(&additions)));
+    text.push_str(&format!("\"subtotals\":[{}],", subtotals.join(",")));
+}
+
+fn json_array(numbers: &[usize]) -> String {
+    let numbers: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+
+    return format!("[{}]", numbers.join(","));
+}
+
+fn push_json_cells(grid: &[Vec<Cell>], first_row: usize, text: &mut String) {
+    let mut cells: Vec<String> = Vec::new();
+    for (row, row_cells) in grid.iter().enumerate() {
+        for (col, (kind, value)) in row_cells.iter().enumerate() {
+            let value: String = match value {
+                Some(digit) => digit.to_string(),
+                None => String::from("null"),
+            };
+            cells.push(format!("{{\"row\":{},\"col\":{col},\"type\":\"{kind}\",\"value\":{value}}}", first_row + row));
+        }
+    }
+    text.push_str(&cells.join(","));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multiplication::{get_table, get_table_as};
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Text
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_text_matches_get_table() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Text::default());
+
+        // Assert
+        assert_eq!(get_table(&multiplicand, &multiplier).unwrap().as_bytes(), text.as_bytes());
+    }
+
+    #[test]
+    fn test_text_matches_get_table_with_subtotals() {
+        // Arrange
+        let multiplicand: String = String::from("99999");
+        let multiplier: String = String::from("99999");
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Text::default());
+
+        // Assert
+        assert_eq!(get_table(&multiplicand, &multiplier).unwrap(), text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Markdown
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_markdown_table() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let expected: &str = "## 13 x 26\n\
+                              \n\
+                              | Pos. | 4 | 3 | 2 | 1 |\n\
+                              | --- | --- | --- | --- | --- |\n\
+                              | 1 ^ |   | 0 | 1 |   |\n";
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Markdown::default());
+
+        // Assert
+        assert!(text.starts_with("## Symbols\n\n- Pos. = Position.\n"));
+        assert!(text.contains(expected));
+        assert!(text.contains("| 1 R |   |   | 6 | 8 |\n"));
+        assert!(text.contains("| 4 C | 0 |   |   |   |\n"));
+        assert!(text.ends_with("| P | 0 | 3 | 3 | 8 |\n\n**Product:** 338\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Html
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_html_table() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Html::default());
+
+        // Assert
+        assert!(text.contains("<h2>5 &times; 7</h2>\n<table>\n<tr><th>Pos.</th><th>2</th><th>1</th></tr>\n"));
+        assert!(text.contains("<tr class=\"carry\"><th>1 ^</th><td class=\"carry\">3</td><td></td></tr>\n"));
+        assert!(text.contains("<tr class=\"row\"><th>1 R</th><td></td><td class=\"unit\">5</td></tr>\n"));
+        assert!(text.ends_with("<tr class=\"product\"><th>P</th><td class=\"product\">3</td><td class=\"product\">5</td></tr>\n</table>\n<p>Product: 35</p>\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Latex
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_latex_array() {
+        // Arrange
+        let multiplicand: String = String::from("9");
+        let multiplier: String = String::from("3");
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Latex::default());
+
+        // Assert
+        assert!(text.starts_with("\\[\n\\begin{array}{rcc}\n &  & 9 \\\\\n\\times &  & 3 \\\\\n\\hline\n"));
+        assert!(text.contains(" & {}^{2} & 7 \\\\\n"));
+        assert!(text.ends_with("\\hline\n & 2 & 7 \\\\\n\\end{array}\n\\]\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Svg
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_svg_image() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let format: Svg = Svg::default();
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &format);
+
+        // Assert
+        assert!(text.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 96 144\" width=\"96\" height=\"144\">\n"));
+        assert!(text.contains("text.product { font-weight: bold; fill: green; }\n"));
+        assert!(text.contains("<text class=\"carry\" x=\"60\" y=\"36\">3</text>\n"));
+        assert!(text.ends_with("<text class=\"product\" x=\"84\" y=\"132\">5</text>\n</svg>\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Struct: Json
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_json_cells() {
+        // Arrange
+        let multiplicand: String = String::from("951");
+        let multiplier: String = String::from("46");
+
+        // Action
+        let text: String = get_table_as(&multiplicand, &multiplier, &Json::default());
+
+        // Assert
+        assert_eq!(50, text.matches("\"row\":").count());
+        assert!(text.starts_with("{\"multiplicand\":\"951\",\"multiplier\":\"46\",\"product\":\"43746\",\"base\":10,\"rows\":["));
+        assert!(text.contains("\"rows\":[{\"carries\":[5,3,0],\"units\":[4,0,6]},{\"carries\":[3,2,0],\"units\":[6,0,4]}],"));
+        assert!(text.ends_with("]}"));
+    }
+}
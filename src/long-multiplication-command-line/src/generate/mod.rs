@@ -1,5 +1,11 @@
-use crate::breakdown::{break_down_addition, break_down_multiplication, break_down_subtotal};
-use crate::length::{get_number_length, get_string_length, get_strings_length};
+use std::ops::Range;
+
+use crate::breakdown::{
+    Breakdown, break_down_addition, break_down_addition_with_radix, break_down_multiplication_with_radix, break_down_subtotal,
+    break_down_subtotal_with_radix, add_digit_strings, compute_product, compute_product_with_options, subtract_digit_strings, digit_to_char, fully_reduce_with_radix, halve_digit_string, normalize_operand, sum_partial_products, to_radix_string,
+};
+use crate::length::{get_string_length, get_strings_length, place_value_name};
+use crate::options::{CarryStyle, ColorScheme, DigitSet, Labels, LegendContext, ProductAlign, RowFilter, RowOrder, SumVerbosity, TableOptions, TableStyle, TopOperand};
 
 /// Store the symbol description of the long multiplication.
 ///
@@ -32,18 +38,211 @@ use crate::length::{get_number_length, get_string_length, get_strings_length};
 /// assert_eq!(expected, text);
 /// ```
 pub fn symbols(text: &mut String) {
-    text.push_str("Symbols\n");
-    text.push_str("=======\n");
-    text.push_str("Pos. = Position.\n");
-    text.push_str("Ops. = Operations of the long multiplication.\n");
-    text.push_str("Sum. = Sum of each column of the multiplication.\n");
-    text.push_str("Sub n. = Subtotal of the last sum.\n");
-    text.push_str("Pro. = Product of the multiplication.\n");
-    text.push_str("n ^ = Carry-over.\n");
-    text.push_str("n R = The row number.\n");
-    text.push_str("n C = The column number of the sum of the rows.\n");
-    text.push_str("* Replace 'n' for a number.\n");
-    text.push_str("P = The product of multiplication.\n");
+    let options: TableOptions = TableOptions::default();
+
+    symbols_with_options(&options, text);
+}
+
+/// Store the symbol description of the long multiplication using the given options.
+///
+/// It generates the table symbols in the language of the
+/// labels of the options and stores it in a text variable.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let mut text: String = String::from("");
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{Labels, TableOptions};
+/// let options: TableOptions = TableOptions { labels: Labels::spanish(), ..TableOptions::default() };
+/// generate::symbols_with_options(&options, &mut text);
+///
+/// assert!(text.starts_with("Símbolos\n========\nPosic. = Posición.\n"));
+/// ```
+pub fn symbols_with_options(options: &TableOptions, text: &mut String) {
+    symbols_with_context(options, &LegendContext::default(), text);
+}
+
+/// Store the symbol description of the sections in the table.
+///
+/// It generates the table symbols like `symbols_with_options`,
+/// without the lines of the sections that the context marks as
+/// absent, like `Sub n.` when the sum has no subtotal.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let mut text: String = String::from("");
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{LegendContext, TableOptions};
+/// let context: LegendContext = LegendContext { subtotals: false, ..LegendContext::default() };
+/// generate::symbols_with_context(&TableOptions::default(), &context, &mut text);
+///
+/// assert!(text.contains("Sum. = Sum of each column of the multiplication.\n\
+///                        Pro. = Product of the multiplication.\n"));
+/// ```
+pub fn symbols_with_context(options: &TableOptions, context: &LegendContext, text: &mut String) {
+    let labels: &Labels = &options.labels;
+
+    text.push_str(labels.symbols);
+    text.push('\n');
+    text.push_str(&"=".repeat(labels.symbols.chars().count()));
+    text.push('\n');
+    for (entry, line) in labels.legend {
+        if context.includes(entry) {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+    text.push('\n');
+}
+
+/// Get the sections of the table that have a line in the legend.
+///
+/// The subtotal sections are drawn only while a sum of the
+/// columns has more than one digit, so short tables, like
+/// `5 * 7`, have none. The carry rows are dropped with the
+/// superscript carries, and the rows of the partial products
+/// when the filter of the rows leaves none.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{LegendContext, TableOptions};
+/// let options: TableOptions = TableOptions::default();
+///
+/// assert_eq!(false, generate::legend_context(&String::from("5"), &String::from("7"), &options).subtotals);
+/// assert_eq!(true, generate::legend_context(&String::from("99999"), &String::from("99999"), &options).subtotals);
+/// ```
+pub fn legend_context(multiplicand: &String, multiplier: &String, options: &TableOptions) -> LegendContext {
+    let radix: u32 = options.radix;
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+    let subtotals: bool = requires_subtotal(&break_down_subtotal_with_radix(&additions, radix), radix);
+    let (rows, _): (Vec<usize>, usize) = displayed_rows(get_string_length(multiplier), options);
+    let superscript: bool = options.carry_style == CarryStyle::Superscript && options.layout.cell_width > 1;
+
+    let context: LegendContext = LegendContext {
+        position: options.position_header,
+        sum: options.sum_section,
+        subtotals: options.sum_section && subtotals,
+        columns: options.sum_section && options.sum == SumVerbosity::Full,
+        carries: !rows.is_empty() && !superscript,
+        rows: !rows.is_empty(),
+    };
+    return context;
+}
+
+/// Store the title of the long multiplication.
+///
+/// It generates the table title for the
+/// long multiplication and stores it in a text variable.
+///
+/// The title is centered over the width of the table.
+/// If the title is wider than the table, it is not indented.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let title: &str = "Warm-up";
+/// let mut text: String = String::from("");
+/// let expected: &str = " Warm-up\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::title(&multiplicand, &multiplier, title, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn title(multiplicand: &String, multiplier: &String, title: &str, text: &mut String) {
+    let options: TableOptions = TableOptions::default();
+
+    title_with_options(multiplicand, multiplier, title, &options, text);
+}
+
+/// Store the title of the long multiplication using the given options.
+///
+/// It works like `title`, but the title is centered over the
+/// width of the table drawn with the cell width of the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let title: &str = "Hi";
+/// let mut text: String = String::from("");
+/// let expected: &str = " Hi\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{COMPACT_CELL_WIDTH, LayoutConfig, TableOptions};
+/// let layout: LayoutConfig = LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() };
+/// let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+/// generate::title_with_options(&multiplicand, &multiplier, title, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn title_with_options(multiplicand: &String, multiplier: &String, title: &str, options: &TableOptions, text: &mut String) {
+    let length: usize = table_length(multiplicand, multiplier, options);
+    let width: usize = length * (options.layout.cell_width + 1) + 1;
+    let title_len: usize = title.chars().count();
+
+    let start_spaces: usize = width.saturating_sub(title_len) / 2;
+    for _ in 0..start_spaces {
+        text.push(' ');
+    }
+    text.push_str(title);
+    text.push('\n');
+}
+
+/// Store the place values of the columns of the long multiplication.
+///
+/// It generates the name of the place value of each column
+/// of the `Pos.` header, e.g. ones, tens and hundreds, and
+/// stores it in a text variable. The names do not fit in the
+/// cells, so they are listed as an annotation above the table.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "Place values\n\
+///                       ============\n\
+///                       Pos. 1 = ones.\n\
+///                       Pos. 2 = tens.\n\
+///                       Pos. 3 = hundreds.\n\
+///                       \n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::place_values(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn place_values(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let length: usize = get_strings_length(multiplicand, multiplier);
+
+    text.push_str("Place values\n");
+    text.push_str("============\n");
+    for col in 1..length + 1 {
+        text.push_str(&format!("Pos. {col} = {}.\n", place_value_name(col)));
+    }
     text.push('\n');
 }
 
@@ -81,15 +280,40 @@ pub fn symbols(text: &mut String) {
 /// assert_eq!(expected, text);
 /// ```
 pub fn top_border(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
+
+    top_border_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the top border of the long multiplication using the given options.
+///
+/// It works like `top_border`, but it draws the top border
+/// with the glyphs of the border style of the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("2");
+/// let multiplier: String = String::from("75");
+/// let mut text: String = String::from("");
+/// let expected: &str = "+-----------+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::top_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn top_border_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push('┏');
-    for _ in 1..(length * 3) + length {
-        text.push('━');
-    }
-    text.push('┓');
-    text.push('\n');
+    push_rule(style.top_left, style.heavy, style.heavy, style.top_right, length, width, text);
 }
 
 /// Store the bottom border of the long multiplication.
@@ -126,19 +350,41 @@ pub fn top_border(multiplicand: &String, multiplier: &String, text: &mut String)
 /// assert_eq!(expected, text);
 /// ```
 pub fn bottom_border(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
+
+    bottom_border_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the bottom border of the long multiplication using the given options.
+///
+/// It works like `bottom_border`, but it draws the bottom border
+/// with the glyphs of the border style of the options. It closes
+/// the product row, so it is widened by `LayoutConfig::pad_product_to`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("12");
+/// let multiplier: String = String::from("57");
+/// let mut text: String = String::from("");
+/// let expected: &str = "+---+---+---+---+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::bottom_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn bottom_border_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push('┗');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┷');
-    }
-    text.push('┛');
-    text.push('\n');
+    push_rule(style.bottom_left, style.heavy, style.heavy_up, style.bottom_right, length, width, text);
 }
 
 /// Store the position title of the long multiplication.
@@ -181,58 +427,84 @@ pub fn bottom_border(multiplicand: &String, multiplier: &String, text: &mut Stri
 /// assert_eq!(expected, text);
 /// ```
 pub fn position_title(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
+
+    position_title_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the position title of the long multiplication using the given options.
+///
+/// It works like `position_title`, but it draws the position title
+/// with the glyphs of the border style and the cell width of the
+/// options. The positions wider than the cells, like `10` in the
+/// compact cells, keep only their last digits, as in a ruler.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("7");
+/// let multiplier: String = String::from("8");
+/// let mut text: String = String::from("");
+/// let expected: &str = "|Pos.   |\n\
+///                       +---+---+\n\
+///                       | 2 | 1 |\n\
+///                       +---+---+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::position_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let multiplicand: String = String::from("123456");
+/// let multiplier: String = String::from("7890");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃Pos.               ┃\n\
+///                       ┠┄┬┄┬┄┬┄┬┄┬┄┬┄┬┄┬┄┬┄┨\n\
+///                       ┃0│9│8│7│6│5│4│3│2│1┃\n\
+///                       ┣━┷━┷━┷━┷━┷━┷━┷━┷━┷━┫\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{COMPACT_CELL_WIDTH, LayoutConfig, TableOptions};
+/// let layout: LayoutConfig = LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() };
+/// let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+/// generate::position_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn position_title_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push_str("┃Pos.");
-    for _ in 1..(length * 3) + length - 4 {
-        text.push(' ');
-    }
-    text.push('┃');
+    push_title(options.labels.position, length, width, style, text);
     text.push('\n');
 
     // Create second row
-    text.push('┠');
-    for n in 1..length + 1 {
-        text.push_str("┄┄┄");
-        if n == length {
-            break;
-        }
-        text.push('┬');
-    }
-    text.push('┨');
-    text.push('\n');
+    push_rule(style.light_left, style.dashed, style.light_down, style.light_right, length, width, text);
 
     // Create third row
-    text.push('┃');
+    text.push(style.outer);
     for n in 1..length + 1 {
         let number: usize = length + 1 - n;
-        if number < 100 {
-            text.push(' ');
-        }
-        text.push_str(&*number.to_string());
-        if number < 10 {
-            text.push(' ');
-        }
+        push_cell(&number.to_string(), width, text);
         if n == length {
             break;
         }
-        text.push('│');
+        text.push(style.inner);
     }
-    text.push('┃');
+    text.push(style.outer);
     text.push('\n');
 
     // Create fourth row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┷');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_up, style.heavy_right, length, width, text);
 }
 
 /// Store the operation title of the long multiplication.
@@ -271,27 +543,69 @@ pub fn position_title(multiplicand: &String, multiplier: &String, text: &mut Str
 /// assert_eq!(expected, text);
 /// ```
 pub fn operation_title(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
+
+    operation_title_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the operation title of the long multiplication using the given options.
+///
+/// It works like `operation_title`, but it draws the operation title
+/// with the glyphs of the border style of the options. The signs
+/// of the operands and the order in which they were given, when
+/// they were swapped to draw fewer rows, are noted after the title.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("73");
+/// let multiplier: String = String::from("4");
+/// let mut text: String = String::from("");
+/// let expected: &str = "|Ops.       |\n\
+///                       +---+---+---+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::operation_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let multiplicand: String = String::from("73");
+/// let multiplier: String = String::from("4");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃Ops.       ┃ (swapped: 4 x 73)\n\
+///                       ┣━━━┯━━━┯━━━┫\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { swapped: true, ..TableOptions::default() };
+/// generate::operation_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn operation_title_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push_str("┃Ops.");
-    for _ in 1..(length * 3) + length - 4 {
-        text.push(' ');
+    push_title(options.labels.operations, length, width, style, text);
+    if options.signs.0 || options.signs.1 {
+        text.push_str(&format!(" {} x {}", sign_note(options.signs.0), sign_note(options.signs.1)));
+    }
+    if options.swapped {
+        text.push_str(&format!(" (swapped: {multiplier} x {multiplicand})"));
     }
-    text.push('┃');
     text.push('\n');
 
     // Create second row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┯');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_down, style.heavy_right, length, width, text);
 }
 
 /// Store the multiplication section of the long multiplication.
@@ -332,60 +646,89 @@ pub fn operation_title(multiplicand: &String, multiplier: &String, text: &mut St
 /// assert_eq!(expected, text);
 /// ```
 pub fn multiplication(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let multiplicand_len: usize = get_string_length(multiplicand);
-    let multiplier_len: usize = get_string_length(multiplier);
-    let length: usize = multiplicand_len + multiplier_len;
+    let options: TableOptions = TableOptions::default();
+
+    multiplication_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the multiplication section of the long multiplication using the given options.
+///
+/// It works like `multiplication`, but it draws the multiplication section
+/// with the glyphs of the border style of the options. With
+/// `TopOperand::Multiplier`, the multiplier is drawn on the first
+/// row and the multiplicand next to the `x`; the operations are
+/// the same.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("3");
+/// let multiplier: String = String::from("5");
+/// let mut text: String = String::from("");
+/// let expected: &str = "|   | 3 |\n\
+///                       | x | 5 |\n\
+///                       +---+---+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::multiplication_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn multiplication_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let (top, bottom): (&String, &String) = match options.top {
+        TopOperand::Multiplicand => (multiplicand, multiplier),
+        TopOperand::Multiplier => (multiplier, multiplicand),
+    };
+    let top_len: usize = get_string_length(top);
+    let bottom_len: usize = get_string_length(bottom);
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push('┃');
-    for n in 0..(length - multiplicand_len) {
-        text.push_str("   ");
+    text.push(style.outer);
+    for n in 0..(length - top_len) {
+        push_cell("", width, text);
         if n == length {
             break;
         }
-        text.push('│');
+        text.push(style.inner);
     }
 
-    for i in multiplicand.chars() {
-        text.push(' ');
-        text.push(i);
-        text.push_str(" │");
+    for i in top.chars() {
+        push_cell(&i.to_string(), width, text);
+        text.push(style.inner);
     }
     text.pop();
-    text.push('┃');
+    text.push(style.outer);
     text.push('\n');
 
     // Create second row
-    text.push('┃');
-    text.push_str(" x │");
-    for n in 0..(length - multiplier_len - 1) {
-        text.push_str("   ");
+    text.push(style.outer);
+    push_cell("x", width, text);
+    text.push(style.inner);
+    for n in 0..length.saturating_sub(bottom_len + 1) {
+        push_cell("", width, text);
         if n == length {
             break;
         }
-        text.push('│');
+        text.push(style.inner);
     }
 
-    for i in multiplier.chars() {
-        text.push(' ');
-        text.push(i);
-        text.push_str(" │");
+    for i in bottom.chars() {
+        push_cell(&i.to_string(), width, text);
+        text.push(style.inner);
     }
     text.pop();
-    text.push('┃');
+    text.push(style.outer);
     text.push('\n');
 
     // Create third row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┿');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_cross, style.heavy_right, length, width, text);
 }
 
 /// Store the operations section of the long multiplication.
@@ -406,7 +749,6 @@ pub fn multiplication(multiplicand: &String, multiplier: &String, text: &mut Str
 ///                       ┃   │ 7 ┃ 1 R\n\
 ///                       ┣━━━┷━━━┫\n";
 ///
-/// use clap::builder::Str;
 /// use long_multiplication_command_line::generate;
 /// generate::operations(&multiplicand, &multiplier, &mut text);
 ///
@@ -427,121 +769,190 @@ pub fn multiplication(multiplicand: &String, multiplier: &String, text: &mut Str
 ///                       ┃   │ 0 │ 8 │ 6 │   ┃ 2 R\n\
 ///                       ┣━━━┷━━━┷━━━┷━━━┷━━━┫\n";
 ///
-/// use clap::builder::Str;
 /// use long_multiplication_command_line::generate;
 /// generate::operations(&multiplicand, &multiplier, &mut text);
 ///
 /// assert_eq!(expected, text);
 /// ```
 pub fn operations(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let options: TableOptions = TableOptions::default();
+
+    operations_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the operations section of the long multiplication using the given options.
+///
+/// It generates the table operations-section for the
+/// long multiplication, displaying only the rows selected
+/// by the options, and stores it in a text variable. With
+/// `max_rows`, the rows after the first ones are replaced by
+/// a `… (m more rows)` marker, or `…+m` when it does not fit in
+/// the table; the sum and the product still
+/// include all the rows. When the filter leaves no rows, like
+/// the even rows of a single-digit multiplier, a `… (rows
+/// filtered)` marker, or `…` when it does not fit, is drawn
+/// instead of an empty section. With `CarryStyle::Superscript`, the
+/// `n ^` rows are dropped and each carry is drawn as a
+/// superscript next to the unit on its left, like `2¹`. The
+/// compact cells are too narrow for both, so they keep the rows.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("579");
+/// let multiplier: String = String::from("48");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃ 2 │ 2 │ 3 │   │   ┃ 2 ^\n\
+///                       ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+///                       ┃   │ 0 │ 8 │ 6 │   ┃ 2 R\n\
+///                       ┣━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{RowFilter, TableOptions};
+/// let options: TableOptions = TableOptions { rows: RowFilter::Even, ..TableOptions::default() };
+/// generate::operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn operations_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
     let multiplicand_len: usize = get_string_length(multiplicand);
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let length: usize = table_length(multiplicand, multiplier, options);
 
-    let operation_unit: Vec<usize>;
-    let operation_carry: Vec<usize>;
-    (operation_unit, operation_carry) = break_down_multiplication(multiplicand, multiplier);
+    let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, options.radix);
+    let operation_unit: &[usize] = breakdown.units();
+    let operation_carry: &[usize] = breakdown.carries();
 
     let step: usize = multiplicand_len;
-    let max_group_rows: usize = operation_unit.len() / step;
-    let mut iteration: usize = 1;
-    for start in (0..operation_unit.len()).step_by(step) {
-        let start: usize = start;
+    let max_group_rows: usize = operation_unit.len().checked_div(step).unwrap_or(0);
+    let (rows, hidden): (Vec<usize>, usize) = displayed_rows(max_group_rows, options);
+    for (index, iteration) in rows.iter().enumerate() {
+        let iteration: usize = *iteration;
+        let start: usize = (iteration - 1) * step;
         let end: usize = start + step;
         let slice: &[usize] = &operation_carry[start..end];
 
+        // Create the separator from the previous row
+        if index > 0 {
+            push_rule(style.light_left, style.light, style.light_cross, style.light_right, length, width, text);
+        }
+
+        if options.carry_style == CarryStyle::Superscript && width > 1 {
+            let carries: &[usize] = &operation_carry[start..end];
+            let units: &[usize] = &operation_unit[start..end];
+            push_superscript_row(carries, units, length.saturating_sub(step + iteration), length, options, text);
+            text.push_str(&format!(" {iteration} R\n"));
+            continue;
+        }
+
         // Create first row
-        text.push('┃');
-        let start_spaces: usize = length - step - iteration;
+        // The row is shifted one column to the left per iteration, and
+        // `length` is at least `step + iteration`, like `1 * 1` with 2.
+        text.push(style.outer);
+        let start_spaces: usize = length.saturating_sub(step + iteration);
         for _ in 0..start_spaces {
-            text.push_str("   │");
+            push_cell("", width, text);
+            text.push(style.inner);
         }
         for n in slice {
-            text.push(' ');
-            text.push_str(&*n.to_string());
-            text.push(' ');
-            text.push('│');
+            push_cell(&digit_to_char(*n, options.radix).to_string(), width, text);
+            text.push(style.inner);
         }
         let end_spaces: usize = iteration;
         for n in 0..end_spaces {
-            text.push_str("   ");
+            push_cell("", width, text);
             if n < end_spaces - 1 {
-                text.push('│');
+                text.push(style.inner);
             }
         }
-        text.push_str("┃ ");
+        text.push(style.outer);
+        text.push(' ');
         let row: String = iteration.to_string();
-        text.push_str(&*row);
+        text.push_str(&row);
         text.push_str(" ^\n");
 
         // Create second row
-        text.push('┠');
-        for n in 1..length + 1 {
-            text.push_str("┈┈┈");
-            if n == length {
-                break;
-            }
-            text.push('┼');
-        }
-        text.push('┨');
-        text.push('\n');
+        push_rule(style.light_left, style.carry_fill, style.light_cross, style.light_right, length, width, text);
 
         // Create third row
         let slice: &[usize] = &operation_unit[start..end];
-        let start_spaces: usize = length - step - iteration + 1;
-        text.push('┃');
+        let start_spaces: usize = length.saturating_sub(step + iteration) + 1;
+        text.push(style.outer);
         for _ in 0..start_spaces {
-            text.push_str("   │");
+            push_cell("", width, text);
+            text.push(style.inner);
         }
         for n in slice {
-            text.push(' ');
-            text.push_str(&*n.to_string());
-            text.push(' ');
-            text.push('│');
+            push_cell(&digit_to_char(*n, options.radix).to_string(), width, text);
+            text.push(style.inner);
         }
-        let end_spaces: usize = iteration - 1;
+        let end_spaces: usize = iteration.saturating_sub(1);
         if end_spaces == 0 {
             text.pop();
         }
         for n in 0..end_spaces {
-            text.push_str("   ");
+            push_cell("", width, text);
             if n < end_spaces - 1 {
-                text.push('│');
+                text.push(style.inner);
             }
         }
-        text.push_str("┃ ");
+        text.push(style.outer);
+        text.push(' ');
         let row: String = iteration.to_string();
-        text.push_str(&*row);
+        text.push_str(&row);
         text.push_str(" R\n");
+    }
 
-        // Create fourth row
-        if iteration == max_group_rows {
-            break;
-        }
-        text.push('┠');
-        for n in 1..length + 1 {
-            text.push_str("───");
-            if n == length {
-                break;
-            }
-            text.push('┼');
+    // Create the marker of the rows beyond the maximum
+    if hidden > 0 {
+        if !rows.is_empty() {
+            push_rule(style.light_left, style.light, style.light_cross, style.light_right, length, width, text);
         }
-        text.push('┨');
+        push_title(&hidden_rows_marker(hidden, length * (width + 1) - 1), length, width, style, text);
         text.push('\n');
+    }
 
-        iteration += 1;
+    // Create the marker of the rows filtered out, instead of an empty section
+    if rows.is_empty() && hidden == 0 {
+        push_title(&filtered_rows_marker(length * (width + 1) - 1), length, width, style, text);
+        text.push('\n');
     }
 
     // Create the final row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┷');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_up, style.heavy_right, length, width, text);
+}
+
+/// Store the note of the rows filtered for display.
+///
+/// It generates a note below the table when only some
+/// partial-product rows are displayed, since the sum and the
+/// product still include all the rows.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let mut text: String = String::from("");
+/// let expected: &str = "Note: only the even rows are displayed; the sum and the product include all the rows.\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::RowFilter;
+/// generate::rows_note(RowFilter::Even, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn rows_note(rows: RowFilter, text: &mut String) {
+    let name: &str = match rows {
+        RowFilter::All => return,
+        RowFilter::Odd => "odd",
+        RowFilter::Even => "even",
+    };
+
+    text.push_str(&format!("Note: only the {name} rows are displayed; the sum and the product include all the rows.\n"));
 }
 
 /// Store the sum title of the long multiplication.
@@ -580,27 +991,45 @@ pub fn operations(multiplicand: &String, multiplier: &String, text: &mut String)
 /// assert_eq!(expected, text);
 /// ```
 pub fn sum_title(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
+
+    sum_title_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the sum title of the long multiplication using the given options.
+///
+/// It works like `sum_title`, but it draws the sum title
+/// with the glyphs of the border style of the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("2");
+/// let multiplier: String = String::from("5");
+/// let mut text: String = String::from("");
+/// let expected: &str = "|Sum.   |\n\
+///                       +---+---+\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+/// generate::sum_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn sum_title_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
 
     // Create first row
-    text.push_str("┃Sum.");
-    for _ in 1..(length * 3) + length - 4 {
-        text.push(' ');
-    }
-    text.push('┃');
+    push_title(options.labels.sum, length, width, style, text);
     text.push('\n');
 
     // Create second row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┯');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_down, style.heavy_right, length, width, text);
 }
 
 /// Store the long-sum section of the long multiplication.
@@ -655,1121 +1084,3948 @@ pub fn sum_title(multiplicand: &String, multiplier: &String, text: &mut String)
 /// assert_eq!(expected, text);
 /// ```
 pub fn long_sum(multiplicand: &String, multiplier: &String, text: &mut String) {
-    let additions: Vec<usize> = break_down_addition(multiplicand, multiplier);
+    let options: TableOptions = TableOptions::default();
 
-    let length: usize = get_strings_length(multiplicand, multiplier);
-    generate_rows_with_numbers(&additions, length, text);
+    long_sum_with_options(multiplicand, multiplier, &options, text);
+}
 
-    let mut sub_addition: Vec<usize> = break_down_subtotal(&additions);
-    let mut sub_index: usize = 0;
-    loop {
-        let mut decimals: bool = false;
-        for number in &sub_addition {
-            if number > &9 {
-                decimals = true;
-                break;
-            }
-        }
+/// Store the long-sum section of the long multiplication using the given options.
+///
+/// It generates the table long-sum-section for the
+/// long multiplication, customized by the options,
+/// and stores it in a text variable.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("3");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃   │ 6 ┃ 1 C\n\
+///                       ┠┈┈┈┼┈┈┈┨\n\
+///                       ┃ 0 │   ┃ 2 C\n\
+///                       ┣━━━┷━━━┫\n\
+///                       ┃Pro.   ┃\n\
+///                       ┣━━━┯━━━┫\n\
+///                       ┃ 6 │   ┃ P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{ProductAlign, TableOptions};
+/// let options: TableOptions = TableOptions { product_align: ProductAlign::Left, ..TableOptions::default() };
+/// generate::long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn long_sum_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    sum_rows_with_options(multiplicand, multiplier, options, text);
+    product_with_options(multiplicand, multiplier, options, text);
+}
 
-        if !decimals {
-            break;
-        }
+/// Store the rows of the sum and subtotals of the long multiplication.
+///
+/// It generates the sum of each column and, while any column
+/// has more than one digit, the subtotal rows. It stops before
+/// the product title.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("3");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃   │ 6 ┃ 1 C\n\
+///                       ┠┈┈┈┼┈┈┈┨\n\
+///                       ┃ 0 │   ┃ 2 C\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::sum_rows(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn sum_rows(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let options: TableOptions = TableOptions::default();
+
+    sum_rows_with_options(multiplicand, multiplier, &options, text);
+}
+
+/// Store the rows of the sum and subtotals using the given options.
+///
+/// It generates the sum of each column and the subtotal rows,
+/// like `sum_rows`, in the base (radix) of the options. With
+/// `SumVerbosity::Compact`, the sums of the columns are drawn
+/// by place instead: a row with the units of every sum, a row
+/// with the tens shifted one column, and so on.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("11");
+/// let multiplier: String = String::from("1");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃   │   │ 1 ┃ 1 C\n\
+///                       ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+///                       ┃   │ 1 │   ┃ 2 C\n\
+///                       ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+///                       ┃ 0 │   │   ┃ 3 C\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { radix: 2, ..TableOptions::default() };
+/// generate::sum_rows_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃ 0 │ 2 │ 3 │ 8 ┃\n\
+///                       ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+///                       ┃   │ 1 │   │   ┃\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{SumVerbosity, TableOptions};
+/// let options: TableOptions = TableOptions { sum: SumVerbosity::Compact, ..TableOptions::default() };
+/// generate::sum_rows_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn sum_rows_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let radix: u32 = options.radix;
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+
+    let length: usize = table_length(multiplicand, multiplier, options);
+    push_sum_rows(&additions, length, options, text);
 
+    let mut sub_addition: Vec<usize> = break_down_subtotal_with_radix(&additions, radix);
+    let mut sub_index: usize = 0;
+    while requires_subtotal(&sub_addition, radix) {
         // Create the first row of the sub-addition
-        text.push('┣');
-        for n in 1..length + 1 {
-            text.push_str("━━━");
-            if n == length {
-                break;
-            }
-            text.push('┷');
-        }
-        text.push('┫');
-        text.push('\n');
+        push_rule(style.heavy_left, style.heavy, style.heavy_up, style.heavy_right, length, width, text);
 
         // Create the second row of the sub-addition
-        text.push_str("┃Sub ");
         sub_index += 1;
-        text.push_str(&*sub_index.to_string());
-        text.push('.');
-        for _ in 1..(length * 3) + length - 6 {
-            text.push(' ');
-        }
-        text.push('┃');
+        push_title(&format!("{} {sub_index}.", options.labels.subtotal), length, width, style, text);
         text.push('\n');
 
         // Create the third row of the sub-addition
-        text.push('┣');
-        for n in 1..length + 1 {
-            text.push_str("━━━");
-            if n == length {
-                break;
-            }
-            text.push('┯');
-        }
-        text.push('┫');
-        text.push('\n');
+        push_rule(style.heavy_left, style.heavy, style.heavy_down, style.heavy_right, length, width, text);
 
         // Create the sum of columns
-        generate_rows_with_numbers(&sub_addition, length, text);
-        sub_addition = break_down_subtotal(&sub_addition);
+        push_sum_rows(&sub_addition, length, options, text);
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
     }
+}
+
+/// Store the product section of the long multiplication using the given options.
+///
+/// It generates the product title and the product row,
+/// customized by the options, and stores it in a text variable.
+/// With `LayoutConfig::pad_product_to`, the product row is
+/// padded on the left to the given number of columns, with
+/// zeros or, when aligned to the left, with empty cells, like
+/// the other sections of the table are widened to match.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("3");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┣━━━┷━━━┫\n\
+///                       ┃Pro.   ┃\n\
+///                       ┣━━━┯━━━┫\n\
+///                       ┃ 0 │ 6 ┃ P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::TableOptions;
+/// generate::product_with_options(&multiplicand, &multiplier, &TableOptions::default(), &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn product_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = table_length(multiplicand, multiplier, options);
+
+    let radix: u32 = options.radix;
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+    let mut sub_addition: Vec<usize> = fully_reduce_with_radix(&additions, radix);
 
     // Create last row
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┷');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_up, style.heavy_right, length, width, text);
 
     // Create first row product title
-    text.push_str("┃Pro.");
-    for _ in 1..(length * 3) + length - 4 {
-        text.push(' ');
-    }
-    text.push('┃');
+    push_title(options.labels.product, length, width, style, text);
     text.push('\n');
 
     // Create second row product title
-    text.push('┣');
-    for n in 1..length + 1 {
-        text.push_str("━━━");
-        if n == length {
-            break;
-        }
-        text.push('┯');
-    }
-    text.push('┫');
-    text.push('\n');
+    push_rule(style.heavy_left, style.heavy, style.heavy_down, style.heavy_right, length, width, text);
 
     // Create first row for product
     sub_addition.reverse();
-    text.push('┃');
-    for i in sub_addition {
-        text.push(' ');
-        text.push_str(&*i.to_string());
-        text.push_str(" │");
+    match options.product_align {
+        ProductAlign::Left => product_row_left(&sub_addition, length, options, text),
+        ProductAlign::Right => {
+            let mut cells: Vec<Option<usize>> = vec![Some(0); length.saturating_sub(sub_addition.len())];
+            cells.extend(sub_addition.iter().map(|digit| Some(*digit)));
+
+            // The sign takes the place of the zero before the first significant digit.
+            let units: usize = length - 1 - options.product_decimals().min(length - 1);
+            let first: usize = cells.iter().position(|cell| *cell != Some(0)).unwrap_or(units).min(units);
+            let sign: Option<usize> = match is_negative_product(&sub_addition, options) {
+                true => first.checked_sub(1),
+                false => None,
+            };
+            generate_product_cells(&cells, options.product_decimals(), sign, options, text);
+        }
     }
-    text.pop();
-
-    text.push_str("┃ P");
-    text.push('\n');
 }
 
-/// Store the author section of the long multiplication.
+/// Store the product row of the long multiplication.
 ///
-/// It generates the table author-section for the
-/// long multiplication and stores it in a text variable.
+/// It generates the table product-row for the given digits
+/// and stores it in a text variable.
+///
+/// The digits are ordered from the most significant to the
+/// least significant. If there are fewer digits than columns,
+/// the row is filled with zeros on the left.
 ///
 /// Examples
 /// --------
 ///
 /// Example #1
 /// ```rust
+/// let digits: Vec<usize> = vec![3, 3, 8];
 /// let mut text: String = String::from("");
-/// let expected: &str = "\n\
-///                       ---\n\
-///                       Author: Israel Roldan\n\
-///                       E-mail: israel.alberto.rv@gmail.com\n\
-///                       License: GPL-3.0\n\
-///                       Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+/// let expected: &str = "┃ 0 │ 3 │ 3 │ 8 ┃ P\n";
 ///
 /// use long_multiplication_command_line::generate;
-/// generate::author(&mut text);
+/// generate::product_row(&digits, 4, &mut text);
 ///
 /// assert_eq!(expected, text);
 /// ```
-pub fn author(text: &mut String) {
-    text.push_str("\n");
-    text.push_str("---\n");
-    text.push_str("Author: Israel Roldan\n");
-    text.push_str("E-mail: israel.alberto.rv@gmail.com\n");
-    text.push_str("License: GPL-3.0\n");
-    text.push_str("Project: https://github.com/airvzxf/long-multiplication-calculator\n");
-}
+///
+/// Example #2
+/// ```rust
+/// let digits: Vec<usize> = vec![3, 5];
+/// let mut text: String = String::from("");
+/// let expected: &str = "┃ 3 │ 5 ┃ P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::product_row(&digits, 2, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn product_row(digits: &[usize], columns: usize, text: &mut String) {
+    let padding: usize = columns.saturating_sub(digits.len());
 
-fn generate_rows_with_numbers(numbers: &Vec<usize>, length: usize, text: &mut String) {
-    let mut iteration: usize = 0;
+    let mut cells: Vec<Option<usize>> = vec![Some(0); padding];
+    cells.extend(digits.iter().map(|digit| Some(*digit)));
+    let options: TableOptions = TableOptions::default();
+    generate_product_cells(&cells, 0, None, &options, text);
+}
 
-    for row in numbers {
-        // Create first row
-        let row_size: usize = get_number_length(*row);
-        text.push('┃');
-        for _ in 0..(length - iteration - row_size) {
-            text.push_str("   ");
-            text.push('│');
+/// Get the labels of the rows of the long multiplication.
+///
+/// It returns the labels that appear down the right margin
+/// of the table, in order: the carry-over (`n ^`) and row
+/// (`n R`) labels of the operations, the column labels (`n C`)
+/// of the sum and each subtotal, and the product (`P`).
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let expected: Vec<String> = vec![
+///     String::from("1 ^"),
+///     String::from("1 R"),
+///     String::from("1 C"),
+///     String::from("2 C"),
+///     String::from("P"),
+/// ];
+///
+/// use long_multiplication_command_line::generate;
+/// let labels: Vec<String> = generate::row_labels("9", "3");
+///
+/// assert_eq!(expected, labels);
+/// ```
+pub fn row_labels(multiplicand: &str, multiplier: &str) -> Vec<String> {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+    let length: usize = get_strings_length(&multiplicand_str, &multiplier_str);
+
+    let mut labels: Vec<String> = Vec::new();
+    for row in 1..get_string_length(&multiplier_str) + 1 {
+        labels.push(format!("{row} ^"));
+        labels.push(format!("{row} R"));
+    }
+
+    let additions: Vec<usize> = break_down_addition(&multiplicand_str, &multiplier_str);
+    let mut sub_addition: Vec<usize> = break_down_subtotal(&additions);
+    let mut sections: usize = 1;
+    while sub_addition.iter().any(|number| number > &9) {
+        sub_addition = break_down_subtotal(&sub_addition);
+        sections += 1;
+    }
+    for _ in 0..sections {
+        for column in 1..length + 1 {
+            labels.push(format!("{column} C"));
         }
+    }
+
+    labels.push(String::from("P"));
+
+    let labels: Vec<String> = labels;
+    return labels;
+}
+
+/// Store the significant digits annotation of the product.
+///
+/// It generates an annotation line, outside the table, with the
+/// first significant digits of the product followed by an ellipsis
+/// and the total number of digits, and stores it in a text variable.
+///
+/// The digits are ordered from the most significant to the least
+/// significant. The leading zeros are not significant.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let digits: Vec<usize> = vec![1, 1, 7, 5, 0, 5, 2, 7, 4];
+/// let mut text: String = String::from("");
+/// let expected: &str = "117…(9 digits)\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::product_significant(&digits, 3, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let digits: Vec<usize> = vec![0, 3, 3, 8];
+/// let mut text: String = String::from("");
+/// let expected: &str = "338(3 digits)\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::product_significant(&digits, 5, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn product_significant(digits: &[usize], significant: usize, text: &mut String) {
+    product_significant_with_radix(digits, significant, 10, text);
+}
+
+/// Store the first significant digits of the product in the given base.
+///
+/// It works like `product_significant`, but the digits are
+/// written in the given base (radix), from 2 to 16.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let digits: Vec<usize> = vec![0, 15, 14, 0, 1];
+/// let mut text: String = String::from("");
+/// let expected: &str = "FE…(4 digits)\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::product_significant_with_radix(&digits, 2, 16, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn product_significant_with_radix(digits: &[usize], significant: usize, radix: u32, text: &mut String) {
+    let mut first: usize = 0;
+    while first + 1 < digits.len() && digits[first] == 0 {
+        first += 1;
+    }
+    let digits: &[usize] = &digits[first..];
+
+    for digit in digits.iter().take(significant) {
+        text.push(digit_to_char(*digit, radix));
+    }
+    if significant < digits.len() {
+        text.push('…');
+    }
+    text.push_str(&format!("({} digits)", digits.len()));
+    text.push('\n');
+}
+
+/// Store the verification row of the product.
+///
+/// It recomputes the digits of the product by adding the shifted
+/// partial products, without the sums of the columns, and marks
+/// the row with `✓` when it matches the product of the table, or
+/// with `✗` when it does not.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "Verify: 338 ✓\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::verification(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn verification(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let product: String = compute_product(multiplicand, multiplier);
+    let check: String = sum_partial_products(multiplicand, multiplier);
+
+    let mark: char = if check == product { '✓' } else { '✗' };
+    text.push_str(&format!("Verify: {check} {mark}\n"));
+}
+
+/// Store the explanation of the products of the operations section.
+///
+/// It lists each product of a multiplicand digit by a multiplier
+/// digit, with its unit and its carry, in the order they are
+/// worked out: the rows from the last digit of the multiplier,
+/// and each row from the last digit of the multiplicand.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "row 1: 3×6=18 (unit 8, carry 1)\n\
+///                       row 1: 1×6=6 (unit 6, carry 0)\n\
+///                       row 2: 3×2=6 (unit 6, carry 0)\n\
+///                       row 2: 1×2=2 (unit 2, carry 0)\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::explanation(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn explanation(multiplicand: &String, multiplier: &String, text: &mut String) {
+    explanation_with_options(multiplicand, multiplier, &TableOptions::default(), text);
+}
 
-        for i in row.to_string().chars() {
-            text.push(' ');
-            text.push(i);
-            text.push_str(" │");
+/// Store the explanation of the displayed rows of the operations section.
+///
+/// It works like `explanation`, but it only lists the rows
+/// displayed by the options, in their order: the rows kept by
+/// `rows`, from the first digit of the multiplier with
+/// `RowOrder::Msd`, and up to `max_rows`. The products, units
+/// and carries are written in the base of the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "row 2: 3×2=6 (unit 6, carry 0)\n\
+///                       row 2: 1×2=2 (unit 2, carry 0)\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{RowOrder, TableOptions};
+/// let options: TableOptions = TableOptions { order: RowOrder::Msd, max_rows: Some(1), ..TableOptions::default() };
+/// generate::explanation_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn explanation_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let radix: u32 = options.radix;
+    let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, radix);
+    let multiplicand_digits: Vec<char> = multiplicand.chars().collect();
+    let multiplier_digits: Vec<char> = multiplier.chars().rev().collect();
+    let step: usize = multiplicand_digits.len();
+
+    let (rows, _): (Vec<usize>, usize) = displayed_rows(multiplier_digits.len(), options);
+    for row in rows {
+        let b: char = multiplier_digits[row - 1];
+        for (column, a) in multiplicand_digits.iter().enumerate().rev() {
+            let index: usize = (row - 1) * step + column;
+            let unit: usize = breakdown.units()[index];
+            let carry: usize = breakdown.carries()[index];
+            let product: String = to_radix_string(carry * radix as usize + unit, radix);
+            let (unit, carry): (char, char) = (digit_to_char(unit, radix), digit_to_char(carry, radix));
+            text.push_str(&format!("row {row}: {a}×{b}={product} (unit {unit}, carry {carry})\n"));
         }
-        text.pop();
+    }
+}
+
+/// Store the Russian peasant (doubling and halving) multiplication.
+///
+/// It generates a two-column table and stores it in a text
+/// variable. The left column halves the multiplicand down to
+/// one, dropping the remainders, and the right column doubles
+/// the multiplier. The rows with an odd left number are kept,
+/// marked with `+`, and the sum of their right numbers, written
+/// below the table, is the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let mut text: String = String::from("");
+/// let expected: &str = "┏━━━┯━━━━┓\n\
+///                       ┃ 5 │  7 ┃ +\n\
+///                       ┃ 2 │ 14 ┃\n\
+///                       ┃ 1 │ 28 ┃ +\n\
+///                       ┗━━━┷━━━━┛\n\
+///                       7 + 28 = 35\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::peasant(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn peasant(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let mut rows: Vec<(String, String)> = Vec::new();
+    let mut half: String = normalize_operand(multiplicand);
+    let mut double: String = normalize_operand(multiplier);
+    loop {
+        rows.push((half.clone(), double.clone()));
+        if half == "0" || half == "1" {
+            break;
+        }
+        half = halve_digit_string(&half);
+        double = add_digit_strings(&double, &double);
+    }
+
+    let left: usize = rows.iter().map(|(half, _)| half.len()).max().unwrap_or(1);
+    let right: usize = rows.iter().map(|(_, double)| double.len()).max().unwrap_or(1);
+
+    text.push_str(&format!("┏{}┯{}┓\n", "━".repeat(left + 2), "━".repeat(right + 2)));
+    let mut kept: Vec<&str> = Vec::new();
+    let mut product: String = String::from("0");
+    for (half, double) in rows.iter() {
+        text.push_str(&format!("┃ {half:>left$} │ {double:>right$} ┃"));
+        if half.ends_with(['1', '3', '5', '7', '9']) {
+            text.push_str(" +");
+            kept.push(double);
+            product = add_digit_strings(&product, double);
+        }
+        text.push('\n');
+    }
+    text.push_str(&format!("┗{}┷{}┛\n", "━".repeat(left + 2), "━".repeat(right + 2)));
+
+    if kept.is_empty() {
+        kept.push("0");
+    }
+    text.push_str(&format!("{} = {product}\n", kept.join(" + ")));
+}
+
+/// Store the plain (schoolbook) long multiplication.
+///
+/// It generates the traditional stacked layout, without the
+/// grid, and stores it in a text variable: the operands aligned
+/// to the right, the multiplier after a `×`, a line, the product
+/// of each multiplier digit indented one column per digit, a
+/// line and their sum. A single-digit multiplier has only one
+/// product, so it is written once.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "  13\n\
+///                       × 26\n\
+///                       ----\n\
+///                       \x20 78\n\
+///                       \x2026\n\
+///                       ----\n\
+///                       \x20338\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::plain(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn plain(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+    let product: String = compute_product(&multiplicand, &multiplier);
+    let partials: Vec<String> = multiplier.chars().rev()
+        .map(|digit| compute_product(&multiplicand, &digit.to_string()))
+        .collect();
+
+    // The `× ` is written before the multiplier.
+    let width: usize = get_string_length(&product).max(get_string_length(&multiplicand)).max(get_string_length(&multiplier) + 2);
+    let line: String = "-".repeat(width);
+
+    text.push_str(&format!("{multiplicand:>width$}\n"));
+    text.push_str(&format!("× {multiplier:>0$}\n", width - 2));
+    text.push_str(&format!("{line}\n"));
+    if partials.len() > 1 {
+        for (shift, partial) in partials.iter().enumerate() {
+            text.push_str(&format!("{partial:>0$}\n", width - shift));
+        }
+        text.push_str(&format!("{line}\n"));
+    }
+    text.push_str(&format!("{product:>width$}\n"));
+}
+
+/// Store the Karatsuba decomposition of the multiplication.
+///
+/// It generates the derivation of one step of the Karatsuba
+/// method and stores it in a text variable. The operands are
+/// split at half the digits of the longer one, `m`, into
+/// `a`, `b` and `c`, `d`, so they are `a x 10^m + b` and
+/// `c x 10^m + d`. Only three products are needed: `ac`, `bd`
+/// and `(a + b)(c + d)`, from which `ad + bc` is subtracted.
+/// The last lines recombine them into the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("1234");
+/// let multiplier: String = String::from("5678");
+/// let mut text: String = String::from("");
+/// let expected: &str = "m = 2\n\
+///                       a = 12, b = 34, c = 56, d = 78\n\
+///                       ac = 12 x 56 = 672\n\
+///                       bd = 34 x 78 = 2652\n\
+///                       (a + b)(c + d) = 46 x 134 = 6164\n\
+///                       ad + bc = 6164 - 672 - 2652 = 2840\n\
+///                       ac x 10^4 + (ad + bc) x 10^2 + bd\n\
+///                       = 6720000 + 284000 + 2652\n\
+///                       = 7006652\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::karatsuba(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn karatsuba(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+    let m: usize = (multiplicand.len().max(multiplier.len()) / 2).max(1);
+
+    let (a, b): (String, String) = split_digits(&multiplicand, m);
+    let (c, d): (String, String) = split_digits(&multiplier, m);
+    let ac: String = compute_product(&a, &c);
+    let bd: String = compute_product(&b, &d);
+    let a_b: String = add_digit_strings(&a, &b);
+    let c_d: String = add_digit_strings(&c, &d);
+    let middle: String = compute_product(&a_b, &c_d);
+    let ad_bc: String = subtract_digit_strings(&subtract_digit_strings(&middle, &ac), &bd);
+
+    let high: String = shift_digits(&ac, 2 * m);
+    let center: String = shift_digits(&ad_bc, m);
+    let product: String = add_digit_strings(&add_digit_strings(&high, &center), &bd);
+
+    text.push_str(&format!("m = {m}\n"));
+    text.push_str(&format!("a = {a}, b = {b}, c = {c}, d = {d}\n"));
+    text.push_str(&format!("ac = {a} x {c} = {ac}\n"));
+    text.push_str(&format!("bd = {b} x {d} = {bd}\n"));
+    text.push_str(&format!("(a + b)(c + d) = {a_b} x {c_d} = {middle}\n"));
+    text.push_str(&format!("ad + bc = {middle} - {ac} - {bd} = {ad_bc}\n"));
+    text.push_str(&format!("ac x 10^{} + (ad + bc) x 10^{m} + bd\n", 2 * m));
+    text.push_str(&format!("= {high} + {center} + {bd}\n"));
+    text.push_str(&format!("= {product}\n"));
+}
+
+/// Store the lattice (grid) multiplication.
+///
+/// It generates the classic lattice of the multiplication and
+/// stores it in a text variable. The multiplicand is written
+/// above the grid and the multiplier on its right. Each cell
+/// holds the product of its digits, split by the diagonal into
+/// the tens (upper-left) and the units (lower-right). The sums
+/// of the diagonals, with their carries, are written along the
+/// left and bottom edges; read from the top-left corner down
+/// and then to the right, they are the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "     1     3\n\
+///                       \x20 ┏━━━━━┯━━━━━┓\n\
+///                       \x20 ┃0   ╱│0   ╱┃\n\
+///                       \x20 ┃  ╱  │  ╱  ┃ 2\n\
+///                       0 ┃╱   2│╱   6┃\n\
+///                       \x20 ┗━━━━━┷━━━━━┛\n\
+///                       \x20    2     6\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::lattice(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn lattice(multiplicand: &String, multiplier: &String, text: &mut String) {
+    lattice_with_options(multiplicand, multiplier, &TableOptions::default(), text);
+}
+
+/// Store the lattice (grid) multiplication using the given options.
+///
+/// It works like `lattice`, but the borders and the diagonals
+/// are drawn with the `TableStyle` of the options and the digits
+/// are in their base (radix). When an operand is negative or has
+/// decimals, the product with its sign and its decimal point is
+/// written below the grid, since the edges only hold its digits.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("2");
+/// let mut text: String = String::from("");
+/// let expected: &str = "     1     3\n\
+///                       \x20 +-----+-----+\n\
+///                       \x20 |0   /|0   /|\n\
+///                       \x20 |  /  |  /  | 2\n\
+///                       0 |/   2|/   6|\n\
+///                       \x20 +-----+-----+\n\
+///                       \x20    2     6\n\
+///                       = -2.6\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions {
+///     style: TableStyle::ascii(),
+///     signs: (true, false),
+///     decimals: (1, 0),
+///     ..TableOptions::default()
+/// };
+/// generate::lattice_with_options(&multiplicand, &multiplier, &options, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn lattice_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let radix: u32 = options.radix;
+    let columns: usize = get_string_length(multiplicand);
+    let rows: usize = get_string_length(multiplier);
+    let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, radix);
+    let operation_unit: &[usize] = breakdown.units();
+    let operation_carry: &[usize] = breakdown.carries();
+
+    // The diagonals are numbered from the bottom-right corner. The
+    // units of a cell lie in its diagonal and the tens in the next.
+    let mut diagonals: Vec<usize> = vec![0; columns + rows];
+    for row in 0..rows {
+        for column in 0..columns {
+            let index: usize = row * columns + column;
+            let diagonal: usize = row + columns - column - 1;
+            diagonals[diagonal] += operation_unit[index];
+            diagonals[diagonal + 1] += operation_carry[index];
+        }
+    }
+    let mut carry: usize = 0;
+    for sum in diagonals.iter_mut() {
+        let total: usize = *sum + carry;
+        *sum = total % radix as usize;
+        carry = total / radix as usize;
+    }
+
+    let mut header: String = String::from(" ");
+    for digit in multiplicand.chars() {
+        header.push_str(&format!("    {digit} "));
+    }
+    text.push_str(header.trim_end());
+    text.push('\n');
+
+    let heavy: Vec<String> = vec![style.heavy.to_string().repeat(5); columns];
+    let diagonal: char = style.diagonal;
+    text.push_str(&format!("  {}{}{}\n", style.top_left, heavy.join(&style.heavy_down.to_string()), style.top_right));
+    for (row, digit) in multiplier.chars().enumerate() {
+        if row > 0 {
+            let light: Vec<String> = vec![style.light.to_string().repeat(5); columns];
+            text.push_str(&format!("  {}{}{}\n", style.light_left, light.join(&style.light_cross.to_string()), style.light_right));
+        }
+
+        // The break down starts from the last digit of the multiplier.
+        let offset: usize = (rows - row - 1) * columns;
+        let inner: String = style.inner.to_string();
+        let tens: Vec<usize> = operation_carry[offset..offset + columns].to_vec();
+        let units: Vec<usize> = operation_unit[offset..offset + columns].to_vec();
+        let cells: Vec<String> = tens.iter().map(|tens| format!("{}   {diagonal}", digit_to_char(*tens, radix))).collect();
+        text.push_str(&format!("  {}{}{}\n", style.outer, cells.join(&inner), style.outer));
+        let cells: Vec<String> = vec![format!("  {diagonal}  "); columns];
+        text.push_str(&format!("  {}{}{} {digit}\n", style.outer, cells.join(&inner), style.outer));
+        let cells: Vec<String> = units.iter().map(|units| format!("{diagonal}   {}", digit_to_char(*units, radix))).collect();
+        let edge: char = digit_to_char(diagonals[columns + rows - row - 1], radix);
+        text.push_str(&format!("{edge} {}{}{}\n", style.outer, cells.join(&inner), style.outer));
+    }
+    text.push_str(&format!("  {}{}{}\n", style.bottom_left, heavy.join(&style.heavy_up.to_string()), style.bottom_right));
+
+    let mut footer: String = String::from(" ");
+    for column in 0..columns {
+        footer.push_str(&format!("    {} ", digit_to_char(diagonals[columns - column - 1], radix)));
+    }
+    text.push_str(footer.trim_end());
+    text.push('\n');
+
+    if options.signs.0 != options.signs.1 || options.product_decimals() > 0 {
+        text.push_str(&format!("= {}\n", compute_product_with_options(multiplicand, multiplier, options)));
+    }
+}
+
+/// Store the author section of the long multiplication.
+///
+/// It generates the table author-section for the
+/// long multiplication and stores it in a text variable.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let mut text: String = String::from("");
+/// let expected: &str = "\n\
+///                       ---\n\
+///                       Author: Israel Roldan\n\
+///                       E-mail: israel.alberto.rv@gmail.com\n\
+///                       License: GPL-3.0\n\
+///                       Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::author(&mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn author(text: &mut String) {
+    text.push('\n');
+    text.push_str("---\n");
+    text.push_str("Author: Israel Roldan\n");
+    text.push_str("E-mail: israel.alberto.rv@gmail.com\n");
+    text.push_str("License: GPL-3.0\n");
+    text.push_str("Project: https://github.com/airvzxf/long-multiplication-calculator\n");
+}
+
+/// Store the statistics section of the long multiplication.
+///
+/// It generates the table statistics-section for the
+/// long multiplication and stores it in a text variable.
+///
+/// The statistics are the length of the multiplicand and the
+/// multiplier, the significant digits of the product, the
+/// number of subtotal rounds (`Sub n.`) and the bytes of the
+/// whole text, the text generated before this section and the
+/// section itself.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+/// let expected: &str = "\n\
+///                       ---\n\
+///                       Multiplicand digits: 2\n\
+///                       Multiplier digits: 2\n\
+///                       Product digits: 3\n\
+///                       Sub rounds: 0\n\
+///                       Table bytes: 97\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::stats(&multiplicand, &multiplier, &mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn stats(multiplicand: &String, multiplier: &String, text: &mut String) {
+    let table_bytes: usize = text.len();
+
+    stats_with_bytes(multiplicand, multiplier, table_bytes, text);
+}
+
+/// Store the statistics section with the given size of the table.
+///
+/// It works like `stats`, but the bytes of the table before the
+/// section are given, for the tables already written into a file
+/// or the standard output section by section.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let mut text: String = String::from("");
+///
+/// use long_multiplication_command_line::generate;
+/// generate::stats_with_bytes(&multiplicand, &multiplier, 891, &mut text);
+///
+/// assert!(text.ends_with("Sub rounds: 0\nTable bytes: 989\n"));
+/// ```
+pub fn stats_with_bytes(multiplicand: &String, multiplier: &String, table_bytes: usize, text: &mut String) {
+    stats_with_radix(multiplicand, multiplier, table_bytes, 10, text);
+}
+
+/// Store the statistics section of the operands in the given base.
+///
+/// It works like `stats_with_bytes`, but the digits of the
+/// operands are in the given base (radix), from 2 to 16, so the
+/// product digits and the subtotal rounds are counted in it.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("FF");
+/// let multiplier: String = String::from("FF");
+/// let mut text: String = String::from("");
+///
+/// use long_multiplication_command_line::generate;
+/// generate::stats_with_radix(&multiplicand, &multiplier, 891, 16, &mut text);
+///
+/// assert!(text.contains("Product digits: 4\n"));
+/// ```
+pub fn stats_with_radix(multiplicand: &String, multiplier: &String, table_bytes: usize, radix: u32, text: &mut String) {
+    let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+
+    let mut sub_addition: Vec<usize> = break_down_subtotal_with_radix(&additions, radix);
+    let mut sub_rounds: usize = 0;
+    while sub_addition.iter().any(|number| *number >= radix as usize) {
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
+        sub_rounds += 1;
+    }
+
+    let mut product_digits: usize = sub_addition.len();
+    while product_digits > 1 && sub_addition[product_digits - 1] == 0 {
+        product_digits -= 1;
+    }
+
+    let mut footer: String = String::from("\n---\n");
+    // The operands are counted as numbers, without their leading zeros.
+    footer.push_str(&format!("Multiplicand digits: {}\n", get_string_length(&normalize_operand(multiplicand))));
+    footer.push_str(&format!("Multiplier digits: {}\n", get_string_length(&normalize_operand(multiplier))));
+    footer.push_str(&format!("Product digits: {product_digits}\n"));
+    footer.push_str(&format!("Sub rounds: {sub_rounds}\n"));
+
+    // The bytes include the footer, whose last line grows with the digits of the total.
+    let fixed: usize = table_bytes + footer.len() + "Table bytes: \n".len();
+    let mut digits: usize = fixed.to_string().len();
+    while (fixed + digits).to_string().len() > digits {
+        digits += 1;
+    }
+    footer.push_str(&format!("Table bytes: {}\n", fixed + digits));
+    text.push_str(&footer);
+}
+
+/// Add the ANSI colors to the table of a text.
+///
+/// It wraps the glyphs of the borders, the digits of the carry
+/// rows (` ^`) and the digits of the product row (` P`) with the
+/// escape sequences of the color scheme. Only the lines of the
+/// table are colored, the annotations after the right border and
+/// the other lines are kept. With `highlight_column`, the cells of
+/// that position, counted from the right like in the Pos. section,
+/// are tinted in every row of cells. The colors are removed with
+/// `strip_ansi`, which returns the original text.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let text: &str = "┃ 3 │ 5 ┃ P\n";
+/// let expected: &str = "\x1b[2m┃\x1b[0m \x1b[1;32m3\x1b[0m \x1b[2m│\x1b[0m \x1b[1;32m5\x1b[0m \x1b[2m┃\x1b[0m P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{ColorScheme, TableStyle};
+/// let colored: String = generate::colorize(text, &TableStyle::unicode(), &ColorScheme::default());
+///
+/// assert_eq!(expected, colored);
+/// ```
+pub fn colorize(text: &str, style: &TableStyle, scheme: &ColorScheme) -> String {
+    let glyphs: [char; 21] = [
+        style.top_left, style.top_right, style.bottom_left, style.bottom_right, style.outer, style.inner,
+        style.guide, style.heavy, style.light, style.dashed, style.dotted, style.carry_fill, style.heavy_left, style.heavy_right,
+        style.light_left, style.light_right, style.heavy_down, style.heavy_up, style.heavy_cross,
+        style.light_down, style.light_cross,
+    ];
+
+    let mut colored: String = String::with_capacity(text.len() * 2);
+    for line in text.split_inclusive('\n') {
+        let (table, annotation): (&str, &str) = match split_table_line(line, style) {
+            Some(parts) => parts,
+            None => {
+                colored.push_str(line);
+                continue;
+            }
+        };
+        let digits: Option<&str> = match annotation.trim_end() {
+            row if row.ends_with(" ^") => Some(scheme.carry),
+            row if row.ends_with(" P") => Some(scheme.product),
+            _ => None,
+        };
+        let highlighted: Option<Range<usize>> = scheme.highlight_column.and_then(|position| column_span(table, style, position));
+
+        let mut open: Option<&str> = None;
+        for (index, character) in table.chars().enumerate() {
+            let color: Option<&str> = if highlighted.as_ref().is_some_and(|span| span.contains(&index)) {
+                Some(scheme.highlight)
+            } else if glyphs.contains(&character) {
+                Some(scheme.border)
+            } else if character.is_alphanumeric() {
+                digits
+            } else {
+                None
+            };
+            if color != open {
+                if open.is_some() {
+                    colored.push_str("\x1b[0m");
+                }
+                if let Some(code) = color {
+                    colored.push_str(&format!("\x1b[{code}m"));
+                }
+                open = color;
+            }
+            colored.push(character);
+        }
+        if open.is_some() {
+            colored.push_str("\x1b[0m");
+        }
+        colored.push_str(annotation);
+    }
+
+    return colored;
+}
+
+/// Replace the ASCII digits of the table of a text.
+///
+/// It maps the digits inside the borders of the table to the
+/// glyphs of the digit set, one character for another, so the
+/// layout is kept. The section titles, like `Sub 1.`, the
+/// annotations after the right border, like ` 1 R`, and the other
+/// lines are kept in ASCII.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let text: &str = "┃ 3 │ 5 ┃ P\n";
+/// let expected: &str = "┃ ٣ │ ٥ ┃ P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// use long_multiplication_command_line::options::{DigitSet, TableStyle};
+/// let localized: String = generate::localize_digits(text, &TableStyle::unicode(), DigitSet::ArabicIndic);
+///
+/// assert_eq!(expected, localized);
+/// ```
+pub fn localize_digits(text: &str, style: &TableStyle, digits: DigitSet) -> String {
+    if digits == DigitSet::Ascii {
+        return text.to_string();
+    }
+
+    let mut localized: String = String::with_capacity(text.len() * 2);
+    for line in text.split_inclusive('\n') {
+        let (table, annotation): (&str, &str) = match split_table_line(line, style) {
+            Some(parts) => parts,
+            None => {
+                localized.push_str(line);
+                continue;
+            }
+        };
+        let title: bool = table.chars().nth(1).is_some_and(|character| character.is_alphabetic());
+        if title {
+            localized.push_str(line);
+            continue;
+        }
+
+        for character in table.chars() {
+            match character.to_digit(10) {
+                Some(digit) => localized.push(digits.digit_glyph(digit as u8)),
+                None => localized.push(character),
+            }
+        }
+        localized.push_str(annotation);
+    }
+
+    return localized;
+}
+
+fn split_table_line<'a>(line: &'a str, style: &TableStyle) -> Option<(&'a str, &'a str)> {
+    let left_edges: [char; 5] = [style.outer, style.top_left, style.bottom_left, style.heavy_left, style.light_left];
+    let right_edges: [char; 5] = [style.outer, style.top_right, style.bottom_right, style.heavy_right, style.light_right];
+
+    let first: Option<char> = line.chars().next();
+    if first.is_none_or(|character| !left_edges.contains(&character)) {
+        return None;
+    }
+
+    // The annotation after the right border, like ` 1 ^`, names the row.
+    let parts: (&str, &str) = match line.char_indices().rfind(|(index, c)| *index > 0 && right_edges.contains(c)) {
+        Some((index, character)) => line.split_at(index + character.len_utf8()),
+        None => (line, ""),
+    };
+    return Some(parts);
+}
+
+fn column_span(table: &str, style: &TableStyle, position: usize) -> Option<Range<usize>> {
+    // Only the rows of cells have an inner line after the first cell; the titles have none.
+    let characters: Vec<char> = table.chars().collect();
+    // The search starts after the left edge, which is also `|` in the ASCII style.
+    let width: usize = characters.iter().skip(1).position(|c| *c == style.inner || *c == style.guide)?;
+    let columns: usize = (characters.len() - 1) / (width + 1);
+    let separators: bool = (1..columns).all(|column| {
+        let separator: char = characters[column * (width + 1)];
+        return separator == style.inner || separator == style.guide || separator == '.';
+    });
+    if width == 0 || !separators || position == 0 || position > columns {
+        return None;
+    }
+
+    let start: usize = (columns - position) * (width + 1) + 1;
+    return Some(start..start + width);
+}
+
+/// Remove the ANSI escape sequences of a text.
+///
+/// It removes the control sequences (`ESC [ ... letter`), like
+/// the colors, and any other two-character escape sequence,
+/// returning the plain text.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let text: &str = "┃ \x1b[1;32m3\x1b[0m │ 5 ┃ P\n";
+/// let expected: &str = "┃ 3 │ 5 ┃ P\n";
+///
+/// use long_multiplication_command_line::generate;
+/// let plain: String = generate::strip_ansi(text);
+///
+/// assert_eq!(expected, plain);
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+    let mut plain: String = String::with_capacity(text.len());
+
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        if character != '\x1b' {
+            plain.push(character);
+            continue;
+        }
+
+        // Skip the control sequence up to its final letter.
+        if characters.next() == Some('[') {
+            for code in characters.by_ref() {
+                if ('\x40'..='\x7e').contains(&code) {
+                    break;
+                }
+            }
+        }
+    }
+
+    return plain;
+}
+
+/// Collapse the consecutive identical border lines of a table.
+///
+/// When some sections are suppressed, the borders around them
+/// can end up next to each other. It keeps only one of each run
+/// of identical full-width border lines, e.g. two `┣━━━┷━━━┫`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let mut text: String = String::from("┃ 3 │ 5 ┃ 1 R\n\
+///                                      ┣━━━┷━━━┫\n\
+///                                      ┣━━━┷━━━┫\n\
+///                                      ┃Pro.   ┃\n");
+/// let expected: &str = "┃ 3 │ 5 ┃ 1 R\n\
+///                       ┣━━━┷━━━┫\n\
+///                       ┃Pro.   ┃\n";
+///
+/// use long_multiplication_command_line::generate;
+/// generate::dedupe_borders(&mut text);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn dedupe_borders(text: &mut String) {
+    let mut deduped: String = String::with_capacity(text.len());
+
+    let mut previous: &str = "";
+    for line in text.split_inclusive('\n') {
+        if line == previous && is_border(line) {
+            continue;
+        }
+        deduped.push_str(line);
+        previous = line;
+    }
+
+    *text = deduped;
+}
+
+/// Cut the lines of a table to the maximum width.
+///
+/// It keeps the first `width` characters of each line, so the
+/// row labels, the legend and the notes wider than the table are
+/// cut, like with `LayoutConfig::max_width`. The line feeds are
+/// kept.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let text: &str = "┃ 3 │ 5 ┃ 1 R\n┣━━━┷━━━┫\n";
+/// let expected: &str = "┃ 3 │ 5 ┃ 1\n┣━━━┷━━━┫\n";
+///
+/// use long_multiplication_command_line::generate;
+///
+/// assert_eq!(expected, generate::clip_lines(text, 11));
+/// ```
+pub fn clip_lines(text: &str, width: usize) -> String {
+    let mut clipped: String = String::with_capacity(text.len());
+
+    for line in text.split_inclusive('\n') {
+        let content: &str = line.strip_suffix('\n').unwrap_or(line);
+        clipped.extend(content.chars().take(width));
+        if line.ends_with('\n') {
+            clipped.push('\n');
+        }
+    }
+
+    return clipped;
+}
+
+fn is_border(line: &str) -> bool {
+    let line: &str = line.trim_end();
+    return !line.is_empty() && line.chars().all(|c| "┏┓┗┛┣┫┠┨━─┄┈┯┷┿┼┬┴+-".contains(c));
+}
+
+fn sign_note(negative: bool) -> &'static str {
+    if negative {
+        return "(-)";
+    }
+
+    return "(+)";
+}
+
+fn table_length(multiplicand: &String, multiplier: &String, options: &TableOptions) -> usize {
+    // Every section is widened to the padded product, so the columns stay aligned.
+    let natural: usize = get_strings_length(multiplicand, multiplier);
+    let length: usize = options.layout.pad_product_to.map_or(natural, |pad| pad.max(natural));
+    if options.signs.0 == options.signs.1 {
+        return length;
+    }
+
+    // A negative product without a free column on its left gets one for the sign.
+    let product: Vec<usize> = fully_reduce_with_radix(&break_down_addition_with_radix(multiplicand, multiplier, options.radix), options.radix);
+    let significant: usize = product.iter().rposition(|digit| *digit != 0).map_or(0, |index| index + 1);
+    if significant > 0 && significant.max(options.product_decimals() + 1) >= length {
+        return length + 1;
+    }
+    return length;
+}
+
+pub(crate) fn body_dimensions(multiplicand: &String, multiplier: &String, options: &TableOptions) -> (usize, usize, String) {
+    // The rows of the operations and the sums are counted from the digits, without drawing them.
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let radix: u32 = options.radix;
+    let length: usize = table_length(multiplicand, multiplier, options);
+    let step: usize = get_string_length(multiplicand);
+
+    // Each operation row has three lines, or one with the superscript carries, and a rule before the next.
+    let (rows, hidden): (Vec<usize>, usize) = displayed_rows(get_string_length(multiplier), options);
+    let row_lines: usize = if options.carry_style == CarryStyle::Superscript && width > 1 { 1 } else { 3 };
+    let mut lines: usize = rows.len() * row_lines + rows.len().saturating_sub(1) + 1;
+    if hidden > 0 {
+        lines += usize::from(!rows.is_empty()) + 1;
+    }
+    if rows.is_empty() && hidden == 0 {
+        lines += 1;
+    }
+    let border: usize = length * (width + 1) + 1;
+    let mut columns: usize = rows.iter().map(|row| labelled_width(border, *row)).max().unwrap_or(border);
+    let mut last: String = String::from("");
+    push_rule(style.heavy_left, style.heavy, style.heavy_up, style.heavy_right, length, width, &mut last);
+
+    if options.explain && !rows.is_empty() {
+        // The explained product has two digits when the row has a carry.
+        let breakdown: Breakdown = break_down_multiplication_with_radix(multiplicand, multiplier, radix);
+        for row in &rows {
+            let carried: bool = breakdown.carries()[(row - 1) * step..row * step].iter().any(|carry| *carry > 0);
+            columns = columns.max(29 + row.to_string().len() + usize::from(carried));
+        }
+        lines += rows.len() * step;
+        last.clear();
+    }
+
+    if options.sum_section {
+        let additions: Vec<usize> = break_down_addition_with_radix(multiplicand, multiplier, radix);
+        let (sum_lines, sum_columns): (usize, usize) = sum_rows_dimensions(&additions, length, options);
+        lines += 2 + sum_lines;
+        columns = columns.max(sum_columns);
+
+        // Each subtotal has a title between two rules.
+        let mut sub_addition: Vec<usize> = break_down_subtotal_with_radix(&additions, radix);
+        while requires_subtotal(&sub_addition, radix) {
+            let (sum_lines, sum_columns): (usize, usize) = sum_rows_dimensions(&sub_addition, length, options);
+            lines += 3 + sum_lines;
+            columns = columns.max(sum_columns);
+            sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
+        }
+        last.clear();
+    }
+
+    return (lines, columns, last);
+}
+
+fn sum_rows_dimensions(numbers: &Vec<usize>, length: usize, options: &TableOptions) -> (usize, usize) {
+    let width: usize = options.layout.cell_width;
+    let border: usize = length * (width + 1) + 1;
+    if options.sum == SumVerbosity::Compact {
+        let places: usize = numbers.iter().map(|number| to_radix_string(*number, options.radix).len()).max().unwrap_or(0);
+        return ((places * 2).saturating_sub(1), border);
+    }
+
+    // The rows stop at the last column, and a row wider than the table pushes its label.
+    let rows: usize = numbers.len().min(length);
+    let columns: usize = numbers.iter().take(rows).enumerate().map(|(index, number)| {
+        let cells: usize = length.max(index + to_radix_string(*number, options.radix).len());
+        return labelled_width(cells * (width + 1) + 1, index + 1);
+    }).max().unwrap_or(0);
+    return ((rows * 2).saturating_sub(1), columns);
+}
+
+fn labelled_width(border: usize, row: usize) -> usize {
+    // The label is a space, the row number, a space and its letter, like ` 12 R`.
+    return border + row.to_string().len() + 3;
+}
+
+fn requires_subtotal(sub_addition: &Vec<usize>, radix: u32) -> bool {
+    return sub_addition.iter().any(|number| *number >= radix as usize);
+}
+
+fn product_row_left(digits: &[usize], columns: usize, options: &TableOptions, text: &mut String) {
+    // The integer digit before the decimal point is kept, like in `0.01`.
+    let decimals: usize = options.product_decimals();
+    let mut first: usize = 0;
+    while first + 1 + decimals < digits.len() && digits[first] == 0 {
+        first += 1;
+    }
+    let padding: usize = columns.saturating_sub(digits.len() - first);
+
+    let mut cells: Vec<Option<usize>> = digits[first..].iter().map(|digit| Some(*digit)).collect();
+    cells.extend(vec![None; padding]);
+
+    // The sign is the first cell, shifting the digits one column to the right.
+    let mut sign: Option<usize> = None;
+    if is_negative_product(digits, options) && cells.last() == Some(&None) {
+        cells.pop();
+        cells.insert(0, None);
+        sign = Some(0);
+    }
+    let padding: usize = cells.iter().rev().take_while(|cell| cell.is_none()).count();
+    let decimal_column: usize = if decimals > 0 { decimals + padding } else { 0 };
+    generate_product_cells(&cells, decimal_column, sign, options, text);
+}
+
+fn is_negative_product(digits: &[usize], options: &TableOptions) -> bool {
+    // The zero has no sign, even when one operand is negative.
+    return options.signs.0 != options.signs.1 && digits.iter().any(|digit| *digit != 0);
+}
+
+fn generate_product_cells(cells: &[Option<usize>], decimal_column: usize, sign: Option<usize>, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let length: usize = cells.len();
+
+    text.push(style.outer);
+    for (n, cell) in cells.iter().enumerate() {
+        match cell {
+            _ if sign == Some(n) => push_cell("-", width, text),
+            Some(digit) => push_cell(&digit_to_char(*digit, options.radix).to_string(), width, text),
+            None => push_cell("", width, text),
+        }
+
+        // The columns are numbered from right to left, like the positions.
+        let column: usize = length - n - 1;
+        if column == 0 {
+            break;
+        }
+        if column == decimal_column {
+            text.push('.');
+        } else if options.group_guides && column.is_multiple_of(3) {
+            text.push(style.guide);
+        } else {
+            text.push(style.inner);
+        }
+    }
+
+    text.push(style.outer);
+    text.push_str(" P");
+    text.push('\n');
+}
+
+fn push_rule(left: char, fill: char, joint: char, right: char, length: usize, width: usize, text: &mut String) {
+    text.push(left);
+    for n in 1..length + 1 {
+        for _ in 0..width {
+            text.push(fill);
+        }
+        if n == length {
+            break;
+        }
+        text.push(joint);
+    }
+    text.push(right);
+    text.push('\n');
+}
+
+fn push_sum_rows(numbers: &Vec<usize>, length: usize, options: &TableOptions, text: &mut String) {
+    match options.sum {
+        SumVerbosity::Full => generate_rows_with_numbers(numbers, length, options, text),
+        SumVerbosity::Compact => generate_rows_by_place(numbers, length, options, text),
+    }
+}
+
+fn generate_rows_by_place(numbers: &Vec<usize>, length: usize, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let digits: Vec<String> = numbers.iter().map(|number| to_radix_string(*number, options.radix)).collect();
+    let places: usize = digits.iter().map(|number| number.len()).max().unwrap_or(0);
+
+    for place in 0..places {
+        // Create the row of the place, shifted one column per place
+        text.push(style.outer);
+        for column in (0..length).rev() {
+            let digit: Option<char> = column.checked_sub(place)
+                .and_then(|index| digits.get(index))
+                .and_then(|number| number.chars().rev().nth(place));
+            match digit {
+                Some(character) => push_cell(&character.to_string(), width, text),
+                None => push_cell("", width, text),
+            }
+            if column > 0 {
+                text.push(style.inner);
+            }
+        }
+        text.push(style.outer);
+        text.push('\n');
+
+        // Create the separator from the next place
+        if place + 1 < places {
+            push_rule(style.light_left, style.dotted, style.light_cross, style.light_right, length, width, text);
+        }
+    }
+}
+
+fn generate_rows_with_numbers(numbers: &Vec<usize>, length: usize, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+    let width: usize = options.layout.cell_width;
+    let mut iteration: usize = 0;
+
+    for row in numbers {
+        // Create first row
+        let row_digits: String = to_radix_string(*row, options.radix);
+        let row_size: usize = row_digits.len();
+        text.push(style.outer);
+        for _ in 0..length.saturating_sub(iteration + row_size) {
+            push_cell("", width, text);
+            text.push(style.inner);
+        }
+
+        for i in row_digits.chars() {
+            push_cell(&i.to_string(), width, text);
+            text.push(style.inner);
+        }
+        text.pop();
+
+        if iteration > 0 {
+            text.push(style.inner);
+        }
+        for n in 0..iteration {
+            push_cell("", width, text);
+            if n == iteration - 1 {
+                break;
+            }
+            text.push(style.inner);
+        }
+        iteration += 1;
+        text.push(style.outer);
+        text.push(' ');
+        let row: String = iteration.to_string();
+        text.push_str(&row);
+        text.push_str(" C");
+        text.push('\n');
+
+        // Create second row
+        if iteration == length || iteration == numbers.len() {
+            break;
+        }
+        push_rule(style.light_left, style.dotted, style.light_cross, style.light_right, length, width, text);
+    }
+}
+
+fn split_digits(number: &str, m: usize) -> (String, String) {
+    // The high part is `0` when the number has `m` digits or fewer.
+    let split: usize = number.len().saturating_sub(m);
+    let (high, low): (&str, &str) = number.split_at(split);
+    return (normalize_operand(&format!("0{high}")), normalize_operand(low));
+}
+
+fn shift_digits(number: &str, places: usize) -> String {
+    if number == "0" {
+        return String::from("0");
+    }
+
+    return format!("{number}{}", "0".repeat(places));
+}
+
+fn push_superscript_row(carries: &[usize], units: &[usize], start: usize, length: usize, options: &TableOptions, text: &mut String) {
+    let style: &TableStyle = &options.style;
+
+    // The units start one column to the right of their carries.
+    let mut cells: Vec<String> = vec![String::from(""); length];
+    for (n, unit) in units.iter().enumerate() {
+        if let Some(cell) = cells.get_mut(start + 1 + n) {
+            cell.push(digit_to_char(*unit, options.radix));
+        }
+    }
+    for (n, carry) in carries.iter().enumerate() {
+        if *carry == 0 {
+            continue;
+        }
+        if let Some(cell) = cells.get_mut(start + n) {
+            if cell.is_empty() {
+                cell.push(' ');
+            }
+            cell.push(superscript_digit(*carry, options.radix));
+        }
+    }
+
+    text.push(style.outer);
+    for (n, cell) in cells.iter().enumerate() {
+        push_cell(cell, options.layout.cell_width, text);
+        if n + 1 < length {
+            text.push(style.inner);
+        }
+    }
+    text.push(style.outer);
+}
+
+fn superscript_digit(digit: usize, radix: u32) -> char {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    // The carries of the bases above ten have no superscript letters.
+    return match SUPERSCRIPTS.get(digit) {
+        Some(superscript) => *superscript,
+        None => digit_to_char(digit, radix),
+    };
+}
+
+fn push_cell(content: &str, width: usize, text: &mut String) {
+    // The content wider than the cell keeps its last characters.
+    let size: usize = content.chars().count();
+    let content: String = content.chars().skip(size.saturating_sub(width)).collect();
+    let size: usize = size.min(width);
+
+    let left: usize = (width + 1 - size) / 2;
+    text.push_str(&" ".repeat(left));
+    text.push_str(&content);
+    text.push_str(&" ".repeat(width - size - left));
+}
+
+fn displayed_rows(count: usize, options: &TableOptions) -> (Vec<usize>, usize) {
+    let mut rows: Vec<usize> = (1..count + 1).filter(|row| options.rows.includes(*row)).collect();
+    if options.order == RowOrder::Msd {
+        // Each row keeps its number and its columns, so the sum is the same.
+        rows.reverse();
+    }
+    let hidden: usize = match options.max_rows {
+        Some(max) => rows.len().saturating_sub(max),
+        None => 0,
+    };
+    rows.truncate(rows.len() - hidden);
+
+    return (rows, hidden);
+}
+
+fn hidden_rows_marker(hidden: usize, interior: usize) -> String {
+    let noun: &str = if hidden == 1 { "row" } else { "rows" };
+    let marker: String = format!("… ({hidden} more {noun})");
+
+    // The short marker fits in the narrow tables, like a single column.
+    if marker.chars().count() > interior {
+        return format!("…+{hidden}");
+    }
+    return marker;
+}
+
+fn filtered_rows_marker(interior: usize) -> String {
+    let marker: String = String::from("… (rows filtered)");
+
+    // The short marker fits in the narrow tables, like a single column.
+    if marker.chars().count() > interior {
+        return String::from("…");
+    }
+    return marker;
+}
+
+fn push_title(label: &str, length: usize, width: usize, style: &TableStyle, text: &mut String) {
+    // The label is cut when the row is narrower, like in the compact cells.
+    let interior: usize = length * (width + 1) - 1;
+    let label: String = label.chars().take(interior).collect();
+
+    text.push(style.outer);
+    text.push_str(&label);
+    text.push_str(&" ".repeat(interior - label.chars().count()));
+    text.push(style.outer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{COMPACT_CELL_WIDTH, LayoutConfig};
+
+    // # -----------------------------------------------------------------------
+    // # Function: symbols
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_symbols_description() {
+        // Arrange
+        let mut text: String = String::from("");
+        let expected: &str = "Symbols\n\
+                              =======\n\
+                              Pos. = Position.\n\
+                              Ops. = Operations of the long multiplication.\n\
+                              Sum. = Sum of each column of the multiplication.\n\
+                              Sub n. = Subtotal of the last sum.\n\
+                              Pro. = Product of the multiplication.\n\
+                              n ^ = Carry-over.\n\
+                              n R = The row number.\n\
+                              n C = The column number of the sum of the rows.\n\
+                              * Replace 'n' for a number.\n\
+                              P = The product of multiplication.\n\
+                              \n";
+
+        // Action
+        symbols(&mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: title
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_title_centered_over_the_table() {
+        // Arrange
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
+        let mut text: String = String::from("");
+        let expected: &str = "               Warm-up\n";
+
+        // Action
+        title(&multiplicand, &multiplier, "Warm-up", &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_title_wider_than_the_table() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let mut text: String = String::from("");
+        let expected: &str = "Multiplication warm-up\n";
+
+        // Action
+        title(&multiplicand, &multiplier, "Multiplication warm-up", &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: symbols_with_context
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_symbols_with_context_five_by_seven() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions::default();
+        let mut text: String = String::from("");
+
+        // Action
+        let context: LegendContext = legend_context(&multiplicand, &multiplier, &options);
+        symbols_with_context(&options, &context, &mut text);
+
+        // Assert
+        assert!(!text.contains("Sub n."));
+        assert!(text.contains("Sum. = Sum of each column of the multiplication.\n"));
+        assert!(text.contains("n C = The column number of the sum of the rows.\n"));
+    }
+
+    #[test]
+    fn test_symbols_with_context_without_numbered_rows() {
+        // Arrange
+        let context: LegendContext = LegendContext { subtotals: false, columns: false, carries: false, rows: false, ..LegendContext::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "Symbols\n\
+                              =======\n\
+                              Pos. = Position.\n\
+                              Ops. = Operations of the long multiplication.\n\
+                              Sum. = Sum of each column of the multiplication.\n\
+                              Pro. = Product of the multiplication.\n\
+                              P = The product of multiplication.\n\
+                              \n";
+
+        // Action
+        symbols_with_context(&TableOptions::default(), &context, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: legend_context
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_legend_context_without_displayed_rows() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions { rows: RowFilter::Even, ..TableOptions::default() };
+
+        // Action
+        let context: LegendContext = legend_context(&multiplicand, &multiplier, &options);
+
+        // Assert
+        assert!(!context.rows);
+        assert!(!context.carries);
+        assert!(context.columns);
+    }
+
+    #[test]
+    fn test_legend_context_superscript_carries() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let superscript: TableOptions = TableOptions { carry_style: CarryStyle::Superscript, ..TableOptions::default() };
+        let compact: TableOptions = TableOptions {
+            layout: LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() },
+            ..superscript.clone()
+        };
+
+        // Action
+        let wide: LegendContext = legend_context(&multiplicand, &multiplier, &superscript);
+        let narrow: LegendContext = legend_context(&multiplicand, &multiplier, &compact);
+
+        // Assert
+        assert!(!wide.carries);
+        assert!(wide.rows);
+        assert!(narrow.carries);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: place_values
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_place_values_three_columns() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("3");
+        let mut text: String = String::from("");
+
+        // Action
+        place_values(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert!(text.contains("Pos. 1 = ones.\n"));
+        assert!(text.contains("Pos. 3 = hundreds.\n"));
+        assert!(!text.contains("Pos. 4"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: top_border
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_top_border_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("2");
+        let multiplier: String = String::from("4");
+        let mut text: String = String::from("");
+        let expected: &str = "┏━━━━━━━┓\n";
+
+        // Action
+        top_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_top_border_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("3");
+        let mut text: String = String::from("");
+        let expected: &str = "┏━━━━━━━━━━━┓\n";
+
+        // Action
+        top_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_top_border_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("345");
+        let multiplier: String = String::from("12");
+        let mut text: String = String::from("");
+        let expected: &str = "┏━━━━━━━━━━━━━━━━━━━┓\n";
+
+        // Action
+        top_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_top_border_size_twelve_digits() {
+        // Arrange
+        let multiplicand: String = String::from("123456");
+        let multiplier: String = String::from("123456");
+        let mut text: String = String::from("");
+        let expected: &str = "┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓\n";
+
+        // Action
+        top_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: top_border_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_top_border_with_options_ascii() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("4");
+        let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "+-----------+\n";
+
+        // Action
+        top_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_top_border_with_options_default() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("4");
+        let options: TableOptions = TableOptions::default();
+        let mut text: String = String::from("");
+        let mut expected: String = String::from("");
+
+        // Action
+        top_border(&multiplicand, &multiplier, &mut expected);
+        top_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: bottom_border
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_bottom_border_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("3");
+        let mut text: String = String::from("");
+        let expected: &str = "┗━━━┷━━━┛\n";
+
+        // Action
+        bottom_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_bottom_border_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("8");
+        let multiplier: String = String::from("43");
+        let mut text: String = String::from("");
+        let expected: &str = "┗━━━┷━━━┷━━━┛\n";
+
+        // Action
+        bottom_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_bottom_border_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("519");
+        let multiplier: String = String::from("43");
+        let mut text: String = String::from("");
+        let expected: &str = "┗━━━┷━━━┷━━━┷━━━┷━━━┛\n";
+
+        // Action
+        bottom_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_bottom_border_size_twelve_digits() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("1234567890");
+        let mut text: String = String::from("");
+        let expected: &str = "┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n";
+
+        // Action
+        bottom_border(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: bottom_border_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_bottom_border_with_options_ascii() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("4");
+        let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "+---+---+---+\n";
+
+        // Action
+        bottom_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_bottom_border_with_options_pad_product_to() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let layout: LayoutConfig = LayoutConfig { pad_product_to: Some(5), ..LayoutConfig::default() };
+        let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┗━━━┷━━━┷━━━┷━━━┷━━━┛\n";
+
+        // Action
+        bottom_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: position_title
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_position_title_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("6");
+        let multiplier: String = String::from("3");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Pos.   ┃\n\
+                              ┠┄┄┄┬┄┄┄┨\n\
+                              ┃ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┫\n";
+
+        // Action
+        position_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_position_title_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("18");
+        let multiplier: String = String::from("6");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Pos.       ┃\n\
+                              ┠┄┄┄┬┄┄┄┬┄┄┄┨\n\
+                              ┃ 3 │ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┷━━━┫\n";
+
+        // Action
+        position_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_position_title_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("78");
+        let multiplier: String = String::from("327");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Pos.               ┃\n\
+                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
+                              ┃ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        position_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_position_title_size_eleven_digits() {
+        // Arrange
+        let multiplicand: String = String::from("123456");
+        let multiplier: String = String::from("54321");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Pos.                                       ┃\n\
+                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
+                              ┃ 11│ 10│ 9 │ 8 │ 7 │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        position_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: operation_title
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_operation_title_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("9");
+        let multiplier: String = String::from("1");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Ops.   ┃\n\
+                              ┣━━━┯━━━┫\n";
+
+        // Action
+        operation_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operation_title_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("53");
+        let multiplier: String = String::from("4");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Ops.       ┃\n\
+                              ┣━━━┯━━━┯━━━┫\n";
+
+        // Action
+        operation_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operation_title_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("53");
+        let multiplier: String = String::from("618");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Ops.               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n";
+
+        // Action
+        operation_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operation_title_size_eleven_digits() {
+        // Arrange
+        let multiplicand: String = String::from("654321");
+        let multiplier: String = String::from("12345");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Ops.                                       ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n";
+
+        // Action
+        operation_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: operation_title_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_operation_title_with_options_signs() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("34");
+        let options: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃Ops.           ┃ (-) x (+)\n\
+                              ┣━━━┯━━━┯━━━┯━━━┫\n";
+
+        // Action
+        operation_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: multiplication
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_multiplication_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("8");
+        let multiplier: String = String::from("4");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 8 ┃\n\
+                              ┃ x │ 4 ┃\n\
+                              ┣━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_multiplication_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("2");
+        let multiplier: String = String::from("37");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ 2 ┃\n\
+                              ┃ x │ 3 │ 7 ┃\n\
+                              ┣━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_multiplication_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("81");
+        let multiplier: String = String::from("925");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │ 8 │ 1 ┃\n\
+                              ┃ x │   │ 9 │ 2 │ 5 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_multiplication_size_eleven_digits() {
+        // Arrange
+        let multiplicand: String = String::from("12345");
+        let multiplier: String = String::from("654321");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │   │   │ 1 │ 2 │ 3 │ 4 │ 5 ┃\n\
+                              ┃ x │   │   │   │   │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_multiplication_multiplicand_bigger_than_a_multiplier() {
+        // Arrange
+        let multiplicand: String = String::from("1234");
+        let multiplier: String = String::from("5");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 1 │ 2 │ 3 │ 4 ┃\n\
+                              ┃ x │   │   │   │ 5 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_multiplication_multiplier_bigger_than_a_multiplicand() {
+        // Arrange
+        let multiplicand: String = String::from("8765");
+        let multiplier: String = String::from("1234");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │ 8 │ 7 │ 6 │ 5 ┃\n\
+                              ┃ x │   │   │   │ 1 │ 2 │ 3 │ 4 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: multiplication_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_multiplication_with_options_multiplier_on_top() {
+        // Arrange
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("345");
+        let options: TableOptions = TableOptions { top: TopOperand::Multiplier, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ 3 │ 4 │ 5 ┃\n\
+                              ┃ x │   │   │ 1 │ 2 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+
+        // Action
+        multiplication_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: operations
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_operations_with_three_digits_multiplicand_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("25");
+        let multiplier: String = String::from("3");
+        let mut text: String = String::from("");
+        let expected: &str = "┃ 0 │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 6 │ 5 ┃ 1 R\n\
+                              ┣━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_single_digits() {
+        // Arrange
+        let cases: [(&str, &str, &str); 3] = [
+            ("1", "1", "┃ 0 │   ┃ 1 ^\n┠┈┈┈┼┈┈┈┨\n┃   │ 1 ┃ 1 R\n┣━━━┷━━━┫\n"),
+            ("9", "1", "┃ 0 │   ┃ 1 ^\n┠┈┈┈┼┈┈┈┨\n┃   │ 9 ┃ 1 R\n┣━━━┷━━━┫\n"),
+            ("1", "9", "┃ 0 │   ┃ 1 ^\n┠┈┈┈┼┈┈┈┨\n┃   │ 9 ┃ 1 R\n┣━━━┷━━━┫\n"),
+        ];
+
+        for (multiplicand, multiplier, expected) in cases {
+            let mut text: String = String::from("");
+
+            // Action
+            operations(&String::from(multiplicand), &String::from(multiplier), &mut text);
+
+            // Assert
+            assert_eq!(expected, text);
+        }
+    }
+
+    #[test]
+    fn test_operations_with_three_digits_multiplicand_is_less() {
+        // Arrange
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("25");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 5 ┃ 1 R\n\
+                              ┠───┼───┼───┨\n\
+                              ┃ 0 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 6 │   ┃ 2 R\n\
+                              ┣━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_four_digit() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 0 │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 6 │ 8 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┨\n\
+                              ┃ 0 │ 0 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 2 │ 6 │   ┃ 2 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_eleven_digits_multiplicand_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("246802468");
+        let multiplier: String = String::from("357");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ 1 │ 2 │ 4 │ 5 │ 0 │ 1 │ 2 │ 4 │ 5 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 4 │ 8 │ 2 │ 6 │ 0 │ 4 │ 8 │ 2 │ 6 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │ 1 │ 2 │ 3 │ 4 │ 0 │ 1 │ 2 │ 3 │ 4 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │   ┃ 2 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃ 0 │ 1 │ 1 │ 2 │ 0 │ 0 │ 1 │ 1 │ 2 │   │   │   ┃ 3 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 6 │ 2 │ 8 │ 4 │ 0 │ 6 │ 2 │ 8 │ 4 │   │   ┃ 3 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_eleven_digits_multiplicand_is_less() {
+        // Arrange
+        let multiplicand: String = String::from("357");
+        let multiplier: String = String::from("246802468");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │   │   │   │   │ 2 │ 4 │ 5 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │ 4 │ 0 │ 6 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │ 3 │ 4 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │ 8 │ 0 │ 2 │   ┃ 2 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │ 1 │ 2 │ 2 │   │   │   ┃ 3 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 2 │ 0 │ 8 │   │   ┃ 3 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │ 0 │ 1 │ 1 │   │   │   │   ┃ 4 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 6 │ 0 │ 4 │   │   │   ┃ 4 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │ 0 │ 0 │ 0 │   │   │   │   │   ┃ 5 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 0 │ 0 │ 0 │   │   │   │   ┃ 5 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │ 2 │ 4 │ 5 │   │   │   │   │   │   ┃ 6 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 4 │ 0 │ 6 │   │   │   │   │   ┃ 6 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │ 1 │ 3 │ 4 │   │   │   │   │   │   │   ┃ 7 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 8 │ 0 │ 2 │   │   │   │   │   │   ┃ 7 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │ 1 │ 2 │ 2 │   │   │   │   │   │   │   │   ┃ 8 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 2 │ 0 │ 8 │   │   │   │   │   │   │   ┃ 8 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃ 0 │ 1 │ 1 │   │   │   │   │   │   │   │   │   ┃ 9 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 6 │ 0 │ 4 │   │   │   │   │   │   │   │   ┃ 9 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_thirteen_rows() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("9876543210123");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │   │ 2 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │   │   │ 1 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │ 1 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │   │ 4 │   ┃ 2 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 0 │   │   │   ┃ 3 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │ 7 │   │   ┃ 3 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   │   │   ┃ 4 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 0 │   │   │   ┃ 4 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │   │ 0 │   │   │   │   │   ┃ 5 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │   │   │   │   │   │   ┃ 6 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │   │ 2 │   │   │   │   │   │   │   ┃ 7 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │   │   │   │   │   │   ┃ 7 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │   │ 2 │   │   │   │   │   │   │   │   ┃ 8 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 8 │   │   │   │   │   │   │   ┃ 8 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │   │ 3 │   │   │   │   │   │   │   │   │   ┃ 9 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 5 │   │   │   │   │   │   │   │   ┃ 9 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │   │ 4 │   │   │   │   │   │   │   │   │   │   ┃ 10 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 2 │   │   │   │   │   │   │   │   │   ┃ 10 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │ 4 │   │   │   │   │   │   │   │   │   │   │   ┃ 11 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 9 │   │   │   │   │   │   │   │   │   │   ┃ 11 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │ 5 │   │   │   │   │   │   │   │   │   │   │   │   ┃ 12 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃ 6 │   │   │   │   │   │   │   │   │   │   │   │   │   ┃ 13 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 3 │   │   │   │   │   │   │   │   │   │   │   │   ┃ 13 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: operations_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_operations_with_options_even_rows() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+        let options: TableOptions = TableOptions { rows: RowFilter::Even, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 0 │ 1 │ 1 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 5 │ 0 │ 5 │   ┃ 2 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_no_even_rows() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions { rows: RowFilter::Even, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃…      ┃\n\
+                              ┣━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_no_even_rows_in_a_wide_table() {
+        // Arrange
+        let multiplicand: String = String::from("12345");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions { rows: RowFilter::Even, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃… (rows filtered)      ┃\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_carry_fill() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("45");
+        let style: TableStyle = TableStyle { carry_fill: '·', ..TableStyle::unicode() };
+        let options: TableOptions = TableOptions { style, ..TableOptions::default() };
+        let mut plain: String = String::from("");
+        let mut text: String = String::from("");
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut plain);
+        operations(&multiplicand, &multiplier, &mut plain);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        let changed: Vec<(&str, &str)> = plain.lines().zip(text.lines()).filter(|(a, b)| a != b).collect();
+        assert_eq!(2, changed.len());
+        for (before, after) in changed {
+            assert_eq!("┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨", before);
+            assert_eq!("┠···┼···┼···┼···┼···┨", after);
+        }
+    }
+
+    #[test]
+    fn test_operations_with_options_msd_order() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("45");
+        let options: TableOptions = TableOptions { order: RowOrder::Msd, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃ 0 │ 0 │ 1 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 4 │ 8 │ 2 │   ┃ 2 R\n\
+                              ┠───┼───┼───┼───┼───┨\n\
+                              ┃   │ 0 │ 1 │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 5 │ 0 │ 5 ┃ 1 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_lsd_order() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("45");
+        let options: TableOptions = TableOptions { order: RowOrder::Lsd, ..TableOptions::default() };
+        let mut text: String = String::from("");
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(2, text.matches(" R\n").count());
+        assert!(text.find("1 R").unwrap() < text.find("2 R").unwrap());
+    }
+
+    #[test]
+    fn test_operations_with_options_odd_rows() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+        let options: TableOptions = TableOptions { rows: RowFilter::Odd, ..TableOptions::default() };
+        let mut text: String = String::from("");
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.contains(" 1 R\n┠───"));
+        assert!(text.contains(" 3 R\n┣━━━"));
+        assert!(!text.contains(" 2 R"));
+    }
+
+    #[test]
+    fn test_operations_with_options_all_rows() {
+        // Arrange
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("456");
+        let mut text: String = String::from("");
+        let mut expected: String = String::from("");
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &TableOptions::default(), &mut text);
+        operations(&multiplicand, &multiplier, &mut expected);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_superscript_carries() {
+        // Arrange
+        let multiplicand: String = String::from("79");
+        let multiplier: String = String::from("3");
+        let options: TableOptions = TableOptions { carry_style: CarryStyle::Superscript, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let mut rows: String = String::from("");
+        let expected: &str = "┃  ²│ 1²│ 7 ┃ 1 R\n\
+                              ┣━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+        operations(&multiplicand, &multiplier, &mut rows);
+
+        // Assert
+        assert_eq!(expected, text);
+        assert!(text.lines().count() < rows.lines().count());
+        assert!(!text.contains(" ^"));
+    }
+
+    #[test]
+    fn test_operations_with_options_max_rows() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("123");
+        let options: TableOptions = TableOptions { max_rows: Some(1), ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ 2 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 1 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┨\n\
+                              ┃… (2 more rows)┃\n\
+                              ┣━━━┷━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_max_rows_narrow_table() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("12");
+        let options: TableOptions = TableOptions { max_rows: Some(1), ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 4 ┃ 1 R\n\
+                              ┠───┼───┼───┨\n\
+                              ┃…+1        ┃\n\
+                              ┣━━━┷━━━┷━━━┫\n";
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_operations_with_options_max_rows_one_more_row() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("1234");
+        let options: TableOptions = TableOptions { max_rows: Some(3), ..TableOptions::default() };
+        let mut text: String = String::from("");
+
+        // Action
+        operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.contains("┃… (1 more row)     ┃\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: sum_title
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_sum_title_size_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("4");
+        let multiplier: String = String::from("2");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Sum.   ┃\n\
+                              ┣━━━┯━━━┫\n";
+
+        // Action
+        sum_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_sum_title_size_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("19");
+        let multiplier: String = String::from("5");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Sum.       ┃\n\
+                              ┣━━━┯━━━┯━━━┫\n";
+
+        // Action
+        sum_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_sum_title_size_five_digits() {
+        // Arrange
+        let multiplicand: String = String::from("73");
+        let multiplier: String = String::from("438");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Sum.               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n";
+
+        // Action
+        sum_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_sum_title_size_eleven_digits() {
+        // Arrange
+        let multiplicand: String = String::from("123456");
+        let multiplier: String = String::from("54321");
+        let mut text: String = String::from("");
+        let expected: &str = "┃Sum.                                       ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n";
+
+        // Action
+        sum_title(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: long_sum
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_long_sum_with_one_digit() {
+        // Arrange
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("2");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   ┃ 2 C\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Pro.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃ 0 │ 6 ┃ P\n";
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_long_sum_with_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("9");
+        let multiplier: String = String::from("9");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │ 1 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃ 8 │   ┃ 2 C\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Pro.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃ 8 │ 1 ┃ P\n";
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_long_sum_with_three_digits() {
+        // Arrange
+        let multiplicand: String = String::from("37");
+        let multiplier: String = String::from("5");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ 5 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 1 │   │   ┃ 3 C\n\
+                              ┣━━━┷━━━┷━━━┫\n\
+                              ┃Pro.       ┃\n\
+                              ┣━━━┯━━━┯━━━┫\n\
+                              ┃ 1 │ 8 │ 5 ┃ P\n";
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_long_sum_with_four_digit() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │ 8 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 1 │ 3 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 2 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   ┃ 4 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.           ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ 0 │ 3 │ 3 │ 8 ┃ P\n";
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_long_sum_with_eleven_digits_multiplicand_is_greater() {
+        // Arrange
+        let multiplicand: String = String::from("246802468");
+        let multiplier: String = String::from("357");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │ 2 │ 0 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │ 9 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 6 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 1 │ 4 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 2 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 1 │ 9 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Sub 1.                                         ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │ 1 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 8 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 1 │ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 7 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.                                           ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ 0 │ 8 │ 8 │ 1 │ 0 │ 8 │ 4 │ 8 │ 1 │ 0 │ 7 │ 6 ┃ P\n";
+
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_long_sum_with_eleven_digits_multiplicand_is_less() {
+        // Arrange
+        let multiplicand: String = String::from("357");
+        let multiplier: String = String::from("246802468");
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │ 2 │ 0 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │ 9 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 6 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 1 │ 4 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 2 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 1 │ 9 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Sub 1.                                         ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 1 │ 1 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 8 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 1 │ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 7 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.                                           ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ 0 │ 8 │ 8 │ 1 │ 0 │ 8 │ 4 │ 8 │ 1 │ 0 │ 7 │ 6 ┃ P\n";
 
-        if iteration > 0 {
-            text.push('│');
-        }
-        for n in 0..iteration {
-            text.push_str("   ");
-            if n == iteration - 1 {
-                break;
-            }
-            text.push('│');
-        }
-        iteration += 1;
-        text.push_str("┃ ");
-        let row: String = iteration.to_string();
-        text.push_str(&*row);
-        text.push_str(" C");
-        text.push('\n');
+        // Action
+        long_sum(&multiplicand, &multiplier, &mut text);
 
-        // Create second row
-        if iteration == length {
-            break;
-        }
-        text.push('┠');
-        for n in 1..length + 1 {
-            text.push_str("┈┈┈");
-            if n == length {
-                break;
-            }
-            text.push('┼');
-        }
-        text.push('┨');
-        text.push('\n');
+        // Assert
+        assert_eq!(expected, text);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     // # -----------------------------------------------------------------------
-    // # Function: symbols
+    // # Function: long_sum_with_options
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_symbols_description() {
+    fn test_long_sum_with_options_product_align_left() {
         // Arrange
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("2");
+        let options: TableOptions = TableOptions { product_align: ProductAlign::Left, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "Symbols\n\
-                              =======\n\
-                              Pos. = Position.\n\
-                              Ops. = Operations of the long multiplication.\n\
-                              Sum. = Sum of each column of the multiplication.\n\
-                              Sub n. = Subtotal of the last sum.\n\
-                              Pro. = Product of the multiplication.\n\
-                              n ^ = Carry-over.\n\
-                              n R = The row number.\n\
-                              n C = The column number of the sum of the rows.\n\
-                              * Replace 'n' for a number.\n\
-                              P = The product of multiplication.\n\
-                              \n";
+        let expected: &str = "┃   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   ┃ 2 C\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Pro.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃ 6 │   ┃ P\n";
 
         // Action
-        symbols(&mut text);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
-    // # -----------------------------------------------------------------------
-    // # Function: top_border
-    // # -----------------------------------------------------------------------
     #[test]
-    fn test_top_border_size_two_digits() {
+    fn test_long_sum_with_options_product_align_right() {
         // Arrange
-        let multiplicand: String = String::from("2");
-        let multiplier: String = String::from("4");
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let options: TableOptions = TableOptions { product_align: ProductAlign::Right, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┏━━━━━━━┓\n";
+        let mut expected: String = String::from("");
 
         // Action
-        top_border(&multiplicand, &multiplier, &mut text);
+        long_sum(&multiplicand, &multiplier, &mut expected);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
+        assert!(text.ends_with("┃ 0 │ 3 │ 3 │ 8 ┃ P\n"));
     }
 
     #[test]
-    fn test_top_border_size_three_digits() {
+    fn test_long_sum_with_options_group_guides() {
         // Arrange
-        let multiplicand: String = String::from("12");
-        let multiplier: String = String::from("3");
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
+        let options: TableOptions = TableOptions { group_guides: true, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┏━━━━━━━━━━━┓\n";
+        let mut expected: String = String::from("");
 
         // Action
-        top_border(&multiplicand, &multiplier, &mut text);
+        long_sum(&multiplicand, &multiplier, &mut expected);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
+        let expected: String = expected.replace(
+            "┃ 1 │ 1 │ 7 │ 5 │ 0 │ 5 │ 2 │ 7 │ 4 ┃ P\n",
+            "┃ 1 │ 1 │ 7 ╏ 5 │ 0 │ 5 ╏ 2 │ 7 │ 4 ┃ P\n",
+        );
         assert_eq!(expected, text);
+        assert_eq!(2, text.matches('╏').count());
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: sum_rows_with_options
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_top_border_size_five_digits() {
+    fn test_long_sum_binary_product() {
         // Arrange
-        let multiplicand: String = String::from("345");
-        let multiplier: String = String::from("12");
+        let multiplicand: String = String::from("101");
+        let multiplier: String = String::from("11");
+        let options: TableOptions = TableOptions { radix: 2, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┏━━━━━━━━━━━━━━━━━━━┓\n";
+        let expected: &str = "┃ 0 │ 1 │ 1 │ 1 │ 1 ┃ P\n";
 
         // Action
-        top_border(&multiplicand, &multiplier, &mut text);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with(expected));
     }
 
     #[test]
-    fn test_top_border_size_twelve_digits() {
+    fn test_long_sum_binary_subtotals() {
         // Arrange
-        let multiplicand: String = String::from("123456");
-        let multiplier: String = String::from("123456");
+        let multiplicand: String = String::from("111");
+        let multiplier: String = String::from("111");
+        let options: TableOptions = TableOptions { radix: 2, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓\n";
+        let expected: &str = "┃ 1 │ 1 │ 0 │ 0 │ 0 │ 1 ┃ P\n";
 
         // Action
-        top_border(&multiplicand, &multiplier, &mut text);
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.contains("┃Sub 1."));
+        assert!(text.ends_with(expected));
+    }
+
+    #[test]
+    fn test_long_sum_hexadecimal() {
+        // Arrange
+        let multiplicand: String = String::from("FF");
+        let multiplier: String = String::from("2");
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃   │   │ E ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ F │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 1 │   │   ┃ 3 C\n\
+                              ┣━━━┷━━━┷━━━┫\n\
+                              ┃Pro.       ┃\n\
+                              ┣━━━┯━━━┯━━━┫\n\
+                              ┃ 1 │ F │ E ┃ P\n";
+
+        // Action
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    #[test]
+    fn test_long_sum_octal() {
+        // Arrange
+        let multiplicand: String = String::from("777");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions { radix: 8, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┃ 6 │ 7 │ 7 │ 1 ┃ P\n";
+
+        // Action
+        long_sum_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(!text.contains("┃Sub 1."));
+        assert!(text.ends_with(expected));
+    }
+
     // # -----------------------------------------------------------------------
-    // # Function: bottom_border
+    // # Function: product_with_options
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_bottom_border_size_two_digits() {
+    fn test_product_with_options_negative_times_positive() {
         // Arrange
-        let multiplicand: String = String::from("7");
-        let multiplier: String = String::from("3");
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("34");
+        let options: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┗━━━┷━━━┛\n";
+        let expected: &str = "┣━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.           ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ - │ 4 │ 0 │ 8 ┃ P\n";
 
         // Action
-        bottom_border(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_bottom_border_size_three_digits() {
+    fn test_product_with_options_negative_times_negative() {
         // Arrange
-        let multiplicand: String = String::from("8");
-        let multiplier: String = String::from("43");
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("34");
+        let options: TableOptions = TableOptions { signs: (true, true), ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┗━━━┷━━━┷━━━┛\n";
 
         // Action
-        bottom_border(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with("┃Pro.           ┃\n┣━━━┯━━━┯━━━┯━━━┫\n┃ 0 │ 4 │ 0 │ 8 ┃ P\n"));
     }
 
     #[test]
-    fn test_bottom_border_size_five_digits() {
+    fn test_product_with_options_signed_zero() {
         // Arrange
-        let multiplicand: String = String::from("519");
-        let multiplier: String = String::from("43");
+        let multiplicand: String = String::from("12");
+        let multiplier: String = String::from("0");
+        let options: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┗━━━┷━━━┷━━━┷━━━┷━━━┛\n";
 
         // Action
-        bottom_border(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with("┃Pro.       ┃\n┣━━━┯━━━┯━━━┫\n┃ 0 │ 0 │ 0 ┃ P\n"));
     }
 
     #[test]
-    fn test_bottom_border_size_twelve_digits() {
+    fn test_product_with_options_negative_widens_the_full_product() {
         // Arrange
-        let multiplicand: String = String::from("12");
-        let multiplier: String = String::from("1234567890");
+        let multiplicand: String = String::from("99");
+        let multiplier: String = String::from("99");
+        let options: TableOptions = TableOptions { signs: (false, true), ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n";
+        let expected: &str = "┣━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ - │ 9 │ 8 │ 0 │ 1 ┃ P\n";
 
         // Action
-        bottom_border(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
-    // # -----------------------------------------------------------------------
-    // # Function: position_title
-    // # -----------------------------------------------------------------------
     #[test]
-    fn test_position_title_size_two_digits() {
+    fn test_product_with_options_negative_aligned_to_the_left() {
         // Arrange
-        let multiplicand: String = String::from("6");
+        let multiplicand: String = String::from("12");
         let multiplier: String = String::from("3");
+        let options: TableOptions = TableOptions {
+            signs: (true, false),
+            product_align: ProductAlign::Left,
+            ..TableOptions::default()
+        };
         let mut text: String = String::from("");
-        let expected: &str = "┃Pos.   ┃\n\
-                              ┠┄┄┄┬┄┄┄┨\n\
-                              ┃ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┫\n";
 
         // Action
-        position_title(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with("┃ - │ 3 │ 6 ┃ P\n"));
     }
 
     #[test]
-    fn test_position_title_size_three_digits() {
+    fn test_product_with_options_decimal_one_and_a_half_times_two() {
         // Arrange
-        let multiplicand: String = String::from("18");
-        let multiplier: String = String::from("6");
+        let multiplicand: String = String::from("15");
+        let multiplier: String = String::from("2");
+        let options: TableOptions = TableOptions { decimals: (1, 0), ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┃Pos.       ┃\n\
-                              ┠┄┄┄┬┄┄┄┬┄┄┄┨\n\
-                              ┃ 3 │ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┷━━━┫\n";
 
         // Action
-        position_title(&multiplicand, &multiplier, &mut text);
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.ends_with("┃ 0 │ 3 . 0 ┃ P\n"));
+    }
+
+    #[test]
+    fn test_product_with_options_decimal_one_tenth_squared() {
+        // Arrange
+        let multiplicand: String = String::from("01");
+        let multiplier: String = String::from("01");
+        let options: TableOptions = TableOptions { decimals: (1, 1), ..TableOptions::default() };
+        let mut text: String = String::from("");
+
+        // Action
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.ends_with("┃ 0 │ 0 . 0 │ 1 ┃ P\n"));
+    }
+
+    #[test]
+    fn test_product_with_options_decimal_left_keeps_the_integer_digit() {
+        // Arrange
+        let multiplicand: String = String::from("01");
+        let multiplier: String = String::from("01");
+        let options: TableOptions = TableOptions {
+            decimals: (1, 1),
+            product_align: ProductAlign::Left,
+            ..TableOptions::default()
+        };
+        let mut text: String = String::from("");
+
+        // Action
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.ends_with("┃ 0 . 0 │ 1 │   ┃ P\n"));
+    }
+
+    #[test]
+    fn test_product_with_options_decimal_twelve_point_three_four_times_five() {
+        // Arrange
+        let multiplicand: String = String::from("1234");
+        let multiplier: String = String::from("5");
+        let options: TableOptions = TableOptions { decimals: (2, 0), ..TableOptions::default() };
+        let mut text: String = String::from("");
+
+        // Action
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+        // Assert
+        assert!(text.ends_with("┃ 0 │ 6 │ 1 . 7 │ 0 ┃ P\n"));
+    }
+
+    #[test]
+    fn test_product_with_options_pad_product_to() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let layout: LayoutConfig = LayoutConfig { pad_product_to: Some(5), ..LayoutConfig::default() };
+        let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+        let mut text: String = String::from("");
+        let expected: &str = "┣━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ 0 │ 0 │ 0 │ 3 │ 5 ┃ P\n";
+
+        // Action
+        product_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: product_row
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_position_title_size_five_digits() {
+    fn test_product_row_padded_with_zeros() {
         // Arrange
-        let multiplicand: String = String::from("78");
-        let multiplier: String = String::from("327");
+        let digits: Vec<usize> = vec![3, 3, 8];
         let mut text: String = String::from("");
-        let expected: &str = "┃Pos.               ┃\n\
-                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
-                              ┃ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+        let expected: &str = "┃ 0 │ 3 │ 3 │ 8 ┃ P\n";
 
         // Action
-        position_title(&multiplicand, &multiplier, &mut text);
+        product_row(&digits, 4, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_position_title_size_eleven_digits() {
+    fn test_product_row_without_padding() {
         // Arrange
-        let multiplicand: String = String::from("123456");
-        let multiplier: String = String::from("54321");
+        let digits: Vec<usize> = vec![1, 1, 7, 5, 0, 5, 2, 7, 4];
         let mut text: String = String::from("");
-        let expected: &str = "┃Pos.                                       ┃\n\
-                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
-                              ┃ 11│ 10│ 9 │ 8 │ 7 │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+        let expected: &str = "┃ 1 │ 1 │ 7 │ 5 │ 0 │ 5 │ 2 │ 7 │ 4 ┃ P\n";
 
         // Action
-        position_title(&multiplicand, &multiplier, &mut text);
+        product_row(&digits, 9, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: operation_title
+    // # Function: plain
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_operation_title_size_two_digits() {
+    fn test_plain_single_digit_multiplier() {
         // Arrange
-        let multiplicand: String = String::from("9");
-        let multiplier: String = String::from("1");
+        let multiplicand: String = String::from("25");
+        let multiplier: String = String::from("3");
         let mut text: String = String::from("");
-        let expected: &str = "┃Ops.   ┃\n\
-                              ┣━━━┯━━━┫\n";
+        let expected: &str = " 25\n\
+                              × 3\n\
+                              ---\n\
+                              \x2075\n";
 
         // Action
-        operation_title(&multiplicand, &multiplier, &mut text);
+        plain(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_operation_title_size_three_digits() {
+    fn test_plain_partial_products() {
         // Arrange
-        let multiplicand: String = String::from("53");
-        let multiplier: String = String::from("4");
+        let multiplicand: String = String::from("123");
+        let multiplier: String = String::from("45");
         let mut text: String = String::from("");
-        let expected: &str = "┃Ops.       ┃\n\
-                              ┣━━━┯━━━┯━━━┫\n";
+        let expected: &str = " 123\n\
+                              × 45\n\
+                              ----\n\
+                              \x20615\n\
+                              492\n\
+                              ----\n\
+                              5535\n";
 
         // Action
-        operation_title(&multiplicand, &multiplier, &mut text);
+        plain(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: karatsuba
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_operation_title_size_five_digits() {
+    fn test_karatsuba_four_by_four_digits() {
         // Arrange
-        let multiplicand: String = String::from("53");
-        let multiplier: String = String::from("618");
+        let multiplicand: String = String::from("9876");
+        let multiplier: String = String::from("5432");
         let mut text: String = String::from("");
-        let expected: &str = "┃Ops.               ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n";
 
         // Action
-        operation_title(&multiplicand, &multiplier, &mut text);
+        karatsuba(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.contains("a = 98, b = 76, c = 54, d = 32\n"));
+        assert!(text.contains("ac = 98 x 54 = 5292\n"));
+        assert!(text.contains("bd = 76 x 32 = 2432\n"));
+        assert!(text.contains("(a + b)(c + d) = 174 x 86 = 14964\n"));
+        assert!(text.contains("ad + bc = 14964 - 5292 - 2432 = 7240\n"));
+        assert!(text.ends_with(&format!("= {}\n", compute_product("9876", "5432"))));
     }
 
     #[test]
-    fn test_operation_title_size_eleven_digits() {
+    fn test_karatsuba_uneven_operands() {
         // Arrange
-        let multiplicand: String = String::from("654321");
-        let multiplier: String = String::from("12345");
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("123");
         let mut text: String = String::from("");
-        let expected: &str = "┃Ops.                                       ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n";
 
         // Action
-        operation_title(&multiplicand, &multiplier, &mut text);
+        karatsuba(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.starts_with("m = 1\na = 0, b = 7, c = 12, d = 3\n"));
+        assert!(text.ends_with("= 861\n"));
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: multiplication
+    // # Function: peasant
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_multiplication_size_two_digits() {
+    fn test_peasant_eighteen_by_twenty_five() {
         // Arrange
-        let multiplicand: String = String::from("8");
-        let multiplier: String = String::from("4");
+        let multiplicand: String = String::from("18");
+        let multiplier: String = String::from("25");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │ 8 ┃\n\
-                              ┃ x │ 4 ┃\n\
-                              ┣━━━┿━━━┫\n";
+        let expected: &str = "┏━━━━┯━━━━━┓\n\
+                              ┃ 18 │  25 ┃\n\
+                              ┃  9 │  50 ┃ +\n\
+                              ┃  4 │ 100 ┃\n\
+                              ┃  2 │ 200 ┃\n\
+                              ┃  1 │ 400 ┃ +\n\
+                              ┗━━━━┷━━━━━┛\n\
+                              50 + 400 = 450\n";
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        peasant(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_multiplication_size_three_digits() {
+    fn test_peasant_matches_the_product() {
         // Arrange
-        let multiplicand: String = String::from("2");
-        let multiplier: String = String::from("37");
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │ 2 ┃\n\
-                              ┃ x │ 3 │ 7 ┃\n\
-                              ┣━━━┿━━━┿━━━┫\n";
+        let expected: String = format!(" = {}\n", compute_product(&multiplicand, &multiplier));
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        peasant(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with(&expected));
     }
 
     #[test]
-    fn test_multiplication_size_five_digits() {
+    fn test_peasant_by_zero() {
         // Arrange
-        let multiplicand: String = String::from("81");
-        let multiplier: String = String::from("925");
+        let multiplicand: String = String::from("0");
+        let multiplier: String = String::from("7");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │ 8 │ 1 ┃\n\
-                              ┃ x │   │ 9 │ 2 │ 5 ┃\n\
-                              ┣━━━┿━━━┿━━━┿━━━┿━━━┫\n";
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        peasant(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.ends_with("┃ 0 │ 7 ┃\n┗━━━┷━━━┛\n0 = 0\n"));
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: lattice
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_multiplication_size_eleven_digits() {
+    fn test_lattice_two_by_two() {
         // Arrange
-        let multiplicand: String = String::from("12345");
-        let multiplier: String = String::from("654321");
+        let multiplicand: String = String::from("27");
+        let multiplier: String = String::from("34");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │   │   │ 1 │ 2 │ 3 │ 4 │ 5 ┃\n\
-                              ┃ x │   │   │   │   │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
-                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+        let expected: &str = "     2     7\n\
+                              \x20 ┏━━━━━┯━━━━━┓\n\
+                              \x20 ┃0   ╱│2   ╱┃\n\
+                              \x20 ┃  ╱  │  ╱  ┃ 3\n\
+                              0 ┃╱   6│╱   1┃\n\
+                              \x20 ┠─────┼─────┨\n\
+                              \x20 ┃0   ╱│2   ╱┃\n\
+                              \x20 ┃  ╱  │  ╱  ┃ 4\n\
+                              9 ┃╱   8│╱   8┃\n\
+                              \x20 ┗━━━━━┷━━━━━┛\n\
+                              \x20    1     8\n";
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        lattice(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_multiplication_multiplicand_bigger_than_a_multiplier() {
+    fn test_lattice_carry_to_the_edge() {
         // Arrange
-        let multiplicand: String = String::from("1234");
-        let multiplier: String = String::from("5");
+        let multiplicand: String = String::from("99");
+        let multiplier: String = String::from("9");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │ 1 │ 2 │ 3 │ 4 ┃\n\
-                              ┃ x │   │   │   │ 5 ┃\n\
-                              ┣━━━┿━━━┿━━━┿━━━┿━━━┫\n";
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        lattice(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.contains("8 ┃╱   1│╱   1┃\n"));
+        assert!(text.ends_with("     9     1\n"));
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: lattice_with_options
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_multiplication_multiplier_bigger_than_a_multiplicand() {
+    fn test_lattice_with_options_hexadecimal() {
         // Arrange
-        let multiplicand: String = String::from("8765");
-        let multiplier: String = String::from("1234");
+        let multiplicand: String = String::from("FF");
+        let multiplier: String = String::from("2");
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │ 8 │ 7 │ 6 │ 5 ┃\n\
-                              ┃ x │   │   │   │ 1 │ 2 │ 3 │ 4 ┃\n\
-                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n";
+        let expected: &str = "     F     F\n\
+                              \x20 ┏━━━━━┯━━━━━┓\n\
+                              \x20 ┃1   ╱│1   ╱┃\n\
+                              \x20 ┃  ╱  │  ╱  ┃ 2\n\
+                              1 ┃╱   E│╱   E┃\n\
+                              \x20 ┗━━━━━┷━━━━━┛\n\
+                              \x20    F     E\n";
 
         // Action
-        multiplication(&multiplicand, &multiplier, &mut text);
+        lattice_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: operations
+    // # Function: author
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_operations_with_three_digits_multiplicand_is_greater() {
+    fn test_author_information() {
         // Arrange
-        let multiplicand: String = String::from("25");
-        let multiplier: String = String::from("3");
         let mut text: String = String::from("");
-        let expected: &str = "┃ 0 │ 1 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 6 │ 5 ┃ 1 R\n\
-                              ┣━━━┷━━━┷━━━┫\n";
+        let expected: &str = "\n\
+                              ---\n\
+                              Author: Israel Roldan\n\
+                              E-mail: israel.alberto.rv@gmail.com\n\
+                              License: GPL-3.0\n\
+                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        author(&mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: stats
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_operations_with_three_digits_multiplicand_is_less() {
+    fn test_stats_with_nine_digits() {
         // Arrange
-        let multiplicand: String = String::from("3");
-        let multiplier: String = String::from("25");
-        let mut text: String = String::from("");
-        let expected: &str = "┃   │ 1 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 5 ┃ 1 R\n\
-                              ┠───┼───┼───┨\n\
-                              ┃ 0 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 6 │   ┃ 2 R\n\
-                              ┣━━━┷━━━┷━━━┫\n";
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
+        let mut text: String = String::from("Table");
+        let expected: &str = "Table\n\
+                              ---\n\
+                              Multiplicand digits: 5\n\
+                              Multiplier digits: 4\n\
+                              Product digits: 9\n\
+                              Sub rounds: 1\n\
+                              Table bytes: 103\n";
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        stats(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_operations_with_four_digit() {
+    fn test_stats_without_sub_rounds() {
         // Arrange
-        let multiplicand: String = String::from("13");
-        let multiplier: String = String::from("26");
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("2");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │ 0 │ 1 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 6 │ 8 ┃ 1 R\n\
-                              ┠───┼───┼───┼───┨\n\
-                              ┃ 0 │ 0 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 2 │ 6 │   ┃ 2 R\n\
-                              ┣━━━┷━━━┷━━━┷━━━┫\n";
+        let expected: &str = "\n\
+                              ---\n\
+                              Multiplicand digits: 1\n\
+                              Multiplier digits: 1\n\
+                              Product digits: 1\n\
+                              Sub rounds: 0\n\
+                              Table bytes: 97\n";
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        stats(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
+        assert_eq!(97, text.len());
     }
 
     #[test]
-    fn test_operations_with_eleven_digits_multiplicand_is_greater() {
+    fn test_stats_with_leading_zeros() {
         // Arrange
-        let multiplicand: String = String::from("246802468");
-        let multiplier: String = String::from("357");
+        let multiplicand: String = String::from("007");
+        let multiplier: String = String::from("26");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │ 1 │ 2 │ 4 │ 5 │ 0 │ 1 │ 2 │ 4 │ 5 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 4 │ 8 │ 2 │ 6 │ 0 │ 4 │ 8 │ 2 │ 6 ┃ 1 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │ 1 │ 2 │ 3 │ 4 │ 0 │ 1 │ 2 │ 3 │ 4 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │ 0 │   ┃ 2 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃ 0 │ 1 │ 1 │ 2 │ 0 │ 0 │ 1 │ 1 │ 2 │   │   │   ┃ 3 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 6 │ 2 │ 8 │ 4 │ 0 │ 6 │ 2 │ 8 │ 4 │   │   ┃ 3 R\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        stats(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert!(text.contains("Multiplicand digits: 1\nMultiplier digits: 2\nProduct digits: 3\n"));
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: colorize
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_operations_with_eleven_digits_multiplicand_is_less() {
+    fn test_colorize_carry_row() {
         // Arrange
-        let multiplicand: String = String::from("357");
-        let multiplier: String = String::from("246802468");
-        let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │   │   │   │   │ 2 │ 4 │ 5 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │ 4 │ 0 │ 6 ┃ 1 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │ 3 │ 4 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │ 8 │ 0 │ 2 │   ┃ 2 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │ 1 │ 2 │ 2 │   │   │   ┃ 3 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 2 │ 0 │ 8 │   │   ┃ 3 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │ 0 │ 1 │ 1 │   │   │   │   ┃ 4 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 6 │ 0 │ 4 │   │   │   ┃ 4 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │ 0 │ 0 │ 0 │   │   │   │   │   ┃ 5 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 0 │ 0 │ 0 │   │   │   │   ┃ 5 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │ 2 │ 4 │ 5 │   │   │   │   │   │   ┃ 6 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 4 │ 0 │ 6 │   │   │   │   │   ┃ 6 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │ 1 │ 3 │ 4 │   │   │   │   │   │   │   ┃ 7 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 8 │ 0 │ 2 │   │   │   │   │   │   ┃ 7 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │ 1 │ 2 │ 2 │   │   │   │   │   │   │   │   ┃ 8 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 2 │ 0 │ 8 │   │   │   │   │   │   │   ┃ 8 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃ 0 │ 1 │ 1 │   │   │   │   │   │   │   │   │   ┃ 9 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 6 │ 0 │ 4 │   │   │   │   │   │   │   │   ┃ 9 R\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+        let text: &str = "┃ 1 │   ┃ 1 ^\n";
+        let expected: &str = "\x1b[2m┃\x1b[0m \x1b[1;33m1\x1b[0m \x1b[2m│\x1b[0m   \x1b[2m┃\x1b[0m 1 ^\n";
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        let colored: String = colorize(text, &TableStyle::unicode(), &ColorScheme::default());
 
         // Assert
-        assert_eq!(expected, text);
+        assert_eq!(expected, colored);
     }
 
     #[test]
-    fn test_operations_with_thirteen_rows() {
+    fn test_colorize_keeps_the_other_lines() {
         // Arrange
-        let multiplicand: String = String::from("7");
-        let multiplier: String = String::from("9876543210123");
-        let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │   │ 2 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │   │   │ 1 ┃ 1 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │ 1 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │   │ 4 │   ┃ 2 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 0 │   │   │   ┃ 3 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │ 7 │   │   ┃ 3 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   │   │   ┃ 4 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 0 │   │   │   ┃ 4 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │   │ 0 │   │   │   │   │   ┃ 5 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │   │   │   │   │   │   ┃ 6 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │   │ 2 │   │   │   │   │   │   │   ┃ 7 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │   │   │   │   │   │   ┃ 7 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │   │ 2 │   │   │   │   │   │   │   │   ┃ 8 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 8 │   │   │   │   │   │   │   ┃ 8 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │   │ 3 │   │   │   │   │   │   │   │   │   ┃ 9 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 5 │   │   │   │   │   │   │   │   ┃ 9 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │   │ 4 │   │   │   │   │   │   │   │   │   │   ┃ 10 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 2 │   │   │   │   │   │   │   │   │   ┃ 10 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │ 4 │   │   │   │   │   │   │   │   │   │   │   ┃ 11 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 9 │   │   │   │   │   │   │   │   │   │   ┃ 11 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │ 5 │   │   │   │   │   │   │   │   │   │   │   │   ┃ 12 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃ 6 │   │   │   │   │   │   │   │   │   │   │   │   │   ┃ 13 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 3 │   │   │   │   │   │   │   │   │   │   │   │   ┃ 13 R\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n";
+        let text: &str = "Symbols\n=======\n┃Pos.   ┃\n---\n";
+        let expected: &str = "Symbols\n=======\n\x1b[2m┃\x1b[0mPos.   \x1b[2m┃\x1b[0m\n---\n";
+
+        // Action
+        let colored: String = colorize(text, &TableStyle::unicode(), &ColorScheme::default());
+
+        // Assert
+        assert_eq!(expected, colored);
+        assert_eq!(text, strip_ansi(&colored));
+    }
+
+    #[test]
+    fn test_colorize_highlight_column() {
+        // Arrange
+        let text: &str = "┃Pos.       ┃\n┃ 3 │ 2 │ 1 ┃\n┣━━━┿━━━┿━━━┫\n┃ 0 │ 7 │ 5 ┃ P\n";
+        let scheme: ColorScheme = ColorScheme { highlight_column: Some(2), ..ColorScheme::default() };
 
         // Action
-        operations(&multiplicand, &multiplier, &mut text);
+        let colored: String = colorize(text, &TableStyle::unicode(), &scheme);
 
         // Assert
-        assert_eq!(expected, text);
+        let highlighted: Vec<&str> = colored.split("\x1b[7m").skip(1).map(|cell| &cell[..cell.find('\x1b').unwrap()]).collect();
+        assert_eq!(vec![" 2 ", " 7 "], highlighted);
+        assert_eq!(text, strip_ansi(&colored));
+    }
+
+    #[test]
+    fn test_colorize_highlight_column_ascii() {
+        // Arrange
+        let text: &str = "|Pos.       |\n| 3 | 2 | 1 |\n+---+---+---+\n| 0 | 7 | 5 | P\n";
+        let scheme: ColorScheme = ColorScheme { highlight_column: Some(3), ..ColorScheme::default() };
+
+        // Action
+        let colored: String = colorize(text, &TableStyle::ascii(), &scheme);
+
+        // Assert
+        let highlighted: Vec<&str> = colored.split("\x1b[7m").skip(1).map(|cell| &cell[..cell.find('\x1b').unwrap()]).collect();
+        assert_eq!(vec![" 3 ", " 0 "], highlighted);
+        assert_eq!(text, strip_ansi(&colored));
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: sum_title
+    // # Function: localize_digits
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_sum_title_size_two_digits() {
+    fn test_localize_digits_keeps_the_annotations() {
         // Arrange
-        let multiplicand: String = String::from("4");
-        let multiplier: String = String::from("2");
-        let mut text: String = String::from("");
-        let expected: &str = "┃Sum.   ┃\n\
-                              ┣━━━┯━━━┫\n";
+        let text: &str = "┃Sub 1. ┃\n┃ 1 │ 0 ┃ 1 ^\n";
+        let expected: &str = "┃Sub 1. ┃\n┃ ١ │ ٠ ┃ 1 ^\n";
 
         // Action
-        sum_title(&multiplicand, &multiplier, &mut text);
+        let localized: String = localize_digits(text, &TableStyle::unicode(), DigitSet::ArabicIndic);
 
         // Assert
-        assert_eq!(expected, text);
+        assert_eq!(expected, localized);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: strip_ansi
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_sum_title_size_three_digits() {
+    fn test_strip_ansi_colors() {
         // Arrange
-        let multiplicand: String = String::from("19");
-        let multiplier: String = String::from("5");
-        let mut text: String = String::from("");
-        let expected: &str = "┃Sum.       ┃\n\
-                              ┣━━━┯━━━┯━━━┫\n";
+        let text: &str = "\x1b[2m┃\x1b[0m \x1b[1;33m1\x1b[0m │   ┃ 1 ^\n";
+        let expected: &str = "┃ 1 │   ┃ 1 ^\n";
 
         // Action
-        sum_title(&multiplicand, &multiplier, &mut text);
+        let plain: String = strip_ansi(text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert_eq!(expected, plain);
     }
 
     #[test]
-    fn test_sum_title_size_five_digits() {
+    fn test_strip_ansi_plain_text() {
         // Arrange
-        let multiplicand: String = String::from("73");
-        let multiplier: String = String::from("438");
-        let mut text: String = String::from("");
-        let expected: &str = "┃Sum.               ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┫\n";
+        let text: &str = "┃ 0 │ 3 │ 3 │ 8 ┃ P\n";
 
         // Action
-        sum_title(&multiplicand, &multiplier, &mut text);
+        let plain: String = strip_ansi(text);
 
         // Assert
-        assert_eq!(expected, text);
+        assert_eq!(text, plain);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: row_labels
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_sum_title_size_eleven_digits() {
+    fn test_row_labels_with_two_digits() {
         // Arrange
-        let multiplicand: String = String::from("123456");
-        let multiplier: String = String::from("54321");
+        let expected: Vec<&str> = vec!["1 ^", "1 R", "1 C", "2 C", "P"];
+
+        // Action
+        let labels: Vec<String> = row_labels("9", "3");
+
+        // Assert
+        assert_eq!(expected, labels);
+    }
+
+    #[test]
+    fn test_row_labels_with_subtotal() {
+        // Arrange
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
         let mut text: String = String::from("");
-        let expected: &str = "┃Sum.                                       ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n";
 
         // Action
-        sum_title(&multiplicand, &multiplier, &mut text);
+        let labels: Vec<String> = row_labels(&multiplicand, &multiplier);
+        operations(&multiplicand, &multiplier, &mut text);
+        long_sum(&multiplicand, &multiplier, &mut text);
 
         // Assert
-        assert_eq!(expected, text);
+        let expected: Vec<&str> = text
+            .lines()
+            .filter_map(|line| line.rsplit_once("┃ ").map(|(_, label)| label))
+            .filter(|label| !label.is_empty())
+            .collect();
+        assert_eq!(27, labels.len());
+        assert_eq!(expected, labels);
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: long_sum
+    // # Function: product_significant
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_long_sum_with_one_digit() {
+    fn test_product_significant_with_ellipsis() {
         // Arrange
-        let multiplicand: String = String::from("3");
-        let multiplier: String = String::from("2");
+        let digits: Vec<usize> = vec![1, 1, 7, 5, 0, 5, 2, 7, 4];
         let mut text: String = String::from("");
-        let expected: &str = "┃   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   ┃ 2 C\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Pro.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃ 0 │ 6 ┃ P\n";
+        let expected: &str = "117…(9 digits)\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        product_significant(&digits, 3, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     #[test]
-    fn test_long_sum_with_two_digits() {
+    fn test_product_significant_without_leading_zeros() {
         // Arrange
-        let multiplicand: String = String::from("9");
-        let multiplier: String = String::from("9");
+        let digits: Vec<usize> = vec![0, 3, 5];
         let mut text: String = String::from("");
-        let expected: &str = "┃   │ 1 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃ 8 │   ┃ 2 C\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Pro.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃ 8 │ 1 ┃ P\n";
+        let expected: &str = "3…(2 digits)\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        product_significant(&digits, 1, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: verification
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_long_sum_with_three_digits() {
+    fn test_verification_with_nine_digits() {
         // Arrange
-        let multiplicand: String = String::from("37");
-        let multiplier: String = String::from("5");
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │ 5 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 1 │   │   ┃ 3 C\n\
-                              ┣━━━┷━━━┷━━━┫\n\
-                              ┃Pro.       ┃\n\
-                              ┣━━━┯━━━┯━━━┫\n\
-                              ┃ 1 │ 8 │ 5 ┃ P\n";
+        let expected: &str = "Verify: 117505274 ✓\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        verification(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: explanation
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_long_sum_with_four_digit() {
+    fn test_explanation_seventy_nine_times_three() {
         // Arrange
-        let multiplicand: String = String::from("13");
-        let multiplier: String = String::from("26");
+        let multiplicand: String = String::from("79");
+        let multiplier: String = String::from("3");
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │ 8 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 1 │ 3 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 2 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   ┃ 4 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Pro.           ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃ 0 │ 3 │ 3 │ 8 ┃ P\n";
+        let expected: &str = "row 1: 9×3=27 (unit 7, carry 2)\n\
+                              row 1: 7×3=21 (unit 1, carry 2)\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        explanation(&multiplicand, &multiplier, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: explanation_with_options
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_long_sum_with_eleven_digits_multiplicand_is_greater() {
+    fn test_explanation_with_options_hexadecimal() {
         // Arrange
-        let multiplicand: String = String::from("246802468");
-        let multiplier: String = String::from("357");
+        let multiplicand: String = String::from("FF");
+        let multiplier: String = String::from("2");
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
         let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │ 2 │ 0 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │ 9 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 6 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 1 │ 4 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 2 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 1 │ 9 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Sub 1.                                         ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │ 1 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 8 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 1 │ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 7 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Pro.                                           ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃ 0 │ 8 │ 8 │ 1 │ 0 │ 8 │ 4 │ 8 │ 1 │ 0 │ 7 │ 6 ┃ P\n";
+        let expected: &str = "row 1: F×2=1E (unit E, carry 1)\n\
+                              row 1: F×2=1E (unit E, carry 1)\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        explanation_with_options(&multiplicand, &multiplier, &options, &mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: dedupe_borders
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_long_sum_with_eleven_digits_multiplicand_is_less() {
+    fn test_dedupe_borders_without_sum_section() {
         // Arrange
-        let multiplicand: String = String::from("357");
-        let multiplier: String = String::from("246802468");
-        let mut text: String = String::from("");
-        let expected: &str = "┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │ 2 │ 0 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │ 9 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 6 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 1 │ 4 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 2 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 1 │ 9 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 6 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Sub 1.                                         ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │   │   │ 0 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 1 │ 1 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 7 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 8 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 1 │ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 7 │   │   │   │   │   │   │   │   │   ┃ 10 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │   │   │   │   │   │   │   │   │   │   ┃ 11 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   │   │   │   │   │   │   │   │   ┃ 12 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Pro.                                           ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃ 0 │ 8 │ 8 │ 1 │ 0 │ 8 │ 4 │ 8 │ 1 │ 0 │ 7 │ 6 ┃ P\n";
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let options: TableOptions = TableOptions { sum_section: false, ..TableOptions::default() };
+        let format: crate::format::Text = crate::format::Text { options };
+        let mut text: String = crate::multiplication::get_table_as(&multiplicand, &multiplier, &format);
+        let expected: &str = "┃   │ 2 │ 6 │   ┃ 2 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.           ┃\n";
 
         // Action
-        long_sum(&multiplicand, &multiplier, &mut text);
+        dedupe_borders(&mut text);
+
+        // Assert
+        assert!(text.contains(expected));
+        assert!(!text.contains("┣━━━┷━━━┷━━━┷━━━┫\n┣━━━┷━━━┷━━━┷━━━┫\n"));
+    }
+
+    #[test]
+    fn test_dedupe_borders_keeps_different_lines() {
+        // Arrange
+        let mut text: String = String::from("┣━━━┿━━━┫\n┣━━━┷━━━┫\n┃   │ 1 ┃ 1 C\n┃   │ 1 ┃ 1 C\n");
+        let expected: String = text.clone();
+
+        // Action
+        dedupe_borders(&mut text);
 
         // Assert
         assert_eq!(expected, text);
     }
 
     // # -----------------------------------------------------------------------
-    // # Function: author
+    // # Function: clip_lines
     // # -----------------------------------------------------------------------
     #[test]
-    fn test_author_information() {
+    fn test_clip_lines_keeps_the_shorter_lines() {
         // Arrange
-        let mut text: String = String::from("");
-        let expected: &str = "\n\
-                              ---\n\
-                              Author: Israel Roldan\n\
-                              E-mail: israel.alberto.rv@gmail.com\n\
-                              License: GPL-3.0\n\
-                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+        let text: &str = "Symbols\n=======\n┃ 3 │ 5 ┃ P\nlast";
+        let expected: &str = "Symbol\n======\n┃ 3 │ \nlast";
 
         // Action
-        author(&mut text);
+        let clipped: String = clip_lines(text, 6);
 
         // Assert
-        assert_eq!(expected, text);
+        assert_eq!(expected, clipped);
     }
 }
@@ -7,6 +7,11 @@
 /// - If the number is a hundred, it will return the value of three.
 /// - So, successively, for the other numbers.
 ///
+/// It is the numeric length, the digits needed to write the
+/// number without leading zeros. The zero is written with one
+/// digit, so it returns one, like any other unit. For the
+/// written length of a digit string, see `get_string_length`.
+///
 /// Examples
 /// --------
 ///
@@ -33,6 +38,18 @@
 ///
 /// assert_eq!(expected, length);
 /// ```
+///
+/// Example #3
+/// ```rust
+/// let number: usize = 0;
+/// let length: usize;
+/// let expected: usize = 1;
+///
+/// use long_multiplication_command_line::length::get_number_length;
+/// length = get_number_length(number);
+///
+/// assert_eq!(expected, length);
+/// ```
 pub fn get_number_length(number: usize) -> usize {
     return (number.checked_ilog10().unwrap_or(0) + 1) as usize;
 }
@@ -42,6 +59,11 @@ pub fn get_number_length(number: usize) -> usize {
 /// Given a string, this function returns the length in digits
 /// of that string.
 ///
+/// It is the written length, every digit is counted as it is
+/// written, including the leading zeros, so `"007"` has three
+/// digits while `get_number_length(7)` returns one. The sign
+/// and the separators are not counted, see `digit_count`.
+///
 /// Examples
 /// --------
 ///
@@ -68,8 +90,44 @@ pub fn get_number_length(number: usize) -> usize {
 ///
 /// assert_eq!(expected, length);
 /// ```
+///
+/// Example #3
+/// ```rust
+/// let number: String = String::from("007");
+/// let length: usize;
+/// let expected: usize = 3;
+///
+/// use long_multiplication_command_line::length::get_string_length;
+/// length = get_string_length(&number);
+///
+/// assert_eq!(expected, length);
+/// ```
 pub fn get_string_length(number: &String) -> usize {
-    return number.len();
+    return digit_count(number);
+}
+
+/// Get the number of digits of a string.
+///
+/// Given a string, this function counts only the digits of
+/// the bases up to sixteen, `0` to `9` and `A` to `F`, ignoring
+/// the sign and the separators (`-`, `_`, `,` and `.`).
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let number: &str = "-1_234";
+/// let length: usize;
+/// let expected: usize = 4;
+///
+/// use long_multiplication_command_line::length::digit_count;
+/// length = digit_count(number);
+///
+/// assert_eq!(expected, length);
+/// ```
+pub fn digit_count(number: &str) -> usize {
+    return number.chars().filter(|c| c.is_ascii_hexdigit()).count();
 }
 
 /// Get the length (digits) of two joined numbers.
@@ -156,6 +214,115 @@ pub fn get_strings_length(number_a: &String, number_b: &String) -> usize {
     return number_a_len + number_b_len;
 }
 
+/// Get the number of partial-product rows of the multiplication.
+///
+/// Given the multiplier, this function returns the number of
+/// rows (`n R`) in the operations of the long multiplication.
+/// The number of rows is the length of the multiplier,
+/// regardless of the zeros in it.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplier: &str = "8642";
+/// let rows: usize;
+/// let expected: usize = 4;
+///
+/// use long_multiplication_command_line::length::partial_row_count;
+/// rows = partial_row_count(multiplier);
+///
+/// assert_eq!(expected, rows);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let multiplier: &str = "1005";
+/// let rows: usize;
+/// let expected: usize = 4;
+///
+/// use long_multiplication_command_line::length::partial_row_count;
+/// rows = partial_row_count(multiplier);
+///
+/// assert_eq!(expected, rows);
+/// ```
+pub fn partial_row_count(multiplier: &str) -> usize {
+    return digit_count(multiplier);
+}
+
+/// Get the name of the place value of a column.
+///
+/// Given the number of a column, counted from the right and
+/// starting at one like the `Pos.` header, this function returns
+/// the name of its place value: ones, tens, hundreds, thousands,
+/// ten thousands, ... up to hundred decillions. Beyond that, it
+/// returns the power of ten, e.g. `10^36`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::length::place_value_name;
+///
+/// assert_eq!("ones", place_value_name(1));
+/// assert_eq!("hundreds", place_value_name(3));
+/// assert_eq!("ten billions", place_value_name(11));
+/// ```
+pub fn place_value_name(col: usize) -> String {
+    let groups: [&str; 12] = [
+        "ones", "thousands", "millions", "billions", "trillions", "quadrillions",
+        "quintillions", "sextillions", "septillions", "octillions", "nonillions", "decillions",
+    ];
+    let exponent: usize = col.saturating_sub(1);
+
+    if exponent == 1 {
+        return String::from("tens");
+    }
+    if exponent == 2 {
+        return String::from("hundreds");
+    }
+    if exponent / 3 >= groups.len() {
+        return format!("10^{exponent}");
+    }
+
+    let prefix: &str = match exponent % 3 {
+        1 => "ten ",
+        2 => "hundred ",
+        _ => "",
+    };
+    return format!("{prefix}{}", groups[exponent / 3]);
+}
+
+/// Get the rendered width (characters) of the table.
+///
+/// Given two numbers, this function returns the width in
+/// characters of the table borders of the long multiplication.
+/// Each column takes three characters plus one separator,
+/// and the table has one more character for the last border.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let number_a: String = String::from("5");
+/// let number_b: String = String::from("7");
+/// let width: usize;
+/// let expected: usize = 9;
+///
+/// use long_multiplication_command_line::length::rendered_width;
+/// width = rendered_width(&number_a, &number_b);
+///
+/// assert_eq!(expected, width);
+/// ```
+pub fn rendered_width(number_a: &String, number_b: &String) -> usize {
+    let length: usize = get_strings_length(number_a, number_b);
+
+    return (length * 4) + 1;
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -234,6 +401,48 @@ mod tests {
         assert_eq!(expected, length);
     }
 
+    #[test]
+    fn test_get_number_length_for_zero() {
+        // Arrange
+        let number: usize = 0;
+        let length: usize;
+        let expected: usize = 1;
+
+        // Action
+        length = get_number_length(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_get_number_length_for_power_of_ten() {
+        // Arrange
+        let number: usize = 1000;
+        let length: usize;
+        let expected: usize = 4;
+
+        // Action
+        length = get_number_length(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_get_number_length_for_max() {
+        // Arrange
+        let number: usize = usize::MAX;
+        let length: usize;
+        let expected: usize = 20;
+
+        // Action
+        length = get_number_length(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
     // # -----------------------------------------------------------------------
     // # Function: get_string_length
     // # -----------------------------------------------------------------------
@@ -307,6 +516,107 @@ mod tests {
         assert_eq!(expected, length);
     }
 
+    #[test]
+    fn test_get_string_length_with_leading_zeros() {
+        // Arrange
+        let number: String = String::from("007");
+        let length: usize;
+        let expected: usize = 3;
+
+        // Action
+        length = get_string_length(&number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_get_string_length_for_zero() {
+        // Arrange
+        let number: String = String::from("0");
+        let length: usize;
+        let expected: usize = 1;
+
+        // Action
+        length = get_string_length(&number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_get_string_length_with_sign() {
+        // Arrange
+        let number: String = String::from("-12");
+        let length: usize;
+        let expected: usize = 2;
+
+        // Action
+        length = get_string_length(&number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: digit_count
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_digit_count_with_sign_and_separator() {
+        // Arrange
+        let number: &str = "-1_234";
+        let length: usize;
+        let expected: usize = 4;
+
+        // Action
+        length = digit_count(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_digit_count_with_decimal_point() {
+        // Arrange
+        let number: &str = "12.5";
+        let length: usize;
+        let expected: usize = 3;
+
+        // Action
+        length = digit_count(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_digit_count_hexadecimal() {
+        // Arrange
+        let number: &str = "1FF";
+        let length: usize;
+        let expected: usize = 3;
+
+        // Action
+        length = digit_count(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
+    #[test]
+    fn test_digit_count_with_thousands_separator() {
+        // Arrange
+        let number: &str = "1,000,000";
+        let length: usize;
+        let expected: usize = 7;
+
+        // Action
+        length = digit_count(number);
+
+        // Assert
+        assert_eq!(expected, length);
+    }
+
     // # -----------------------------------------------------------------------
     // # Function: get_numbers_length
     // # -----------------------------------------------------------------------
@@ -432,4 +742,123 @@ mod tests {
         // Assert
         assert_eq!(expected, length);
     }
+
+    // # -----------------------------------------------------------------------
+    // # Function: partial_row_count
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_partial_row_count_for_four_digits() {
+        // Arrange
+        let multiplier: &str = "8642";
+        let rows: usize;
+        let expected: usize = 4;
+
+        // Action
+        rows = partial_row_count(multiplier);
+
+        // Assert
+        assert_eq!(expected, rows);
+    }
+
+    #[test]
+    fn test_partial_row_count_for_nine_digits() {
+        // Arrange
+        let multiplier: &str = "246802468";
+        let rows: usize;
+        let expected: usize = 9;
+
+        // Action
+        rows = partial_row_count(multiplier);
+
+        // Assert
+        assert_eq!(expected, rows);
+    }
+
+    #[test]
+    fn test_partial_row_count_with_zeros() {
+        // Arrange
+        let multiplier: &str = "1000";
+        let rows: usize;
+        let expected: usize = 4;
+
+        // Action
+        rows = partial_row_count(multiplier);
+
+        // Assert
+        assert_eq!(expected, rows);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: place_value_name
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_place_value_name_first_columns() {
+        // Arrange
+        let expected: Vec<&str> = vec!["ones", "tens", "hundreds", "thousands", "ten thousands", "hundred thousands"];
+
+        // Action
+        let names: Vec<String> = (1..7).map(place_value_name).collect();
+
+        // Assert
+        assert_eq!(expected, names);
+    }
+
+    #[test]
+    fn test_place_value_name_billions() {
+        // Arrange
+        let col: usize = 10;
+        let expected: &str = "billions";
+
+        // Action
+        let name: String = place_value_name(col);
+
+        // Assert
+        assert_eq!(expected, name);
+    }
+
+    #[test]
+    fn test_place_value_name_beyond_decillions() {
+        // Arrange
+        let col: usize = 37;
+        let expected: &str = "10^36";
+
+        // Action
+        let name: String = place_value_name(col);
+
+        // Assert
+        assert_eq!(expected, name);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: rendered_width
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_rendered_width_for_two_digits() {
+        // Arrange
+        let number_a: String = String::from("5");
+        let number_b: String = String::from("7");
+        let width: usize;
+        let expected: usize = 9;
+
+        // Action
+        width = rendered_width(&number_a, &number_b);
+
+        // Assert
+        assert_eq!(expected, width);
+    }
+
+    #[test]
+    fn test_rendered_width_for_nine_digits() {
+        // Arrange
+        let number_a: String = String::from("13597");
+        let number_b: String = String::from("8642");
+        let width: usize;
+        let expected: usize = 37;
+
+        // Action
+        width = rendered_width(&number_a, &number_b);
+
+        // Assert
+        assert_eq!(expected, width);
+    }
 }
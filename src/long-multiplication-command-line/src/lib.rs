@@ -1,5 +1,35 @@
+//! Create a table with the long-multiplication method given two coefficients.
+//!
+//! The table, the product and the breakdown of the columns are
+//! available without the command-line parsing of `arguments`,
+//! which needs `clap` and is only compiled with the `cli` feature.
+//!
+//! Examples
+//! --------
+//!
+//! Example #1
+//! ```rust
+//! use long_multiplication_command_line::{Breakdown, break_down_multiplication, compute_product, get_table};
+//! let table: String = get_table("13", "26").unwrap();
+//! let breakdown: Breakdown = break_down_multiplication(&String::from("13"), &String::from("26"));
+//!
+//! assert!(table.contains("┃ 0 │ 3 │ 3 │ 8 ┃ P"));
+//! assert_eq!("338", compute_product("13", "26"));
+//! assert_eq!(&[0, 1, 0, 0], breakdown.carries());
+//! ```
+#[cfg(feature = "cli")]
 pub mod arguments;
 pub mod breakdown;
+pub mod format;
 pub mod generate;
 pub mod length;
+pub mod model;
 pub mod multiplication;
+pub mod options;
+pub mod random;
+pub mod words;
+
+pub use breakdown::{Breakdown, break_down_addition, break_down_multiplication, break_down_subtotal, compute_product, fully_reduce, partial_products};
+pub use model::{Cell, CellKind, TableModel};
+pub use multiplication::{MultiplicationError, build_model, get_table, get_table_as, get_table_with_options, validate, write_table};
+pub use options::{Footer, TableOptions, TableStyle};
@@ -1,20 +1,146 @@
-use long_multiplication_command_line::arguments::{Args, get_args};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use long_multiplication_command_line::arguments::{Args, SkippedLine, dry_run_summary, get_args, get_table_options, read_batch_with_max_digits};
+use long_multiplication_command_line::breakdown::{compute_product, compute_product_with_options, normalize_operand};
+use long_multiplication_command_line::length::get_string_length;
 use long_multiplication_command_line::multiplication;
+use long_multiplication_command_line::random::random_pairs;
+use long_multiplication_command_line::words::number_to_words;
+use long_multiplication_command_line::options::{LineEnding, Literal, StoreOptions, TableOptions, WriteMode};
 
 fn main() {
     // TODO: #1 - Do I need to convert mutable variables to shadowing variables?
     let args: Args = get_args();
-    let multiplicand: String = args.multiplicand;
-    let multiplier: String = args.multiplier;
-    let output: String = args.output;
-    let content: String = multiplication::get_table(&multiplicand, &multiplier);
+    let options: TableOptions = match get_table_options(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    };
+    let multiplicand: String = args.multiplicand.clone();
+    let multiplier: String = args.multiplier.clone();
+    let output: String = args.output.clone();
+
+    if args.dry_run {
+        display(&format!("{}\n", dry_run_summary(&args)));
+        return;
+    }
+
+    if output == "pyliteral" {
+        let mut literal: Literal = Literal::Python;
+        if args.js {
+            literal = Literal::JavaScript;
+        }
+        let content: String = multiplication::get_table_literal_with_options(&multiplicand, &multiplier, literal, &options);
+        display(&format!("{content}\n"));
+        return;
+    }
+
+    let mut content: String = match (args.times_table, args.random) {
+        (Some(up_to), _) if args.times_grids => multiplication::times_table_with_grids(&multiplicand, up_to, &options),
+        (Some(up_to), _) => multiplication::times_table_with_options(&multiplicand, up_to, &options),
+        (None, Some((multiplicand_digits, multiplier_digits))) => {
+            let seed: u64 = args.seed.unwrap_or_else(|| {
+                SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs())
+            });
+            let pairs: Vec<(String, String)> = random_pairs(multiplicand_digits, multiplier_digits, args.count, seed);
+            multiplication::get_practice_sheet(&pairs, &options)
+        }
+        (None, None) if args.batch.is_some() => batch(args.batch.as_deref().unwrap_or_default(), args.max_digits, &options),
+        (None, None) if !args.operands.is_empty() => {
+            let mut operands: Vec<String> = vec![multiplicand.clone(), multiplier.clone()];
+            operands.extend(args.operands.iter().cloned());
+            if args.quiet {
+                operands.iter().skip(1).fold(multiplicand.clone(), |product, operand| {
+                    normalize_operand(&compute_product(&product, operand))
+                })
+            } else {
+                multiplication::get_table_chain_with_options(&operands, &options)
+            }
+        }
+        (None, None) if args.quiet => compute_product_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if get_string_length(&multiplicand) > args.auto_answer_threshold
+            || get_string_length(&multiplier) > args.auto_answer_threshold => {
+            eprintln!(
+                "NOTE: an operand has more than {} digits, so only the product is displayed.",
+                args.auto_answer_threshold
+            );
+            compute_product_with_options(&multiplicand, &multiplier, &options)
+        }
+        (None, None) if args.method == "lattice" => multiplication::get_table_lattice_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.method == "peasant" => multiplication::get_table_peasant(&multiplicand, &multiplier),
+        (None, None) if args.method == "plain" => multiplication::get_table_plain(&multiplicand, &multiplier),
+        (None, None) if args.method == "karatsuba" => multiplication::get_table_karatsuba(&multiplicand, &multiplier),
+        (None, None) if args.format == "html" => multiplication::get_table_html_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.format == "json" => multiplication::get_table_json_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.format == "latex" => multiplication::get_table_latex_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.format == "markdown" => multiplication::get_table_markdown_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.format == "svg" => multiplication::get_table_svg_with_options(&multiplicand, &multiplier, &options),
+        (None, None) if args.format == "tsv" => multiplication::get_table_tsv_with_options(&multiplicand, &multiplier, &options),
+        (None, None) => multiplication::get_table_with_options(&multiplicand, &multiplier, &options),
+    };
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+
+    // Only a single whole base-ten product is written in words.
+    let single: bool = args.times_table.is_none() && args.random.is_none() && args.batch.is_none() && args.operands.is_empty();
+    if args.words && single && args.base == 10 && args.multiplicand_decimals + args.multiplier_decimals == 0 {
+        let product: String = compute_product(&multiplicand, &multiplier);
+        let mut words: String = number_to_words(&product);
+        if args.multiplicand_negative != args.multiplier_negative && words != "zero" {
+            words = format!("minus {words}");
+        }
+        content.push_str(&format!("Product in words: {words}\n"));
+    }
+
+    if output == "mdcode" {
+        display(&multiplication::wrap_code_fence(&content, "text"));
+        return;
+    }
 
     if output == "display" || output == "both" {
-        multiplication::display(&content);
+        display(&content);
     }
 
     if output == "store" || output == "both" {
         let file_path: String = args.file;
-        multiplication::store(&content, &file_path);
+        let mut line_ending: LineEnding = LineEnding::Lf;
+        if args.eol == "crlf" {
+            line_ending = LineEnding::Crlf;
+        }
+        let content: String = multiplication::convert_line_endings(&content, line_ending);
+        let mut store_options: StoreOptions = StoreOptions { bom: args.bom, mkdir: args.mkdir, ..StoreOptions::default() };
+        if args.append {
+            store_options.mode = WriteMode::Append;
+        }
+        if let Err(error) = multiplication::try_store_with_options(&content, &file_path, &store_options) {
+            eprintln!("WARNING: the file '{file_path}' cannot be stored.\nDetails: {error}");
+            std::process::exit(1);
+        }
     }
 }
+
+fn display(content: &str) {
+    if let Err(error) = multiplication::display(content, &mut io::stdout().lock()) {
+        eprintln!("WARNING: the table cannot be displayed.\nDetails: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn batch(file_path: &str, max_digits: usize, options: &TableOptions) -> String {
+    let file: File = File::open(file_path).unwrap_or_else(|error| {
+        eprintln!("ERROR: the batch file '{file_path}' cannot be read.\nDetails: {error}");
+        std::process::exit(2);
+    });
+
+    let (pairs, skipped): (Vec<(String, String)>, Vec<SkippedLine>) = read_batch_with_max_digits(BufReader::new(file), max_digits);
+    for (line, error) in skipped {
+        eprintln!("WARNING: the line {line} of the batch file is skipped.\nDetails: {error}");
+    }
+
+    return multiplication::get_tables_batch_with_options(&pairs, options);
+}
@@ -0,0 +1,102 @@
+/// The kind of a cell of the table of the long multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CellKind {
+    // The position of the column, in the header.
+    Position,
+
+    // A digit of the multiplicand or the multiplier.
+    Operand,
+
+    // The `x` before the multiplier.
+    Times,
+
+    // The carry of a product, in the `n ^` rows.
+    Carry,
+
+    // The unit of a product, in the `n R` rows.
+    Digit,
+
+    // A digit of the sum of a column, in the `n C` rows.
+    Sum,
+
+    // A digit of the sum of a subtotal, in the `n C` rows of a `Sub n.`.
+    Subtotal,
+
+    // A digit of the product, in the `P` row.
+    Product,
+}
+
+/// A cell of the table of the long multiplication.
+///
+/// The empty cells have no value and keep the kind of their row.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::model::{Cell, CellKind};
+/// let cell: Cell = Cell { value: Some(8), kind: CellKind::Product };
+///
+/// assert_eq!(Some(8), cell.value);
+/// assert!(!cell.is_empty());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+    // The digit of the cell, `None` when the cell is empty.
+    pub value: Option<u8>,
+
+    // The kind of the cell.
+    pub kind: CellKind,
+}
+
+impl Cell {
+    /// Whether the cell has no digit.
+    pub fn is_empty(&self) -> bool {
+        return self.value.is_none();
+    }
+}
+
+/// The logical table of the long multiplication.
+///
+/// It holds the cells of the table without the borders, the
+/// titles and the glyphs, for the renderers that draw the table
+/// by themselves. Every row has one cell per column, from the
+/// most significant to the least significant, and the label of
+/// the right margin, like `1 ^` or `P`, when it has one.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::model::{CellKind, TableModel};
+/// use long_multiplication_command_line::multiplication::build_model;
+/// let model: TableModel = build_model("5", "7");
+///
+/// assert_eq!(2, model.columns);
+/// assert_eq!(model.grid.len(), model.labels.len());
+/// assert_eq!(CellKind::Position, model.grid[0][0].kind);
+/// assert_eq!(Some(String::from("P")), model.labels[model.labels.len() - 1]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableModel {
+    // The number of columns of each row.
+    pub columns: usize,
+
+    // The cells of each row, from the top to the bottom of the table.
+    pub grid: Vec<Vec<Cell>>,
+
+    // The label of each row, `None` for the header and the operands.
+    pub labels: Vec<Option<String>>,
+}
+
+impl TableModel {
+    /// The row of the product, the last row of the grid.
+    pub fn product(&self) -> &[Cell] {
+        return match self.grid.last() {
+            Some(row) => row,
+            None => &[],
+        };
+    }
+}
@@ -1,7 +1,74 @@
-use std::fs::File;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::fs::{File, OpenOptions};
+use std::io;
 use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
 
+use crate::breakdown::{Breakdown, break_down_addition, break_down_addition_with_radix, break_down_multiplication_with_radix, break_down_subtotal_with_radix, compute_product, compute_product_with_options, fully_reduce, normalize_operand, break_down_multiplication, break_down_subtotal, partial_products_with_radix};
+use crate::format::{Html, Json, Latex, Markdown, OutputFormat, Svg, Text, push_markdown_header, signed_operands};
 use crate::generate;
+use crate::length::{digit_count, get_string_length, get_strings_length, rendered_width};
+use crate::model::{Cell, CellKind, TableModel};
+use crate::options::{
+    CarryStyle, DigitSet, Footer, Labels, LineEnding, Literal, ProductAlign, RowFilter, RowOrder, StoreOptions, SumVerbosity, TableOptions, TableStyle, TopOperand, WriteMode,
+};
+
+/// The maximum number of digits of an operand in `get_table`.
+pub const MAX_DIGITS: usize = 500;
+
+/// The UTF-8 byte-order mark written before the stored content.
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+// The offset basis and the prime of the 64-bit FNV-1a hash.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// The version of the options key of `table_hash_with_options`, changed with its fields.
+const TABLE_HASH_VERSION: usize = 1;
+
+/// The error returned when the operands cannot be multiplied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MultiplicationError {
+    // An operand has no digits.
+    EmptyOperand,
+
+    // An operand holds a character other than the digits 0-9.
+    NonDigitCharacter(char),
+
+    // The operands are too long for the size of the table or the product.
+    Overflow,
+
+    // An operand has more digits than the limit.
+    TooManyDigits { limit: usize, digits: usize },
+
+    // The table cannot be written into the output.
+    Write(io::ErrorKind),
+}
+
+impl fmt::Display for MultiplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            MultiplicationError::EmptyOperand => write!(
+                f, "ERROR: the multiplicand and the multiplier must not be empty."
+            ),
+            MultiplicationError::NonDigitCharacter(character) => write!(
+                f, "ERROR: the operands must contain only digits, found '{character}'."
+            ),
+            MultiplicationError::Overflow => write!(
+                f, "ERROR: the operands are too long, the table or the product overflows."
+            ),
+            MultiplicationError::TooManyDigits { limit, digits } => write!(
+                f, "ERROR: operand exceeds {limit} digits (got {digits})."
+            ),
+            MultiplicationError::Write(kind) => write!(
+                f, "ERROR: the table cannot be written, {kind}."
+            ),
+        };
+    }
+}
 
 /// Return the table of the long multiplication.
 ///
@@ -60,7 +127,7 @@ use crate::generate;
 ///                       Project: https://github.com/airvzxf/long-multiplication-calculator\n";
 ///
 /// use long_multiplication_command_line::multiplication::get_table;
-/// let text: String = get_table(&multiplicand, &multiplier);
+/// let text: String = get_table(&multiplicand, &multiplier).unwrap();
 ///
 /// assert_eq!(expected, text);
 /// ```
@@ -160,295 +227,3728 @@ use crate::generate;
 ///                       Project: https://github.com/airvzxf/long-multiplication-calculator\n";
 ///
 /// use long_multiplication_command_line::multiplication::get_table;
-/// let text: String = get_table(&multiplicand, &multiplier);
+/// let text: String = get_table(&multiplicand, &multiplier).unwrap();
 ///
 /// assert_eq!(expected, text);
 /// ```
-pub fn get_table(multiplicand: &String, multiplier: &String) -> String {
-    let mut content: String = String::from("");
+pub fn get_table(multiplicand: &str, multiplier: &str) -> Result<String, MultiplicationError> {
+    return get_table_with_style(multiplicand, multiplier, &TableStyle::unicode());
+}
 
-    generate::symbols(&mut content);
-    generate::top_border(&multiplicand, &multiplier, &mut content);
-    generate::position_title(&multiplicand, &multiplier, &mut content);
-    generate::operation_title(&multiplicand, &multiplier, &mut content);
-    generate::multiplication(&multiplicand, &multiplier, &mut content);
-    generate::operations(&multiplicand, &multiplier, &mut content);
-    generate::sum_title(&multiplicand, &multiplier, &mut content);
-    generate::long_sum(&multiplicand, &multiplier, &mut content);
-    generate::bottom_border(&multiplicand, &multiplier, &mut content);
-    generate::author(&mut content);
+/// Return the table of the long multiplication with the given style.
+///
+/// It works like `get_table`, but the borders of the table
+/// are drawn with the glyphs of the style, e.g. the ASCII-only
+/// glyphs for the terminals without box-drawing characters.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_with_style;
+/// use long_multiplication_command_line::options::TableStyle;
+/// let text: String = get_table_with_style("5", "7", &TableStyle::ascii()).unwrap();
+///
+/// assert!(text.contains("+-------+\n|Pos.   |\n"));
+/// assert!(text.contains("| 3 | 5 | P\n+---+---+\n"));
+/// ```
+pub fn get_table_with_style(multiplicand: &str, multiplier: &str, style: &TableStyle) -> Result<String, MultiplicationError> {
+    let options: TableOptions = TableOptions { style: *style, ..TableOptions::default() };
+    validate_with_options(multiplicand, multiplier, &options)?;
 
-    let content: String = content;
-    return content;
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+
+    return Ok(get_table_with_options(&multiplicand, &multiplier, &options));
 }
 
-/// Display the table of the long multiplication.
+/// Validate the operands of the long multiplication.
 ///
-/// It displays the complete table for the
-/// long multiplication and returns it in a text variable.
+/// Both operands must be non-empty, contain only the digits
+/// from 0 to 9 and be short enough for the width of the table
+/// to fit in a `usize`.
 ///
 /// Examples
 /// --------
 ///
 /// Example #1
 /// ```rust
-/// let content: String = String::from("This is a text for test.");
+/// use long_multiplication_command_line::multiplication::{MultiplicationError, validate};
 ///
-/// use long_multiplication_command_line::multiplication::display;
-/// display(&content);
+/// assert_eq!(Ok(()), validate("13", "26"));
+/// assert_eq!(Err(MultiplicationError::EmptyOperand), validate("", "26"));
+/// assert_eq!(Err(MultiplicationError::NonDigitCharacter('a')), validate("12a", "26"));
 /// ```
-pub fn display(content: &String) {
-    println!("{content}");
+pub fn validate(multiplicand: &str, multiplier: &str) -> Result<(), MultiplicationError> {
+    return validate_with_radix(multiplicand, multiplier, 10);
 }
 
-/// Store the table of the long multiplication.
+/// Validate the operands of the long multiplication in the given base.
 ///
-/// It stores the complete table for the
-/// long multiplication as a file in your local machine.
+/// It works like `validate`, but the operands must contain only
+/// the digits of the given base (radix), from 2 to 16, e.g.
+/// `0` to `9` and `A` to `F` for base sixteen.
 ///
 /// Examples
 /// --------
 ///
 /// Example #1
-/// ```text
-/// let content: String = String::from("This text will be stored.");
-/// let file_path: String = String::from("/home/USER_NAME/test-store-doc-01.txt");
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{MultiplicationError, validate_with_radix};
 ///
-/// use long_multiplication_command_line::multiplication::store;
-/// store(&content, &file_path);
+/// assert_eq!(Ok(()), validate_with_radix("FF", "2", 16));
+/// assert_eq!(Err(MultiplicationError::NonDigitCharacter('8')), validate_with_radix("777", "8", 8));
 /// ```
-pub fn store(content: &String, file_path: &String) {
-    match File::create(file_path) {
-        Ok(mut file) => {
-            file.write_all(content.as_bytes())
+pub fn validate_with_radix(multiplicand: &str, multiplier: &str, radix: u32) -> Result<(), MultiplicationError> {
+    for operand in [multiplicand, multiplier] {
+        if operand.is_empty() {
+            return Err(MultiplicationError::EmptyOperand);
         }
-        Err(_err) => panic!("ERROR: the file '{file_path}' cannot be created.\nDetails: {_err:?}"),
-    }.expect("ERROR: trying to write the content in the file.");
+        if let Some(character) = operand.chars().find(|c| !c.is_digit(radix)) {
+            return Err(MultiplicationError::NonDigitCharacter(character));
+        }
+    }
+
+    let width: Option<usize> = multiplicand.len()
+        .checked_add(multiplier.len())
+        .and_then(|length| length.checked_mul(4))
+        .and_then(|width| width.checked_add(1));
+    if width.is_none() {
+        return Err(MultiplicationError::Overflow);
+    }
+
+    return Ok(());
 }
 
-#[cfg(test)]
-mod tests {
-    use std::io::Read;
+/// Validate that the operands do not exceed the number of digits.
+///
+/// The table grows with the product of the lengths of the
+/// operands, so the long operands are rejected before the
+/// table is generated, instead of exhausting the memory.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{MultiplicationError, validate_max_digits};
+///
+/// assert_eq!(Ok(()), validate_max_digits("13", "26", 2));
+/// assert_eq!(Err(MultiplicationError::TooManyDigits { limit: 2, digits: 3 }), validate_max_digits("13", "260", 2));
+/// ```
+pub fn validate_max_digits(multiplicand: &str, multiplier: &str, limit: usize) -> Result<(), MultiplicationError> {
+    let digits: usize = digit_count(multiplicand).max(digit_count(multiplier));
+    if digits > limit {
+        return Err(MultiplicationError::TooManyDigits { limit, digits });
+    }
 
-    use super::*;
+    return Ok(());
+}
 
-    // # -----------------------------------------------------------------------
-    // # Function: get table
-    // # -----------------------------------------------------------------------
-    #[test]
-    fn test_get_table_product_one_digits() {
-        // Arrange
-        let multiplicand: String = String::from("3");
-        let multiplier: String = String::from("2");
-        let expected: &str = "Symbols\n\
-                              =======\n\
-                              Pos. = Position.\n\
-                              Ops. = Operations of the long multiplication.\n\
-                              Sum. = Sum of each column of the multiplication.\n\
-                              Sub n. = Subtotal of the last sum.\n\
-                              Pro. = Product of the multiplication.\n\
-                              n ^ = Carry-over.\n\
-                              n R = The row number.\n\
-                              n C = The column number of the sum of the rows.\n\
-                              * Replace 'n' for a number.\n\
-                              P = The product of multiplication.\n\
-                              \n\
-                              ┏━━━━━━━┓\n\
-                              ┃Pos.   ┃\n\
-                              ┠┄┄┄┬┄┄┄┨\n\
-                              ┃ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Ops.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃   │ 3 ┃\n\
-                              ┃ x │ 2 ┃\n\
-                              ┣━━━┿━━━┫\n\
-                              ┃ 0 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 6 ┃ 1 R\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Sum.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃   │ 6 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   ┃ 2 C\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Pro.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃ 0 │ 6 ┃ P\n\
-                              ┗━━━┷━━━┛\n\
-                              \n\
-                              ---\n\
-                              Author: Israel Roldan\n\
-                              E-mail: israel.alberto.rv@gmail.com\n\
-                              License: GPL-3.0\n\
-                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+/// Validate the operands of the long multiplication for the given options.
+///
+/// It checks the digits of the operands in the base of the
+/// options, like `validate_with_radix`, and their length against
+/// the `max_digits` of the options, like `validate_max_digits`.
+/// The library callers raise or lower the limit of `MAX_DIGITS`
+/// through the options before calling `get_table_with_options`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{MultiplicationError, validate_with_options};
+/// use long_multiplication_command_line::options::TableOptions;
+/// let multiplicand: String = "9".repeat(600);
+/// let options: TableOptions = TableOptions { max_digits: 1000, ..TableOptions::default() };
+///
+/// assert_eq!(Ok(()), validate_with_options(&multiplicand, "7", &options));
+/// assert_eq!(
+///     Err(MultiplicationError::TooManyDigits { limit: 500, digits: 600 }),
+///     validate_with_options(&multiplicand, "7", &TableOptions::default()),
+/// );
+/// ```
+pub fn validate_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> Result<(), MultiplicationError> {
+    validate_with_radix(multiplicand, multiplier, options.radix)?;
 
-        // Action
-        let text: String = get_table(&multiplicand, &multiplier);
+    return validate_max_digits(multiplicand, multiplier, options.max_digits);
+}
 
-        // Assert
-        assert_eq!(expected, text);
+/// Return the table of the long multiplication using the given options.
+///
+/// It generates the complete table for the
+/// long multiplication, customized by the options,
+/// and returns it in a text variable.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+///
+/// use long_multiplication_command_line::multiplication::get_table_with_options;
+/// use long_multiplication_command_line::options::{Footer, TableOptions};
+/// let options: TableOptions = TableOptions { footer: Footer::Stats, ..TableOptions::default() };
+/// let text: String = get_table_with_options(&multiplicand, &multiplier, &options);
+///
+/// assert!(text.ends_with("Product digits: 2\nSub rounds: 0\nTable bytes: 989\n"));
+/// assert_eq!(989, text.len());
+/// ```
+pub fn get_table_with_options(multiplicand: &String, multiplier: &String, options: &TableOptions) -> String {
+    let mut content: Vec<u8> = Vec::new();
+
+    write_table_with_options(multiplicand, multiplier, options, &mut content)
+        .expect("ERROR: trying to write the table in memory.");
+
+    return String::from_utf8(content).expect("ERROR: the table is not valid UTF-8.");
+}
+
+/// Write the table of the long multiplication.
+///
+/// It works like `get_table`, but the table is written into the
+/// given writer, like a file or the standard output, section by
+/// section, so the complete table of very large operands is
+/// never held in memory.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{get_table, write_table};
+/// let mut out: Vec<u8> = Vec::new();
+/// write_table("5", "7", &mut out).unwrap();
+///
+/// assert_eq!(get_table("5", "7").unwrap().as_bytes(), out.as_slice());
+/// ```
+pub fn write_table<W: Write>(multiplicand: &str, multiplier: &str, out: &mut W) -> Result<(), MultiplicationError> {
+    let options: TableOptions = TableOptions::default();
+    validate_with_options(multiplicand, multiplier, &options)?;
+
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+
+    return write_table_with_options(&multiplicand, &multiplier, &options, out)
+        .map_err(|error| MultiplicationError::Write(error.kind()));
+}
+
+/// Write the table of the long multiplication using the given options.
+///
+/// It works like `get_table_with_options`, but each section is
+/// written into the given writer as soon as it is generated. The
+/// border repeated at the start of a section is dropped, like in
+/// `generate::dedupe_borders`. With `optimize_layout`, the operand
+/// with fewer digits is drawn as the multiplier, so the table has
+/// fewer rows, and the swap is noted in the Ops. section. A zero
+/// multiplicand is always swapped, so `0 * 999` draws a single
+/// row of zeros instead of one per digit of 999. The
+/// cells are widened for the position numbers of three or more
+/// digits, see `LayoutConfig::fit_positions`. With
+/// `LayoutConfig::max_width`, the lines are cut to the maximum
+/// width, see `generate::clip_lines`. With `colors`, the
+/// sections are written with the ANSI colors of `generate::colorize`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let mut out: Vec<u8> = Vec::new();
+///
+/// use long_multiplication_command_line::multiplication::write_table_with_options;
+/// use long_multiplication_command_line::options::{Footer, TableOptions};
+/// let options: TableOptions = TableOptions { footer: Footer::Stats, ..TableOptions::default() };
+/// write_table_with_options(&multiplicand, &multiplier, &options, &mut out).unwrap();
+///
+/// assert!(out.ends_with(b"Table bytes: 989\n"));
+/// ```
+pub fn write_table_with_options<W: Write>(multiplicand: &String, multiplier: &String, options: &TableOptions, out: &mut W) -> io::Result<()> {
+    let (multiplicand, multiplier, options): (String, String, TableOptions) = arrange_table(multiplicand, multiplier, options);
+    let (multiplicand, multiplier): (&String, &String) = (&multiplicand, &multiplier);
+
+    let format: Text = Text { options: options.clone() };
+    let mut section: String = String::from("");
+    let mut previous: String = String::from("");
+    let mut written: usize = 0;
+
+    let mut stage: usize = 0;
+    while generate_section(stage, &format, multiplicand, multiplier, written, &mut section) {
+        write_section(&mut section, &mut previous, &mut written, &options, out)?;
+        stage += 1;
     }
 
-    #[test]
-    fn test_get_table_product_two_digits() {
-        // Arrange
-        let multiplicand: String = String::from("5");
-        let multiplier: String = String::from("7");
-        let expected: &str = "Symbols\n\
-                              =======\n\
-                              Pos. = Position.\n\
-                              Ops. = Operations of the long multiplication.\n\
-                              Sum. = Sum of each column of the multiplication.\n\
-                              Sub n. = Subtotal of the last sum.\n\
-                              Pro. = Product of the multiplication.\n\
-                              n ^ = Carry-over.\n\
-                              n R = The row number.\n\
-                              n C = The column number of the sum of the rows.\n\
-                              * Replace 'n' for a number.\n\
-                              P = The product of multiplication.\n\
-                              \n\
-                              ┏━━━━━━━┓\n\
-                              ┃Pos.   ┃\n\
-                              ┠┄┄┄┬┄┄┄┨\n\
-                              ┃ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Ops.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃   │ 5 ┃\n\
-                              ┃ x │ 7 ┃\n\
-                              ┣━━━┿━━━┫\n\
-                              ┃ 3 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 5 ┃ 1 R\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Sum.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃   │ 5 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┨\n\
-                              ┃ 3 │   ┃ 2 C\n\
-                              ┣━━━┷━━━┫\n\
-                              ┃Pro.   ┃\n\
-                              ┣━━━┯━━━┫\n\
-                              ┃ 3 │ 5 ┃ P\n\
-                              ┗━━━┷━━━┛\n\
-                              \n\
-                              ---\n\
-                              Author: Israel Roldan\n\
-                              E-mail: israel.alberto.rv@gmail.com\n\
-                              License: GPL-3.0\n\
-                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+    out.flush()?;
+    return Ok(());
+}
 
-        // Action
-        let text: String = get_table(&multiplicand, &multiplier);
+/// Iterate over the lines of the table of the long multiplication.
+///
+/// It yields each line of the table of `get_table`, without the
+/// line feed. The sections are generated one by one as the lines
+/// are consumed, so the whole table is never held in memory. The
+/// invalid operands yield no lines.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::table_lines;
+/// let lines: Vec<String> = table_lines("5", "7").collect();
+///
+/// assert_eq!("Symbols", lines[0]);
+/// assert!(lines.contains(&String::from("┃ 3 │ 5 ┃ P")));
+/// ```
+pub fn table_lines(multiplicand: &str, multiplier: &str) -> impl Iterator<Item = String> {
+    let valid: bool = validate(multiplicand, multiplier).is_ok();
 
-        // Assert
-        assert_eq!(expected, text);
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+    let length: usize = get_strings_length(&multiplicand, &multiplier);
+    let options: TableOptions = TableOptions {
+        layout: TableOptions::default().layout.fit_positions(length),
+        ..TableOptions::default()
+    };
+
+    return TableLines {
+        multiplicand,
+        multiplier,
+        format: Text { options },
+        stage: if valid { 0 } else { usize::MAX },
+        previous: String::from(""),
+        written: 0,
+        lines: VecDeque::new(),
+    };
+}
+
+/// Get the rows and columns of the table of the long multiplication.
+///
+/// It returns the number of lines and the width of the widest
+/// line, in characters, of the table of `get_table`, including
+/// the symbols, sum, subtotal, product and footer sections. The
+/// table is never generated, see `table_dimensions_with_options`.
+/// The invalid operands return `(0, 0)`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{get_table, table_dimensions};
+/// let table: String = get_table("5", "7").unwrap();
+/// let (rows, columns): (usize, usize) = table_dimensions("5", "7");
+///
+/// assert_eq!(table.lines().count(), rows);
+/// assert_eq!(66, columns);
+/// ```
+pub fn table_dimensions(multiplicand: &str, multiplier: &str) -> (usize, usize) {
+    return table_dimensions_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Get the rows and columns of the table of the long multiplication using the given options.
+///
+/// It works like `table_dimensions`, but for the table of
+/// `get_table_with_options`, with the operands validated in the
+/// radix of the options. The operations and the sums, which grow
+/// with the product of the digits, are counted from the digits
+/// without drawing their rows, and the other sections are counted
+/// as they are written. The colors are not counted, since they
+/// take no columns.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{get_table_with_options, table_dimensions_with_options};
+/// use long_multiplication_command_line::options::{COMPACT_CELL_WIDTH, Footer, LayoutConfig, TableOptions};
+/// let options: TableOptions = TableOptions {
+///     layout: LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() },
+///     symbols: false,
+///     footer: Footer::None,
+///     ..TableOptions::default()
+/// };
+/// let table: String = get_table_with_options(&String::from("12345"), &String::from("678"), &options);
+/// let (rows, columns): (usize, usize) = table_dimensions_with_options("12345", "678", &options);
+///
+/// assert_eq!(44, rows);
+/// assert_eq!(table.lines().count(), rows);
+/// assert_eq!(21, columns);
+/// ```
+pub fn table_dimensions_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> (usize, usize) {
+    if validate_with_options(multiplicand, multiplier, options).is_err() {
+        return (0, 0);
     }
 
+    let multiplicand: String = normalize_operand(multiplicand);
+    let multiplier: String = normalize_operand(multiplier);
+    let options: TableOptions = TableOptions { colors: None, ..options.clone() };
+    let (multiplicand, multiplier, options): (String, String, TableOptions) = arrange_table(&multiplicand, &multiplier, &options);
+
+    let format: Text = Text { options: options.clone() };
+    let mut counter: DimensionCounter = DimensionCounter { rows: 0, columns: 0, line: 0 };
+    let mut section: String = String::from("");
+    let mut previous: String = String::from("");
+    let mut written: usize = 0;
+    for stage in [0, 1, 2, 4, 5] {
+        if stage == 2 {
+            // The operations and the sums, the stages 2 and 3, are counted without drawing them.
+            let (rows, columns, last): (usize, usize, String) = generate::body_dimensions(&multiplicand, &multiplier, &options);
+            counter.rows += rows;
+            counter.columns = counter.columns.max(options.layout.max_width.map_or(columns, |max| columns.min(max)));
+            previous = last;
+            continue;
+        }
+        generate_section(stage, &format, &multiplicand, &multiplier, written, &mut section);
+        write_section(&mut section, &mut previous, &mut written, &options, &mut counter)
+            .expect("ERROR: trying to count the table.");
+    }
+
+    let columns: usize = counter.columns.max(counter.line);
+    let rows: usize = counter.rows + usize::from(counter.line > 0);
+    return (rows, columns);
+}
+
+/// Return the table of the long multiplication in the given output format.
+///
+/// It chains the sections of the format: symbols, operands,
+/// operations, sum, product and footer.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+///
+/// use long_multiplication_command_line::format::Text;
+/// use long_multiplication_command_line::multiplication::{get_table, get_table_as};
+/// let text: String = get_table_as(&multiplicand, &multiplier, &Text::default());
+///
+/// assert_eq!(get_table(&multiplicand, &multiplier).unwrap(), text);
+/// ```
+pub fn get_table_as<F: OutputFormat>(multiplicand: &String, multiplier: &String, format: &F) -> String {
+    let mut content: String = String::from("");
+
+    format.symbols(&mut content);
+    format.operands(multiplicand, multiplier, &mut content);
+    format.operations(multiplicand, multiplier, &mut content);
+    format.sum(multiplicand, multiplier, &mut content);
+    format.product(multiplicand, multiplier, &mut content);
+    format.footer(multiplicand, multiplier, &mut content);
+
+    let content: String = content;
+    return content;
+}
+
+/// Return the comparison of two long multiplications.
+///
+/// It generates the tables of two long multiplications stacked
+/// one above the other, with a caption for each product, to
+/// teach the relationship between them (e.g. 12 x 10 vs 12 x 11).
+/// The symbols and the author are only included once, and the
+/// narrower table is indented so the right borders are aligned.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand_a: String = String::from("12");
+/// let multiplier_a: String = String::from("10");
+/// let multiplicand_b: String = String::from("12");
+/// let multiplier_b: String = String::from("11");
+///
+/// use long_multiplication_command_line::multiplication::compare;
+/// let text: String = compare(&multiplicand_a, &multiplier_a, &multiplicand_b, &multiplier_b);
+///
+/// assert!(text.contains("12 x 10 = 120\n┏"));
+/// assert!(text.contains("┛\n\nvs.\n\n12 x 11 = 132\n┏"));
+/// ```
+pub fn compare(multiplicand_a: &String, multiplier_a: &String, multiplicand_b: &String, multiplier_b: &String) -> String {
+    let options: TableOptions = TableOptions::default();
+    let width_a: usize = rendered_width(multiplicand_a, multiplier_a);
+    let width_b: usize = rendered_width(multiplicand_b, multiplier_b);
+    let width: usize = width_a.max(width_b);
+
+    let mut content: String = String::from("");
+    generate::symbols(&mut content);
+
+    let mut grid_a: String = String::from("");
+    generate_grid(multiplicand_a, multiplier_a, &options, &mut grid_a);
+    let product_a: String = compute_product(multiplicand_a, multiplier_a);
+    content.push_str(&format!("{multiplicand_a} x {multiplier_a} = {product_a}\n"));
+    content.push_str(&indent_lines(&grid_a, width - width_a));
+
+    content.push_str("\nvs.\n\n");
+
+    let mut grid_b: String = String::from("");
+    generate_grid(multiplicand_b, multiplier_b, &options, &mut grid_b);
+    let product_b: String = compute_product(multiplicand_b, multiplier_b);
+    content.push_str(&format!("{multiplicand_b} x {multiplier_b} = {product_b}\n"));
+    content.push_str(&indent_lines(&grid_b, width - width_b));
+
+    generate::author(&mut content);
+
+    let content: String = content;
+    return content;
+}
+
+/// Return a practice sheet of long multiplications.
+///
+/// It generates the table of each pair of multiplicand and
+/// multiplier, numbered as problems, followed by an answer key
+/// with the products. The symbols and the footer are only
+/// included once.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let pairs: Vec<(String, String)> = vec![
+///     (String::from("5"), String::from("7")),
+///     (String::from("13"), String::from("26")),
+/// ];
+///
+/// use long_multiplication_command_line::multiplication::get_practice_sheet;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let text: String = get_practice_sheet(&pairs, &TableOptions::default());
+///
+/// assert!(text.contains("Problem 1: 5 x 7\n┏"));
+/// assert!(text.contains("Problem 2: 13 x 26\n┏"));
+/// assert!(text.contains("Answer key\n==========\n1. 5 x 7 = 35\n2. 13 x 26 = 338\n"));
+/// ```
+pub fn get_practice_sheet(pairs: &[(String, String)], options: &TableOptions) -> String {
+    let mut content: String = String::from("");
+    if options.symbols {
+        generate::symbols(&mut content);
+    }
+
+    for (index, (multiplicand, multiplier)) in pairs.iter().enumerate() {
+        content.push_str(&format!("Problem {}: {multiplicand} x {multiplier}\n", index + 1));
+        generate_grid(multiplicand, multiplier, options, &mut content);
+        content.push('\n');
+    }
+
+    content.push_str("Answer key\n");
+    content.push_str("==========\n");
+    for (index, (multiplicand, multiplier)) in pairs.iter().enumerate() {
+        let product: String = compute_product(multiplicand, multiplier);
+        content.push_str(&format!("{}. {multiplicand} x {multiplier} = {product}\n", index + 1));
+    }
+
+    if options.footer == Footer::Author {
+        generate::author(&mut content);
+    }
+
+    let content: String = content;
+    return content;
+}
+
+/// Return the tables of a batch of long multiplications.
+///
+/// It generates the complete table of each pair of multiplicand
+/// and multiplier, like `get_table`, separated by a form feed
+/// line so each table starts on a new page when it is printed.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let pairs: Vec<(String, String)> = vec![
+///     (String::from("5"), String::from("7")),
+///     (String::from("13"), String::from("26")),
+/// ];
+///
+/// use long_multiplication_command_line::multiplication::{get_table, get_tables_batch};
+/// let text: String = get_tables_batch(&pairs);
+/// let expected: String = format!("{}\u{c}\n{}", get_table("5", "7").unwrap(), get_table("13", "26").unwrap());
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn get_tables_batch(pairs: &[(String, String)]) -> String {
+    let options: TableOptions = TableOptions::default();
+
+    return get_tables_batch_with_options(pairs, &options);
+}
+
+/// Return the tables of a batch of long multiplications using the given options.
+///
+/// It generates the table of each pair like `get_tables_batch`,
+/// but each one is drawn like `get_table_with_options`, e.g.
+/// without the symbols, with the ASCII style or without the
+/// author.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let pairs: Vec<(String, String)> = vec![
+///     (String::from("5"), String::from("7")),
+///     (String::from("13"), String::from("26")),
+/// ];
+///
+/// use long_multiplication_command_line::multiplication::get_tables_batch_with_options;
+/// use long_multiplication_command_line::options::{Footer, TableOptions};
+/// let options: TableOptions = TableOptions { symbols: false, footer: Footer::None, ..TableOptions::default() };
+/// let text: String = get_tables_batch_with_options(&pairs, &options);
+///
+/// assert!(text.starts_with('┏'));
+/// assert!(text.contains("┛\n\u{c}\n┏"));
+/// assert!(!text.contains("Author"));
+/// ```
+pub fn get_tables_batch_with_options(pairs: &[(String, String)], options: &TableOptions) -> String {
+    let tables: Vec<String> = pairs.iter()
+        .map(|(multiplicand, multiplier)| get_table_with_options(multiplicand, multiplier, options))
+        .collect();
+
+    return tables.join("\u{c}\n");
+}
+
+/// Return the chained tables of a long multiplication of many operands.
+///
+/// It multiplies the first two operands, then the product by the
+/// third operand, and so on. Each step has its own table, like
+/// `get_table`, after a line with the step and its product, so
+/// the product of the last step is the product of all operands.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let operands: Vec<String> = vec![String::from("2"), String::from("3"), String::from("4")];
+///
+/// use long_multiplication_command_line::multiplication::get_table_chain;
+/// let text: String = get_table_chain(&operands);
+///
+/// assert!(text.starts_with("Step 1: 2 × 3 = 6\n\n"));
+/// assert!(text.contains("Step 2: 6 × 4 = 24\n\n"));
+/// ```
+pub fn get_table_chain(operands: &[String]) -> String {
+    let options: TableOptions = TableOptions::default();
+
+    return get_table_chain_with_options(operands, &options);
+}
+
+/// Return the chained tables using the given options.
+///
+/// It works like `get_table_chain`, but each table is
+/// customized by the options, like `get_table_with_options`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let operands: Vec<String> = vec![String::from("2"), String::from("3"), String::from("4")];
+///
+/// use long_multiplication_command_line::multiplication::get_table_chain_with_options;
+/// use long_multiplication_command_line::options::{Footer, TableOptions};
+/// let options: TableOptions = TableOptions { footer: Footer::None, ..TableOptions::default() };
+/// let text: String = get_table_chain_with_options(&operands, &options);
+///
+/// assert!(text.ends_with("┃ 2 │ 4 ┃ P\n┗━━━┷━━━┛\n"));
+/// ```
+pub fn get_table_chain_with_options(operands: &[String], options: &TableOptions) -> String {
+    let mut content: String = String::from("");
+    let mut product: String = match operands.first() {
+        Some(first) => first.clone(),
+        None => return content,
+    };
+
+    for (step, operand) in operands.iter().skip(1).enumerate() {
+        let next: String = normalize_operand(&compute_product(&product, operand));
+        if step > 0 {
+            content.push('\n');
+        }
+        content.push_str(&format!("Step {}: {product} × {operand} = {next}\n\n", step + 1));
+        content.push_str(&get_table_with_options(&product, operand, options));
+        product = next;
+    }
+
+    let content: String = content;
+    return content;
+}
+
+/// Return the times table of a number.
+///
+/// It lists the products of the number by one up to
+/// the given multiplier, one per line.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let expected: &str = "7 × 1 = 7\n\
+///                       7 × 2 = 14\n\
+///                       7 × 3 = 21\n";
+///
+/// use long_multiplication_command_line::multiplication::times_table;
+/// let text: String = times_table("7", 3);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn times_table(n: &str, up_to: usize) -> String {
+    return times_table_with_options(n, up_to, &TableOptions::default());
+}
+
+/// Return the times table of a number using the given options.
+///
+/// It works like `times_table`, but the number and the products
+/// are written with the sign and the decimals of the multiplicand
+/// in the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let expected: &str = "-1.5 × 1 = -1.5\n\
+///                       -1.5 × 2 = -3.0\n";
+///
+/// use long_multiplication_command_line::multiplication::times_table_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { signs: (true, false), decimals: (1, 0), ..TableOptions::default() };
+/// let text: String = times_table_with_options("15", 2, &options);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn times_table_with_options(n: &str, up_to: usize, options: &TableOptions) -> String {
+    let multiplicand: String = n.to_string();
+
+    let mut content: String = String::from("");
+    for row in 1..up_to + 1 {
+        let multiplier: String = row.to_string();
+        content.push_str(&times_table_line(&multiplicand, &multiplier, options));
+    }
+
+    let content: String = content;
+    return content;
+}
+
+/// Return the times table of a number with the table of each product.
+///
+/// It generates the line of each product of the times table
+/// followed by its long multiplication. The symbols and the
+/// footer are only included once.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::times_table_with_grids;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let text: String = times_table_with_grids("7", 2, &TableOptions::default());
+///
+/// assert!(text.contains("7 × 1 = 7\n┏"));
+/// assert!(text.contains("7 × 2 = 14\n┏"));
+/// ```
+pub fn times_table_with_grids(n: &str, up_to: usize, options: &TableOptions) -> String {
+    let multiplicand: String = n.to_string();
+
+    let mut content: String = String::from("");
+    if options.symbols {
+        generate::symbols(&mut content);
+    }
+    for row in 1..up_to + 1 {
+        let multiplier: String = row.to_string();
+        content.push_str(&times_table_line(&multiplicand, &multiplier, options));
+        generate_grid(&multiplicand, &multiplier, options, &mut content);
+        content.push('\n');
+    }
+
+    if options.footer == Footer::Author {
+        generate::author(&mut content);
+    }
+
+    let content: String = content;
+    return content;
+}
+
+/// Return the number of teaching steps of the long multiplication.
+///
+/// It counts one step for each single-digit product, one for each
+/// carry-over, one for each column sum, one for each column
+/// carried in the subtotals, and one for the final product. A user
+/// interface can use it to show a progress like "step 5 of 20".
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::step_count;
+///
+/// assert_eq!(5, step_count("5", "7"));
+/// ```
+pub fn step_count(a: &str, b: &str) -> usize {
+    return step_count_with_radix(a, b, 10);
+}
+
+/// Return the number of teaching steps of the long multiplication in the given base.
+///
+/// It works like `step_count`, but the digits of the operands
+/// are in the given base (radix), from 2 to 16, so a column is
+/// carried when its sum is not a single digit of that base.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::step_count_with_radix;
+///
+/// assert_eq!(5, step_count_with_radix("F", "F", 16));
+/// ```
+pub fn step_count_with_radix(a: &str, b: &str, radix: u32) -> usize {
+    let multiplicand: String = a.to_string();
+    let multiplier: String = b.to_string();
+
+    let breakdown: Breakdown = break_down_multiplication_with_radix(&multiplicand, &multiplier, radix);
+    let units: &[usize] = breakdown.units();
+    let carriers: &[usize] = breakdown.carries();
+    let products: usize = units.len();
+    let carries: usize = carriers.iter().filter(|carry| carry > &&0).count();
+
+    let additions: Vec<usize> = break_down_addition_with_radix(&multiplicand, &multiplier, radix);
+    let sums: usize = additions.len();
+
+    let mut subtotals: usize = 0;
+    let mut sub_addition: Vec<usize> = additions;
+    while sub_addition.iter().any(|number| *number >= radix as usize) {
+        subtotals += sub_addition.iter().filter(|number| **number >= radix as usize).count();
+        sub_addition = break_down_subtotal_with_radix(&sub_addition, radix);
+    }
+
+    return products + carries + sums + subtotals + 1;
+}
+
+/// Get a stable key of the table of the long multiplication.
+///
+/// It hashes the operands without their leading zeros, so the
+/// same table always has the same key, without rendering it.
+/// The operands are not commutative: `13 * 26` and `26 * 13`
+/// are different tables.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::table_hash;
+///
+/// assert_eq!(table_hash("13", "26"), table_hash("013", "26"));
+/// assert_ne!(table_hash("13", "26"), table_hash("1", "326"));
+/// ```
+pub fn table_hash(multiplicand: &str, multiplier: &str) -> u64 {
+    let options: TableOptions = TableOptions::default();
+
+    return table_hash_with_options(multiplicand, multiplier, &options);
+}
+
+/// Get a stable key of the table using the given options.
+///
+/// It works like `table_hash`, but the options drawn in the
+/// table are hashed with the operands, so the tables with a
+/// different style, base or layout have different keys. The
+/// colors, the `swapped` note and `max_digits` do not change the
+/// drawing, so they keep the key. It is the 64-bit FNV-1a hash
+/// of a versioned list of the options, the same on every
+/// platform and every run; a new version of the list, for new
+/// options, changes the keys.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::{table_hash, table_hash_with_options};
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+///
+/// assert_eq!(table_hash("5", "7"), table_hash_with_options("5", "7", &TableOptions::default()));
+/// assert_ne!(table_hash("5", "7"), table_hash_with_options("5", "7", &options));
+/// ```
+///
+/// Example #2
+/// ```rust
+/// use long_multiplication_command_line::multiplication::table_hash_with_options;
+/// use long_multiplication_command_line::options::{ColorScheme, TableOptions};
+/// let options: TableOptions = TableOptions { colors: Some(ColorScheme::default()), ..TableOptions::default() };
+///
+/// assert_eq!(table_hash_with_options("5", "7", &TableOptions::default()), table_hash_with_options("5", "7", &options));
+/// ```
+pub fn table_hash_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> u64 {
+    // The operands are separated, so `1 * 23` and `12 * 3` differ.
+    let key: String = format!("{}*{}|{}", normalize_operand(multiplicand), normalize_operand(multiplier), table_hash_key(options));
+
+    let mut hash: u64 = FNV_OFFSET;
+    for byte in key.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    return hash;
+}
+
+/// Build the logical table of the long multiplication.
+///
+/// It returns the cells of the table, row after row, like they
+/// are drawn by `get_table`: the positions, the operands, the
+/// carries and units of each multiplier digit, the sum of each
+/// column, the subtotals and the product. The positions keep
+/// only their last digit, as in a ruler. The labels are the
+/// ones of `generate::row_labels`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::model::{Cell, CellKind, TableModel};
+/// use long_multiplication_command_line::multiplication::build_model;
+/// let model: TableModel = build_model("9", "3");
+/// let product: Vec<Option<u8>> = model.product().iter().map(|cell: &Cell| cell.value).collect();
+///
+/// assert_eq!(vec![Some(2), Some(7)], product);
+/// assert_eq!(Cell { value: Some(2), kind: CellKind::Carry }, model.grid[3][0]);
+/// assert_eq!(Some(String::from("1 ^")), model.labels[3]);
+/// ```
+pub fn build_model(multiplicand: &str, multiplier: &str) -> TableModel {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let columns: usize = get_strings_length(&multiplicand, &multiplier);
+    let step: usize = get_string_length(&multiplicand);
+
+    let mut grid: Vec<Vec<Cell>> = Vec::new();
+    let positions: Vec<u8> = (1..columns + 1).rev().map(|position| (position % 10) as u8).collect();
+    grid.push(model_row(&positions, 0, columns, CellKind::Position));
+    grid.push(model_row(&operand_digits(&multiplicand), 0, columns, CellKind::Operand));
+    let mut multiplier_row: Vec<Cell> = model_row(&operand_digits(&multiplier), 0, columns, CellKind::Operand);
+    multiplier_row[0] = Cell { value: None, kind: CellKind::Times };
+    grid.push(multiplier_row);
+
+    // Each row is shifted one column to the left, the carries one more.
+    let breakdown: Breakdown = break_down_multiplication(&multiplicand, &multiplier);
+    for row in 0..breakdown.rows() {
+        let range: Range<usize> = row * step..(row + 1) * step;
+        let carries: Vec<u8> = breakdown.carries()[range.clone()].iter().map(|digit| *digit as u8).collect();
+        let units: Vec<u8> = breakdown.units()[range].iter().map(|digit| *digit as u8).collect();
+        grid.push(model_row(&carries, row + 1, columns, CellKind::Carry));
+        grid.push(model_row(&units, row, columns, CellKind::Digit));
+    }
+
+    let additions: Vec<usize> = break_down_addition(&multiplicand, &multiplier);
+    push_model_sums(&additions, columns, CellKind::Sum, &mut grid);
+    let mut sub_addition: Vec<usize> = break_down_subtotal(&additions);
+    while sub_addition.iter().any(|number| *number > 9) {
+        push_model_sums(&sub_addition, columns, CellKind::Subtotal, &mut grid);
+        sub_addition = break_down_subtotal(&sub_addition);
+    }
+
+    let mut product: Vec<u8> = fully_reduce(&additions).iter().map(|digit| *digit as u8).collect();
+    product.reverse();
+    let mut product_row: Vec<Cell> = model_row(&product, 0, columns, CellKind::Product);
+    for cell in product_row.iter_mut().filter(|cell| cell.is_empty()) {
+        cell.value = Some(0);
+    }
+    grid.push(product_row);
+
+    let mut labels: Vec<Option<String>> = vec![None; 3];
+    labels.extend(generate::row_labels(&multiplicand, &multiplier).into_iter().map(Some));
+
+    return TableModel { columns, grid, labels };
+}
+
+/// Return the table of the long multiplication as JSON.
+///
+/// It generates the cells of the operations, sums and product
+/// of the long multiplication, with their coordinates, so a
+/// front-end can render and animate the table without deriving
+/// the layout again.
+///
+/// Besides the cells, the `base` has the radix of the digits,
+/// the `rows` have the `carries` and `units` of each multiplier
+/// digit, from the multiplicand's most significant digit, the
+/// `columnSums` have the sum of each column, starting from the
+/// units column, and the `subtotals` have each round of carrying
+/// those sums.
+///
+/// Each cell has the `row` and `col` (from left to right) in the
+/// grid, the `type` (`carry`, `unit`, `sum`, `product` or `blank`)
+/// and the digit `value` (`null` for blank cells).
+/// The rows are the carries and units for each multiplier digit,
+/// followed by the sum for each column and the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("9");
+/// let multiplier: String = String::from("3");
+///
+/// use long_multiplication_command_line::multiplication::get_table_json;
+/// let text: String = get_table_json(&multiplicand, &multiplier);
+///
+/// assert!(text.starts_with("{\"multiplicand\":\"9\",\"multiplier\":\"3\",\"product\":\"27\",\"base\":10"));
+/// assert!(text.contains("{\"row\":0,\"col\":0,\"type\":\"carry\",\"value\":2}"));
+/// assert!(text.contains("{\"row\":4,\"col\":1,\"type\":\"product\",\"value\":7}"));
+/// assert!(text.contains("\"rows\":[{\"carries\":[2],\"units\":[7]}],\"columnSums\":[7,2]"));
+/// ```
+pub fn get_table_json(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_json_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the long multiplication as a JSON object using the given options.
+///
+/// It works like `get_table_json`, but the `multiplicand`, the
+/// `multiplier` and the `product` have the signs and the decimal
+/// points of the options, and the digits of the rows, the sums and
+/// the cells are in the `base` of the options.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_json_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { signs: (true, false), decimals: (1, 0), ..TableOptions::default() };
+/// let text: String = get_table_json_with_options("15", "2", &options);
+///
+/// assert!(text.starts_with("{\"multiplicand\":\"-1.5\",\"multiplier\":\"2\",\"product\":\"-3.0\",\"base\":10"));
+/// ```
+pub fn get_table_json_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+
+    return get_table_as(&multiplicand, &multiplier, &Json { options: options.clone() });
+}
+
+/// Return the long multiplication as an HTML table.
+///
+/// It generates a semantic `<table>` to embed the long
+/// multiplication in a web page, where the box-drawing
+/// characters do not render consistently.
+///
+/// The first column of each row is its marker (`1 ^`, `1 R`,
+/// `1 C` or `P`), and each row has the class of its section:
+/// `carry`, `row`, `sum` or `product`. The cells have one `<td>`
+/// per position with the class of their digit.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_html;
+/// let text: String = get_table_html("5", "7");
+///
+/// assert!(text.contains("<table>\n<tr><th>Pos.</th><th>2</th><th>1</th></tr>\n"));
+/// assert!(text.contains("<tr class=\"carry\"><th>1 ^</th><td class=\"carry\">3</td><td></td></tr>\n"));
+/// assert!(text.ends_with("</table>\n<p>Product: 35</p>\n"));
+/// ```
+pub fn get_table_html(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_html_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the long multiplication as an HTML table using the given options.
+///
+/// It works like `get_table_html`, but the table mirrors the
+/// sections of the text table customized by the options: the
+/// operands, the operations, the sums with their subtotals and
+/// the product, each below its title row. The digits are in the
+/// base of the options, and the heading and the product show the
+/// signs and the decimal points of the operands.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_html_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
+/// let text: String = get_table_html_with_options("12", "3", &options);
+///
+/// assert!(text.contains("<h2>-12 &times; 3</h2>\n"));
+/// assert!(text.contains("<tr class=\"operand\"><th>x</th><td></td><td></td><td class=\"operand\">3</td></tr>\n"));
+/// assert!(text.ends_with("</table>\n<p>Product: -36</p>\n"));
+/// ```
+pub fn get_table_html_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+
+    return get_table_as(&multiplicand, &multiplier, &Html { options: options.clone() });
+}
+
+/// Return the long multiplication as a Markdown table.
+///
+/// It generates a GitHub-flavored pipe table, to paste in the
+/// issues and the documents where the box-drawing characters
+/// look broken. The header row has the positions, followed by
+/// the rows of the operations, the sums and the product, and
+/// the product is written below the table. Unlike the Markdown
+/// document of `get_table_as`, it has no symbols nor heading.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_markdown;
+/// let text: String = get_table_markdown("5", "7");
+///
+/// assert!(text.starts_with("| Pos. | 2 | 1 |\n| --- | --- | --- |\n"));
+/// assert!(text.ends_with("| P | 3 | 5 |\n\n**Product:** 35\n"));
+/// ```
+pub fn get_table_markdown(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_markdown_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the long multiplication as a Markdown table using the given options.
+///
+/// It works like `get_table_markdown`, but the digits are in the
+/// base of the options, and the product below the table has the
+/// sign and the decimal point of the operands.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_markdown_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { signs: (true, false), decimals: (0, 1), ..TableOptions::default() };
+/// let text: String = get_table_markdown_with_options("5", "7", &options);
+///
+/// assert!(text.ends_with("| P | 3 | 5 |\n\n**Product:** -3.5\n"));
+/// ```
+pub fn get_table_markdown_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let format: Markdown = Markdown { options: options.clone() };
+    let mut text: String = String::from("");
+
+    push_markdown_header(&multiplicand, &multiplier, &mut text);
+    format.operations(&multiplicand, &multiplier, &mut text);
+    format.sum(&multiplicand, &multiplier, &mut text);
+    format.product(&multiplicand, &multiplier, &mut text);
+    return text;
+}
+
+/// Return the digits of the long multiplication as tab-separated values.
+///
+/// It generates a grid of numbers without borders nor labels, to
+/// paste in a spreadsheet. The first two rows are the digits of the
+/// multiplicand and the multiplier, followed by one row per partial
+/// product, shifted with zeros to the position of its multiplier
+/// digit, and the row of the product. Every row has one column per
+/// position, and the empty positions on the left are zeros.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_tsv;
+/// let text: String = get_table_tsv("5", "7");
+///
+/// assert_eq!("0\t5\n0\t7\n3\t5\n3\t5\n", text);
+/// ```
+pub fn get_table_tsv(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_tsv_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the digits of the long multiplication as tab-separated values using the given options.
+///
+/// It works like `get_table_tsv`, but the digits are in the base
+/// (radix) of the options. The grid only holds digits, so the
+/// signs and the decimals of the options are not drawn, and the
+/// command line rejects them for this format.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_tsv_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+/// let text: String = get_table_tsv_with_options("FF", "3", &options);
+///
+/// assert_eq!("0\tF\tF\n0\t0\t3\n2\tF\tD\n2\tF\tD\n", text);
+/// ```
+pub fn get_table_tsv_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand_str: String = multiplicand.to_string();
+    let multiplier_str: String = multiplier.to_string();
+    let length: usize = get_strings_length(&multiplicand_str, &multiplier_str);
+    let radix: TableOptions = TableOptions { radix: options.radix, ..TableOptions::default() };
+
+    let mut rows: Vec<String> = vec![multiplicand_str.clone(), multiplier_str.clone()];
+    rows.extend(partial_products_with_radix(multiplicand, multiplier, options.radix));
+    rows.push(compute_product_with_options(&multiplicand_str, &multiplier_str, &radix));
+
+    let mut text: String = String::from("");
+    for row in rows {
+        let padded: String = format!("{row:0>length$}");
+        let cells: Vec<String> = padded.chars().map(|digit| digit.to_string()).collect();
+        text.push_str(&cells.join("\t"));
+        text.push('\n');
+    }
+    return text;
+}
+
+/// Return the long multiplication as an SVG image.
+///
+/// It draws the grid with a `<rect>` per cell and a `<text>` per
+/// digit, so the table scales crisply in the slides, where the
+/// box-drawing characters do not. The `viewBox` is sized from
+/// the number of columns and rows, and the carries and the
+/// product are colored with the default `ColorScheme`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_svg;
+/// let text: String = get_table_svg("5", "7");
+///
+/// assert!(text.contains("viewBox=\"0 0 96 144\""));
+/// assert!(text.ends_with("</svg>\n"));
+/// ```
+pub fn get_table_svg(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_svg_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the long multiplication as an SVG image using the given options.
+///
+/// It works like `get_table_svg`, but the carries, the product
+/// and the borders are colored with the `ColorScheme` of the
+/// options, when it has one, the digits are in the base of the
+/// options, and the product row shows the sign and the decimal
+/// point of the product.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_svg_with_options;
+/// use long_multiplication_command_line::options::{ColorScheme, TableOptions};
+/// let colors: ColorScheme = ColorScheme { product: "1;34", ..ColorScheme::default() };
+/// let options: TableOptions = TableOptions { colors: Some(colors), signs: (true, false), ..TableOptions::default() };
+/// let text: String = get_table_svg_with_options("12", "34", &options);
+///
+/// assert!(text.contains("text.product { font-weight: bold; fill: blue; }\n"));
+/// assert!(text.contains("<text class=\"product\" x=\"60\" y=\"228\">-</text>\n"));
+/// ```
+pub fn get_table_svg_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+
+    return get_table_as(&multiplicand, &multiplier, &Svg { options: options.clone() });
+}
+
+/// Return the long multiplication as a LaTeX array.
+///
+/// It generates an `array` environment in display math, for
+/// the math worksheets typeset with LaTeX. It has the operands,
+/// a row for each partial product, with the carries as small
+/// superscripts, and the product below an `\hline`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let expected: &str = "\\[\n\
+///                       \\begin{array}{rcc}\n \
+///                       &  & 5 \\\\\n\
+///                       \\times &  & 7 \\\\\n\
+///                       \\hline\n \
+///                       & {}^{3} & 5 \\\\\n\
+///                       \\hline\n \
+///                       & 3 & 5 \\\\\n\
+///                       \\end{array}\n\
+///                       \\]\n";
+///
+/// use long_multiplication_command_line::multiplication::get_table_latex;
+/// let text: String = get_table_latex("5", "7");
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn get_table_latex(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_latex_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the long multiplication as a LaTeX array using the given options.
+///
+/// It works like `get_table_latex`, but the digits are in the
+/// base of the options, and the operands and the product show
+/// their signs and decimal points. The sign of the product takes
+/// the place of its leading zero, when it has one.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_latex_with_options;
+/// use long_multiplication_command_line::options::TableOptions;
+/// let options: TableOptions = TableOptions { signs: (false, true), decimals: (1, 0), ..TableOptions::default() };
+/// let text: String = get_table_latex_with_options("12", "34", &options);
+///
+/// assert!(text.contains("\\begin{array}{rcccc}\n &  &  & 1. & 2 \\\\\n\\times &  &  & -3 & 4 \\\\\n"));
+/// assert!(text.contains("\\hline\n & - & 4 & 0. & 8 \\\\\n\\end{array}\n"));
+/// ```
+pub fn get_table_latex_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+
+    return get_table_as(&multiplicand, &multiplier, &Latex { options: options.clone() });
+}
+
+/// Return the Russian peasant (doubling and halving) multiplication.
+///
+/// It generates the two-column table of the peasant method,
+/// halving the multiplicand and doubling the multiplier, instead
+/// of the stacked table. See `generate::peasant`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_peasant;
+/// let text: String = get_table_peasant("18", "25");
+///
+/// assert!(text.ends_with("50 + 400 = 450\n"));
+/// ```
+pub fn get_table_peasant(multiplicand: &str, multiplier: &str) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let mut text: String = String::from("");
+
+    generate::peasant(&multiplicand, &multiplier, &mut text);
+    return text;
+}
+
+/// Return the plain (schoolbook) long multiplication.
+///
+/// It generates the traditional stacked layout, with the
+/// operands aligned to the right and the partial products
+/// indented, without the grid. See `generate::plain`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_plain;
+/// let text: String = get_table_plain("123", "45");
+///
+/// assert!(text.starts_with(" 123\n× 45\n"));
+/// assert!(text.ends_with("----\n5535\n"));
+/// ```
+pub fn get_table_plain(multiplicand: &str, multiplier: &str) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let mut text: String = String::from("");
+
+    generate::plain(&multiplicand, &multiplier, &mut text);
+    return text;
+}
+
+/// Return the Karatsuba decomposition of the multiplication.
+///
+/// It generates the derivation of the three sub-products of the
+/// Karatsuba method and how they recombine, instead of the grid.
+/// See `generate::karatsuba`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_karatsuba;
+/// let text: String = get_table_karatsuba("1234", "5678");
+///
+/// assert!(text.contains("a = 12, b = 34, c = 56, d = 78\n"));
+/// assert!(text.ends_with("= 7006652\n"));
+/// ```
+pub fn get_table_karatsuba(multiplicand: &str, multiplier: &str) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let mut text: String = String::from("");
+
+    generate::karatsuba(&multiplicand, &multiplier, &mut text);
+    return text;
+}
+
+/// Return only the operations section of the long multiplication.
+///
+/// It generates the top border, the `Ops.` title, the operands,
+/// the partial-product rows and the bottom border, without the
+/// `Sum.` and `Pro.` sections, for the worksheets where the sum
+/// is left to the student.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let expected: &str = "┏━━━━━━━┓\n\
+///                       ┃Ops.   ┃\n\
+///                       ┣━━━┯━━━┫\n\
+///                       ┃   │ 5 ┃\n\
+///                       ┃ x │ 7 ┃\n\
+///                       ┣━━━┿━━━┫\n\
+///                       ┃ 3 │   ┃ 1 ^\n\
+///                       ┠┈┈┈┼┈┈┈┨\n\
+///                       ┃   │ 5 ┃ 1 R\n\
+///                       ┗━━━┷━━━┛\n";
+///
+/// use long_multiplication_command_line::multiplication::get_operations_only;
+/// let text: String = get_operations_only("5", "7");
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn get_operations_only(multiplicand: &str, multiplier: &str) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let options: TableOptions = TableOptions::default();
+    let mut text: String = String::from("");
+
+    generate::top_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+    generate::operation_title_with_options(&multiplicand, &multiplier, &options, &mut text);
+    generate::multiplication_with_options(&multiplicand, &multiplier, &options, &mut text);
+    generate::operations_with_options(&multiplicand, &multiplier, &options, &mut text);
+
+    // The rule above the sum section is replaced by the bottom border.
+    text.pop();
+    if let Some(index) = text.rfind('\n') {
+        text.truncate(index + 1);
+    }
+    generate::bottom_border_with_options(&multiplicand, &multiplier, &options, &mut text);
+    return text;
+}
+
+/// Return the lattice (grid) multiplication.
+///
+/// It generates the diagonal grid of the lattice method, taught
+/// alongside the long multiplication, instead of the stacked
+/// table. See `generate::lattice`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_lattice;
+/// let text: String = get_table_lattice("27", "34");
+///
+/// assert!(text.starts_with("     2     7\n"));
+/// assert!(text.ends_with("     1     8\n"));
+/// ```
+pub fn get_table_lattice(multiplicand: &str, multiplier: &str) -> String {
+    return get_table_lattice_with_options(multiplicand, multiplier, &TableOptions::default());
+}
+
+/// Return the lattice (grid) multiplication using the given options.
+///
+/// It works like `get_table_lattice`, but the grid is drawn with
+/// the style and the base of the options, and the signed product
+/// is written below it. See `generate::lattice_with_options`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::multiplication::get_table_lattice_with_options;
+/// use long_multiplication_command_line::options::{TableOptions, TableStyle};
+/// let options: TableOptions = TableOptions { style: TableStyle::ascii(), signs: (false, true), ..TableOptions::default() };
+/// let text: String = get_table_lattice_with_options("27", "34", &options);
+///
+/// assert!(text.contains("  +-----+-----+\n"));
+/// assert!(text.ends_with("     1     8\n= -918\n"));
+/// ```
+pub fn get_table_lattice_with_options(multiplicand: &str, multiplier: &str, options: &TableOptions) -> String {
+    let multiplicand: String = multiplicand.to_string();
+    let multiplier: String = multiplier.to_string();
+    let mut text: String = String::from("");
+
+    generate::lattice_with_options(&multiplicand, &multiplier, options, &mut text);
+    return text;
+}
+
+/// Return the long multiplication as a Python or JavaScript literal.
+///
+/// It generates a dictionary (Python) or an object (JavaScript)
+/// with the multiplicand (`a`), the multiplier (`b`), the product
+/// and the units and carries of each row of the operations, so it
+/// can be pasted as the expected values of a test suite.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("13");
+/// let multiplier: String = String::from("26");
+/// let expected: &str = "{\"a\": 13, \"b\": 26, \"product\": 338, \"rows\": [\
+///                       {\"units\": [6, 8], \"carries\": [0, 1]}, \
+///                       {\"units\": [2, 6], \"carries\": [0, 0]}\
+///                       ]}";
+///
+/// use long_multiplication_command_line::multiplication::get_table_literal;
+/// use long_multiplication_command_line::options::Literal;
+/// let text: String = get_table_literal(&multiplicand, &multiplier, Literal::Python);
+///
+/// assert_eq!(expected, text);
+/// ```
+///
+/// Example #2
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let expected: &str = "{a: 5, b: 7, product: 35, rows: [{units: [5], carries: [3]}]}";
+///
+/// use long_multiplication_command_line::multiplication::get_table_literal;
+/// use long_multiplication_command_line::options::Literal;
+/// let text: String = get_table_literal(&multiplicand, &multiplier, Literal::JavaScript);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn get_table_literal(multiplicand: &String, multiplier: &String, literal: Literal) -> String {
+    return get_table_literal_with_options(multiplicand, multiplier, literal, &TableOptions::default());
+}
+
+/// Return the long multiplication as a Python or JavaScript literal using the given options.
+///
+/// It works like `get_table_literal`, but the operands and the
+/// product are written with the signs and the decimals of the
+/// options, which are valid number literals in both languages.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let multiplicand: String = String::from("5");
+/// let multiplier: String = String::from("7");
+/// let expected: &str = "{a: -0.5, b: 7, product: -3.5, rows: [{units: [5], carries: [3]}]}";
+///
+/// use long_multiplication_command_line::multiplication::get_table_literal_with_options;
+/// use long_multiplication_command_line::options::{Literal, TableOptions};
+/// let options: TableOptions = TableOptions { signs: (true, false), decimals: (1, 0), ..TableOptions::default() };
+/// let text: String = get_table_literal_with_options(&multiplicand, &multiplier, Literal::JavaScript, &options);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn get_table_literal_with_options(multiplicand: &String, multiplier: &String, literal: Literal, options: &TableOptions) -> String {
+    let step: usize = get_string_length(multiplicand);
+    let quote: &str = match literal {
+        Literal::Python => "\"",
+        Literal::JavaScript => "",
+    };
+
+    let breakdown: Breakdown = break_down_multiplication(multiplicand, multiplier);
+    let units: &[usize] = breakdown.units();
+    let carriers: &[usize] = breakdown.carries();
+
+    let mut rows: Vec<String> = Vec::new();
+    for start in (0..units.len()).step_by(step) {
+        let row_units: Vec<String> = units[start..start + step].iter().map(|n| n.to_string()).collect();
+        let row_carries: Vec<String> = carriers[start..start + step].iter().map(|n| n.to_string()).collect();
+        rows.push(format!(
+            "{{{quote}units{quote}: [{}], {quote}carries{quote}: [{}]}}",
+            row_units.join(", "),
+            row_carries.join(", "),
+        ));
+    }
+
+    let (a, b): (String, String) = signed_operands(multiplicand, multiplier, options);
+    let text: String = format!(
+        "{{{quote}a{quote}: {a}, {quote}b{quote}: {b}, \
+         {quote}product{quote}: {}, {quote}rows{quote}: [{}]}}",
+        compute_product_with_options(multiplicand, multiplier, options),
+        rows.join(", "),
+    );
+    return text;
+}
+
+/// Return the content wrapped in a Markdown code block.
+///
+/// It surrounds the content with backtick fences and the given
+/// language hint, so the table is not reflowed when it is pasted
+/// into Markdown. The fences are longer than any run of backticks
+/// inside the content.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let content: String = String::from("┃ 3 │ 5 ┃ P\n");
+/// let expected: &str = "```text\n┃ 3 │ 5 ┃ P\n```\n";
+///
+/// use long_multiplication_command_line::multiplication::wrap_code_fence;
+/// let text: String = wrap_code_fence(&content, "text");
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn wrap_code_fence(content: &String, language: &str) -> String {
+    let mut longest: usize = 0;
+    let mut run: usize = 0;
+    for character in content.chars() {
+        if character == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    let fence: String = "`".repeat((longest + 1).max(3));
+
+    let mut text: String = format!("{fence}{language}\n");
+    text.push_str(content);
+    if !content.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(&fence);
+    text.push('\n');
+
+    return text;
+}
+
+/// Display the table of the long multiplication.
+///
+/// It writes the complete table for the long multiplication
+/// into the given writer, like the locked standard output, as
+/// is: no line ending is appended to the content.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let content: String = String::from("This is a text for test.\n");
+/// let mut out: Vec<u8> = Vec::new();
+///
+/// use long_multiplication_command_line::multiplication::display;
+/// display(&content, &mut out).unwrap();
+///
+/// assert_eq!(content.as_bytes(), out.as_slice());
+/// ```
+pub fn display<W: Write>(content: &str, out: &mut W) -> io::Result<()> {
+    out.write_all(content.as_bytes())?;
+    out.flush()?;
+
+    return Ok(());
+}
+
+/// Convert the line endings of the table of the long multiplication.
+///
+/// It replaces each `\n` of the content with the given line ending.
+/// The existing `\r\n` line endings are kept as they are, so the
+/// content is never converted twice.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let content: String = String::from("┃ 3 │ 5 ┃ P\n┗━━━┷━━━┛\r\n");
+/// let expected: &str = "┃ 3 │ 5 ┃ P\r\n┗━━━┷━━━┛\r\n";
+///
+/// use long_multiplication_command_line::multiplication::convert_line_endings;
+/// use long_multiplication_command_line::options::LineEnding;
+/// let text: String = convert_line_endings(&content, LineEnding::Crlf);
+///
+/// assert_eq!(expected, text);
+/// ```
+pub fn convert_line_endings(content: &String, line_ending: LineEnding) -> String {
+    let mut text: String = String::with_capacity(content.len());
+
+    let mut previous: char = '\0';
+    for character in content.chars() {
+        if character == '\n' && previous != '\r' && line_ending == LineEnding::Crlf {
+            text.push('\r');
+        }
+        text.push(character);
+        previous = character;
+    }
+
+    let text: String = text;
+    return text;
+}
+
+/// Store the table of the long multiplication.
+///
+/// It stores the complete table for the
+/// long multiplication as a file in your local machine.
+/// The ANSI escape sequences (colors) are removed, so the
+/// file stays clean.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```text
+/// let content: String = String::from("This text will be stored.");
+/// let file_path: String = String::from("/home/USER_NAME/test-store-doc-01.txt");
+///
+/// use long_multiplication_command_line::multiplication::store;
+/// store(&content, &file_path);
+/// ```
+pub fn store(content: &String, file_path: &String) {
+    let options: StoreOptions = StoreOptions::default();
+
+    store_with_options(content, file_path, &options);
+}
+
+/// Store the table of the long multiplication using the given options.
+///
+/// It works like `store`, but with `bom` the UTF-8 byte-order
+/// mark (`EF BB BF`) is written before the content, so the
+/// Windows editors decode the box-drawing characters. With the
+/// `WriteMode::Append` mode, the content is written after the
+/// tables already stored in the file, instead of replacing them.
+/// With `mkdir`, the missing parent directories of the file are
+/// created first; without it, a missing directory still panics.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```text
+/// let content: String = String::from("This text will be stored.");
+/// let file_path: String = String::from("/home/USER_NAME/test-store-doc-02.txt");
+///
+/// use long_multiplication_command_line::multiplication::store_with_options;
+/// use long_multiplication_command_line::options::StoreOptions;
+/// store_with_options(&content, &file_path, &StoreOptions { bom: true, ..StoreOptions::default() });
+/// ```
+pub fn store_with_options(content: &String, file_path: &String, options: &StoreOptions) {
+    let content: String = generate::strip_ansi(content);
+
+    match open_stored(file_path, options) {
+        Ok(mut file) => {
+            write_stored(&content, options, &mut file)
+        }
+        Err(_err) => panic!("ERROR: the file '{file_path}' cannot be created.\nDetails: {_err:?}"),
+    }.expect("ERROR: trying to write the content in the file.");
+}
+
+/// Try to store the table of the long multiplication.
+///
+/// It stores the complete table for the
+/// long multiplication as a file in your local machine,
+/// returning the error instead of panicking.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let content: String = String::from("This text will not be stored.");
+/// let file_path: String = String::from("/tmp/USER_NAME/test-try-store-doc-01.txt");
+///
+/// use long_multiplication_command_line::multiplication::try_store;
+/// let result: std::io::Result<()> = try_store(&content, &file_path);
+///
+/// assert!(result.is_err());
+/// ```
+pub fn try_store(content: &String, file_path: &String) -> io::Result<()> {
+    let options: StoreOptions = StoreOptions::default();
+
+    return try_store_with_options(content, file_path, &options);
+}
+
+/// Try to store the table of the long multiplication using the given options.
+///
+/// It works like `store_with_options`, returning the error
+/// instead of panicking.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// let content: String = String::from("This text will not be stored.");
+/// let file_path: String = String::from("/tmp/USER_NAME/test-try-store-doc-02.txt");
+///
+/// use long_multiplication_command_line::multiplication::try_store_with_options;
+/// use long_multiplication_command_line::options::StoreOptions;
+/// let result: std::io::Result<()> = try_store_with_options(&content, &file_path, &StoreOptions { bom: true, ..StoreOptions::default() });
+///
+/// assert!(result.is_err());
+/// ```
+pub fn try_store_with_options(content: &String, file_path: &String, options: &StoreOptions) -> io::Result<()> {
+    let content: String = generate::strip_ansi(content);
+    let mut file: File = open_stored(file_path, options)?;
+    write_stored(&content, options, &mut file)?;
+
+    return Ok(());
+}
+
+fn open_stored(file_path: &String, options: &StoreOptions) -> io::Result<File> {
+    if options.mkdir {
+        if let Some(parent) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    return match options.mode {
+        WriteMode::Truncate => File::create(file_path),
+        WriteMode::Append => OpenOptions::new().append(true).create(true).open(file_path),
+    };
+}
+
+fn write_stored(content: &str, options: &StoreOptions, file: &mut File) -> io::Result<()> {
+    // The appended tables follow the mark written at the start of the file.
+    if options.bom && file.metadata()?.len() == 0 {
+        file.write_all(UTF8_BOM)?;
+    }
+    file.write_all(content.as_bytes())?;
+
+    return Ok(());
+}
+
+
+/// A writer that counts the lines and the widest line, in characters.
+struct DimensionCounter {
+    // The complete lines written.
+    rows: usize,
+
+    // The characters of the widest complete line.
+    columns: usize,
+
+    // The characters of the line being written.
+    line: usize,
+}
+
+impl Write for DimensionCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for byte in buf {
+            if *byte == b'\n' {
+                self.rows += 1;
+                self.columns = self.columns.max(self.line);
+                self.line = 0;
+            } else if byte & 0xC0 != 0x80 {
+                // The UTF-8 continuation bytes are part of the previous character.
+                self.line += 1;
+            }
+        }
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
+
+/// The lines of a table, generated section by section by `table_lines`.
+struct TableLines {
+    // The operands of the table.
+    multiplicand: String,
+    multiplier: String,
+
+    // The format with the options of the table.
+    format: Text,
+
+    // The next section to generate, see `generate_section`.
+    stage: usize,
+
+    // The last line of the previous section and the bytes written.
+    previous: String,
+    written: usize,
+
+    // The lines of the current section not yielded yet.
+    lines: VecDeque<String>,
+}
+
+impl Iterator for TableLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while self.lines.is_empty() {
+            let mut section: String = String::from("");
+            if !generate_section(self.stage, &self.format, &self.multiplicand, &self.multiplier, self.written, &mut section) {
+                return None;
+            }
+            self.stage += 1;
+
+            let mut content: Vec<u8> = Vec::new();
+            write_section(&mut section, &mut self.previous, &mut self.written, &self.format.options, &mut content)
+                .expect("ERROR: trying to write the table in memory.");
+            let content: String = String::from_utf8(content).expect("ERROR: the table is not valid UTF-8.");
+            self.lines.extend(content.lines().map(String::from));
+        }
+
+        return self.lines.pop_front();
+    }
+}
+
+fn generate_section(stage: usize, format: &Text, multiplicand: &String, multiplier: &String, written: usize, section: &mut String) -> bool {
+    match stage {
+        0 => format.symbols(section),
+        1 => format.operands(multiplicand, multiplier, section),
+        2 => format.operations(multiplicand, multiplier, section),
+        3 => format.sum(multiplicand, multiplier, section),
+        4 => format.product(multiplicand, multiplier, section),
+        // The statistics count the bytes already written.
+        5 => match format.options.footer {
+            Footer::Stats => generate::stats_with_radix(multiplicand, multiplier, written, format.options.radix, section),
+            _ => format.footer(multiplicand, multiplier, section),
+        },
+        _ => return false,
+    }
+
+    return true;
+}
+
+fn write_section<W: Write>(section: &mut String, previous: &mut String, written: &mut usize, options: &TableOptions, out: &mut W) -> io::Result<()> {
+    // The last line written is prepended to drop its duplicate.
+    let mut text: String = format!("{previous}{section}");
+    generate::dedupe_borders(&mut text);
+    let text: &str = &text[previous.len()..];
+
+    // The lines are cut, and the digits and the colors are replaced, after the borders are compared.
+    let clipped: String = match options.layout.max_width {
+        Some(max) => generate::clip_lines(text, max),
+        None => text.to_string(),
+    };
+    let localized: String = generate::localize_digits(&clipped, &options.style, options.digits);
+    let colored: String = match &options.colors {
+        Some(scheme) => generate::colorize(&localized, &options.style, scheme),
+        None => localized,
+    };
+    out.write_all(colored.as_bytes())?;
+    *written += colored.len();
+    if let Some(line) = text.split_inclusive('\n').next_back() {
+        *previous = line.to_string();
+    }
+    section.clear();
+
+    return Ok(());
+}
+
+fn generate_grid(multiplicand: &String, multiplier: &String, options: &TableOptions, content: &mut String) {
+    generate::top_border_with_options(multiplicand, multiplier, options, content);
+    generate::position_title_with_options(multiplicand, multiplier, options, content);
+    generate::operation_title_with_options(multiplicand, multiplier, options, content);
+    generate::multiplication_with_options(multiplicand, multiplier, options, content);
+    generate::operations_with_options(multiplicand, multiplier, options, content);
+    generate::sum_title_with_options(multiplicand, multiplier, options, content);
+    generate::long_sum_with_options(multiplicand, multiplier, options, content);
+    generate::bottom_border_with_options(multiplicand, multiplier, options, content);
+}
+
+fn table_hash_key(options: &TableOptions) -> String {
+    // Each field is named and written by hand, so the key does not follow the `Debug` output.
+    let optional = |value: Option<usize>| -> String { value.map_or(String::from("-"), |value| value.to_string()) };
+    // The texts have their length first, so a separator inside a text is not ambiguous.
+    let sized = |text: &str| -> String { format!("{}:{text}", text.len()) };
+
+    let style: &TableStyle = &options.style;
+    let glyphs: String = [
+        style.top_left, style.top_right, style.bottom_left, style.bottom_right, style.outer, style.inner,
+        style.guide, style.heavy, style.light, style.dashed, style.dotted, style.carry_fill,
+        style.heavy_left, style.heavy_right, style.light_left, style.light_right, style.heavy_down,
+        style.heavy_up, style.heavy_cross, style.light_down, style.light_cross, style.diagonal,
+    ].iter().collect();
+    let labels: &Labels = &options.labels;
+    let mut texts: Vec<&str> = vec![labels.position, labels.operations, labels.sum, labels.product, labels.subtotal, labels.symbols];
+    texts.extend(labels.legend.map(|(_, line)| line));
+
+    let fields: Vec<String> = vec![
+        format!("version={TABLE_HASH_VERSION}"),
+        format!("symbols={}", options.symbols),
+        format!("trim_symbols={}", options.trim_symbols),
+        format!("footer={}", match options.footer {
+            Footer::Author => "author",
+            Footer::Stats => "stats",
+            Footer::None => "none",
+        }),
+        format!("product_align={}", match options.product_align {
+            ProductAlign::Left => "left",
+            ProductAlign::Right => "right",
+        }),
+        format!("cell_width={}", options.layout.cell_width),
+        format!("max_width={}", optional(options.layout.max_width)),
+        format!("pad_product_to={}", optional(options.layout.pad_product_to)),
+        format!("title={}", options.title.as_deref().map_or(String::from("-"), sized)),
+        format!("group_guides={}", options.group_guides),
+        format!("product_sig={}", optional(options.product_sig)),
+        format!("rows={}", match options.rows {
+            RowFilter::All => "all",
+            RowFilter::Odd => "odd",
+            RowFilter::Even => "even",
+        }),
+        format!("order={}", match options.order {
+            RowOrder::Lsd => "lsd",
+            RowOrder::Msd => "msd",
+        }),
+        format!("max_rows={}", optional(options.max_rows)),
+        format!("carry_style={}", match options.carry_style {
+            CarryStyle::Row => "row",
+            CarryStyle::Superscript => "superscript",
+        }),
+        format!("top={}", match options.top {
+            TopOperand::Multiplicand => "multiplicand",
+            TopOperand::Multiplier => "multiplier",
+        }),
+        format!("position_header={}", options.position_header),
+        format!("sum_section={}", options.sum_section),
+        format!("sum={}", match options.sum {
+            SumVerbosity::Full => "full",
+            SumVerbosity::Compact => "compact",
+        }),
+        format!("place_values={}", options.place_values),
+        format!("step_count={}", options.step_count),
+        format!("verify={}", options.verify),
+        format!("explain={}", options.explain),
+        format!("radix={}", options.radix),
+        format!("style={}", sized(&glyphs)),
+        format!("signs={},{}", options.signs.0, options.signs.1),
+        format!("decimals={},{}", options.decimals.0, options.decimals.1),
+        format!("optimize_layout={}", options.optimize_layout),
+        format!("digits={}", match options.digits {
+            DigitSet::Ascii => "ascii",
+            DigitSet::ArabicIndic => "arabic",
+        }),
+        format!("labels={}", texts.iter().map(|text| sized(text)).collect::<Vec<String>>().join(",")),
+    ];
+    return fields.join(";");
+}
+
+fn arrange_table(multiplicand: &String, multiplier: &String, options: &TableOptions) -> (String, String, TableOptions) {
+    let longer: bool = get_string_length(multiplier) > get_string_length(multiplicand);
+    // The zero multiplier ends the swaps, like in `00 * 00`.
+    let zero: bool = is_zero(multiplicand) && !is_zero(multiplier) && get_string_length(multiplier) > 1;
+    if (options.optimize_layout && longer) || zero {
+        let options: TableOptions = TableOptions {
+            optimize_layout: false,
+            swapped: true,
+            signs: (options.signs.1, options.signs.0),
+            decimals: (options.decimals.1, options.decimals.0),
+            ..options.clone()
+        };
+        return arrange_table(multiplier, multiplicand, &options);
+    }
+
+    // The cells grow to hold the position numbers of the wide tables.
+    let length: usize = get_strings_length(multiplicand, multiplier);
+    let options: TableOptions = TableOptions {
+        layout: options.layout.fit_positions(length),
+        ..options.clone()
+    };
+    return (multiplicand.clone(), multiplier.clone(), options);
+}
+
+fn times_table_line(multiplicand: &String, multiplier: &String, options: &TableOptions) -> String {
+    let (signed, _): (String, String) = signed_operands(multiplicand, multiplier, options);
+    let product: String = compute_product_with_options(multiplicand, multiplier, options);
+
+    return format!("{signed} × {multiplier} = {product}\n");
+}
+
+fn is_zero(operand: &String) -> bool {
+    return operand.chars().all(|digit| digit == '0');
+}
+
+fn operand_digits(operand: &String) -> Vec<u8> {
+    return operand.chars().filter_map(|character| character.to_digit(10)).map(|digit| digit as u8).collect();
+}
+
+fn model_row(digits: &[u8], offset: usize, columns: usize, kind: CellKind) -> Vec<Cell> {
+    // The last digit is `offset` columns to the left of the units column.
+    let mut row: Vec<Cell> = vec![Cell { value: None, kind }; columns];
+    for (n, digit) in digits.iter().rev().enumerate() {
+        if n + offset >= columns {
+            break;
+        }
+        row[columns - 1 - offset - n].value = Some(*digit);
+    }
+
+    return row;
+}
+
+fn push_model_sums(numbers: &[usize], columns: usize, kind: CellKind, grid: &mut Vec<Vec<Cell>>) {
+    for (column, number) in numbers.iter().enumerate() {
+        let digits: Vec<u8> = operand_digits(&number.to_string());
+        grid.push(model_row(&digits, column, columns, kind));
+    }
+}
+
+fn indent_lines(text: &String, spaces: usize) -> String {
+    let indentation: String = " ".repeat(spaces);
+
+    let mut indented: String = String::with_capacity(text.len());
+    for line in text.lines() {
+        indented.push_str(&indentation);
+        indented.push_str(line);
+        indented.push('\n');
+    }
+
+    return indented;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufWriter, Read};
+
+    use super::*;
+    use crate::options::{COMPACT_CELL_WIDTH, CarryStyle, ColorScheme, DigitSet, LayoutConfig, ProductAlign, RowFilter, RowOrder, SumVerbosity};
+
+    // # -----------------------------------------------------------------------
+    // # Function: get table
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_product_one_digits() {
+        // Arrange
+        let multiplicand: String = String::from("3");
+        let multiplier: String = String::from("2");
+        let expected: &str = "Symbols\n\
+                              =======\n\
+                              Pos. = Position.\n\
+                              Ops. = Operations of the long multiplication.\n\
+                              Sum. = Sum of each column of the multiplication.\n\
+                              Sub n. = Subtotal of the last sum.\n\
+                              Pro. = Product of the multiplication.\n\
+                              n ^ = Carry-over.\n\
+                              n R = The row number.\n\
+                              n C = The column number of the sum of the rows.\n\
+                              * Replace 'n' for a number.\n\
+                              P = The product of multiplication.\n\
+                              \n\
+                              ┏━━━━━━━┓\n\
+                              ┃Pos.   ┃\n\
+                              ┠┄┄┄┬┄┄┄┨\n\
+                              ┃ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Ops.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃   │ 3 ┃\n\
+                              ┃ x │ 2 ┃\n\
+                              ┣━━━┿━━━┫\n\
+                              ┃ 0 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 6 ┃ 1 R\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Sum.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃   │ 6 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   ┃ 2 C\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Pro.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃ 0 │ 6 ┃ P\n\
+                              ┗━━━┷━━━┛\n\
+                              \n\
+                              ---\n\
+                              Author: Israel Roldan\n\
+                              E-mail: israel.alberto.rv@gmail.com\n\
+                              License: GPL-3.0\n\
+                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+
+        // Action
+        let text: String = get_table(&multiplicand, &multiplier).unwrap();
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_get_table_product_two_digits() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let expected: &str = "Symbols\n\
+                              =======\n\
+                              Pos. = Position.\n\
+                              Ops. = Operations of the long multiplication.\n\
+                              Sum. = Sum of each column of the multiplication.\n\
+                              Sub n. = Subtotal of the last sum.\n\
+                              Pro. = Product of the multiplication.\n\
+                              n ^ = Carry-over.\n\
+                              n R = The row number.\n\
+                              n C = The column number of the sum of the rows.\n\
+                              * Replace 'n' for a number.\n\
+                              P = The product of multiplication.\n\
+                              \n\
+                              ┏━━━━━━━┓\n\
+                              ┃Pos.   ┃\n\
+                              ┠┄┄┄┬┄┄┄┨\n\
+                              ┃ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Ops.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃   │ 5 ┃\n\
+                              ┃ x │ 7 ┃\n\
+                              ┣━━━┿━━━┫\n\
+                              ┃ 3 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 5 ┃ 1 R\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Sum.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃   │ 5 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┨\n\
+                              ┃ 3 │   ┃ 2 C\n\
+                              ┣━━━┷━━━┫\n\
+                              ┃Pro.   ┃\n\
+                              ┣━━━┯━━━┫\n\
+                              ┃ 3 │ 5 ┃ P\n\
+                              ┗━━━┷━━━┛\n\
+                              \n\
+                              ---\n\
+                              Author: Israel Roldan\n\
+                              E-mail: israel.alberto.rv@gmail.com\n\
+                              License: GPL-3.0\n\
+                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+
+        // Action
+        let text: String = get_table(&multiplicand, &multiplier).unwrap();
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_get_table_product_nine_digits() {
+        // Arrange
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
+        let expected: &str = "Symbols\n\
+                              =======\n\
+                              Pos. = Position.\n\
+                              Ops. = Operations of the long multiplication.\n\
+                              Sum. = Sum of each column of the multiplication.\n\
+                              Sub n. = Subtotal of the last sum.\n\
+                              Pro. = Product of the multiplication.\n\
+                              n ^ = Carry-over.\n\
+                              n R = The row number.\n\
+                              n C = The column number of the sum of the rows.\n\
+                              * Replace 'n' for a number.\n\
+                              P = The product of multiplication.\n\
+                              \n\
+                              ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓\n\
+                              ┃Pos.                               ┃\n\
+                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
+                              ┃ 9 │ 8 │ 7 │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Ops.                               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃   │   │   │   │ 1 │ 3 │ 5 │ 9 │ 7 ┃\n\
+                              ┃ x │   │   │   │   │ 8 │ 6 │ 4 │ 2 ┃\n\
+                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n\
+                              ┃   │   │   │ 0 │ 0 │ 1 │ 1 │ 1 │   ┃ 1 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 2 │ 6 │ 0 │ 8 │ 4 ┃ 1 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │   │ 0 │ 1 │ 2 │ 3 │ 2 │   │   ┃ 2 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 4 │ 2 │ 0 │ 6 │ 8 │   ┃ 2 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃   │ 0 │ 1 │ 3 │ 5 │ 4 │   │   │   ┃ 3 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 6 │ 8 │ 0 │ 4 │ 2 │   │   ┃ 3 R\n\
+                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                              ┃ 0 │ 2 │ 4 │ 7 │ 5 │   │   │   │   ┃ 4 ^\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 8 │ 4 │ 0 │ 2 │ 6 │   │   │   ┃ 4 R\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Sum.                               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃   │   │   │   │   │   │   │   │ 4 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 1 │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 1 │ 1 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │ 2 │ 4 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 1 │ 8 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 2 │ 3 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 1 │ 5 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 1 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 0 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Sub 1.                             ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃   │   │   │   │   │   │   │   │ 4 ┃ 1 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │   │ 2 │   │   ┃ 3 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │   │   │ 5 │   │   │   ┃ 4 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 1 │ 0 │   │   │   │   ┃ 5 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃   │ 1 │   │   │   │   │   │   │   ┃ 8 C\n\
+                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
+                              ┃ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
+                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
+                              ┃Pro.                               ┃\n\
+                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                              ┃ 1 │ 1 │ 7 │ 5 │ 0 │ 5 │ 2 │ 7 │ 4 ┃ P\n\
+                              ┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n\
+                              \n\
+                              ---\n\
+                              Author: Israel Roldan\n\
+                              E-mail: israel.alberto.rv@gmail.com\n\
+                              License: GPL-3.0\n\
+                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+
+        // Action
+        let text: String = get_table(&multiplicand, &multiplier).unwrap();
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_get_table_with_options_title() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let options: TableOptions = TableOptions { title: Some(String::from("Warm-up")), ..TableOptions::default() };
+        let expected: &str = "P = The product of multiplication.\n\
+                              \n \
+                              Warm-up\n\
+                              ┏━━━━━━━┓\n\
+                              ┃Pos.   ┃\n";
+
+        // Action
+        let text: String = get_table_with_options(&multiplicand, &multiplier, &options);
+
+        // Assert
+        assert!(text.contains(expected));
+        assert!(text.contains("┃ 3 │ 5 ┃ P\n┗━━━┷━━━┛\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_product_sig() {
+        // Arrange
+        let multiplicand: String = String::from("13597");
+        let multiplier: String = String::from("8642");
+        let options: TableOptions = TableOptions { product_sig: Some(3), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&multiplicand, &multiplier, &options);
+
+        // Assert
+        assert!(text.contains("┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n117…(9 digits)\n\n---\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_explain_before_the_sum() {
+        // Arrange
+        let options: TableOptions = TableOptions { explain: true, max_rows: Some(1), ..TableOptions::default() };
+        let expected: &str = "┣━━━┷━━━┷━━━┷━━━┫\n\
+                              row 1: 3×6=18 (unit 8, carry 1)\n\
+                              row 1: 1×6=6 (unit 6, carry 0)\n\
+                              ┃Sum.           ┃\n";
+
+        // Action
+        let text: String = get_table_with_options(&String::from("13"), &String::from("26"), &options);
+
+        // Assert
+        assert!(text.contains(expected));
+        assert!(!text.contains("row 2:"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_pad_product_to_aligns_the_right_border() {
+        // Arrange
+        let layout: LayoutConfig = LayoutConfig { pad_product_to: Some(5), ..LayoutConfig::default() };
+        let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+
+        // Assert
+        // The right border of every line is below the corner of the top border.
+        let corner: usize = text.lines().find(|line| line.starts_with('┏')).unwrap().chars().count() - 1;
+        assert_eq!(5 * 4, corner);
+        for line in text.lines().filter(|line| line.starts_with(['┏', '┃', '┠', '┣', '┗'])) {
+            let right_border: Option<char> = line.chars().nth(corner);
+            assert!(right_border.is_some_and(|character| "┓┃┨┫┛".contains(character)), "{line}");
+        }
+        assert!(text.contains("┃   │   │   │ 3 │   ┃ 2 C\n┣━━━┷━━━┷━━━┷━━━┷━━━┫\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_max_width_cuts_the_lines() {
+        // Arrange
+        let layout: LayoutConfig = LayoutConfig { max_width: Some(9), ..LayoutConfig::default() };
+        let options: TableOptions = TableOptions { layout, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+
+        // Assert
+        assert!(text.lines().all(|line| line.chars().count() <= 9));
+        assert!(text.contains("┃ 3 │ 5 ┃\n┗━━━┷━━━┛\n"));
+        assert!(text.starts_with("Symbols\n=======\n"));
+    }
+
+    fn assert_zero_table(text: &str, product: &str) {
+        // The right border of every line is below the corner of the top border.
+        let corner: usize = text.lines().find(|line| line.starts_with('┏')).unwrap().chars().count() - 1;
+        for line in text.lines().filter(|line| line.starts_with(['┏', '┃', '┠', '┣', '┗'])) {
+            let right_border: Option<char> = line.chars().nth(corner);
+            assert!(right_border.is_some_and(|character| "┓┃┨┫┛".contains(character)), "{line}");
+        }
+        assert_eq!(1, text.matches(" R\n").count());
+        assert!(text.contains(&format!("{product} P\n")));
+    }
+
+    #[test]
+    fn test_get_table_zero_times_zero() {
+        // Arrange
+        let expected: &str = "┃ 0 │ 0 ┃";
+
+        // Action
+        let text: String = get_table("0", "0").unwrap();
+
+        // Assert
+        assert_zero_table(&text, expected);
+    }
+
+    #[test]
+    fn test_get_table_zero_times_999() {
+        // Arrange
+        let expected: &str = "┃ 0 │ 0 │ 0 │ 0 ┃";
+
+        // Action
+        let text: String = get_table("0", "999").unwrap();
+
+        // Assert
+        assert_zero_table(&text, expected);
+        assert!(text.contains("(swapped: 0 x 999)"));
+    }
+
+    #[test]
+    fn test_get_table_999_times_zero() {
+        // Arrange
+        let expected: &str = "┃ 0 │ 0 │ 0 │ 0 ┃";
+
+        // Action
+        let text: String = get_table("999", "0").unwrap();
+
+        // Assert
+        assert_zero_table(&text, expected);
+    }
+
+    #[test]
+    fn test_get_table_with_options_max_rows() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("123456789");
+        let options: TableOptions = TableOptions { max_rows: Some(4), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&multiplicand, &multiplier, &options);
+
+        // Assert
+        assert!(text.contains(" 4 R\n"));
+        assert!(!text.contains(" 5 R\n"));
+        assert!(text.contains("┃… (5 more rows)                        ┃\n"));
+        assert!(text.contains("┃ 0 │ 8 │ 6 │ 4 │ 1 │ 9 │ 7 │ 5 │ 2 │ 3 ┃ P\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_chain
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_chain_three_operands() {
+        // Arrange
+        let operands: Vec<String> = vec![String::from("2"), String::from("3"), String::from("4")];
+
+        // Action
+        let text: String = get_table_chain(&operands);
+
+        // Assert
+        assert_eq!(2, text.matches("┃Pos.").count());
+        assert!(text.contains("Step 1: 2 × 3 = 6\n"));
+        assert!(text.contains("Step 2: 6 × 4 = 24\n"));
+        assert!(text.contains("┃ 2 │ 4 ┃ P\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: compare
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_compare_same_width() {
+        // Arrange
+        let multiplicand_a: String = String::from("12");
+        let multiplier_a: String = String::from("10");
+        let multiplicand_b: String = String::from("12");
+        let multiplier_b: String = String::from("11");
+
+        // Action
+        let text: String = compare(&multiplicand_a, &multiplier_a, &multiplicand_b, &multiplier_b);
+
+        // Assert
+        assert!(text.starts_with("Symbols\n"));
+        assert!(text.contains("12 x 10 = 120\n┏━━━━━━━━━━━━━━━┓\n"));
+        assert!(text.contains("┃ 0 │ 1 │ 2 │ 0 ┃ P\n┗━━━┷━━━┷━━━┷━━━┛\n\nvs.\n\n12 x 11 = 132\n"));
+        assert!(text.contains("┃ 0 │ 1 │ 3 │ 2 ┃ P\n┗━━━┷━━━┷━━━┷━━━┛\n\n---\n"));
+        assert_eq!(1, text.matches("Symbols\n").count());
+        assert_eq!(1, text.matches("Author: ").count());
+    }
+
+    #[test]
+    fn test_compare_aligns_the_narrower_table() {
+        // Arrange
+        let multiplicand_a: String = String::from("5");
+        let multiplier_a: String = String::from("7");
+        let multiplicand_b: String = String::from("50");
+        let multiplier_b: String = String::from("7");
+
+        // Action
+        let text: String = compare(&multiplicand_a, &multiplier_a, &multiplicand_b, &multiplier_b);
+
+        // Assert
+        assert!(text.contains("5 x 7 = 35\n    ┏━━━━━━━┓\n"));
+        assert!(text.contains("    ┃ 3 │ 5 ┃ P\n    ┗━━━┷━━━┛\n"));
+        assert!(text.contains("50 x 7 = 350\n┏━━━━━━━━━━━┓\n"));
+        assert!(text.contains("┃ 3 │ 5 │ 0 ┃ P\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_with_options_no_symbols() {
+        // Arrange
+        let options: TableOptions = TableOptions { symbols: false, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+
+        // Assert
+        assert!(text.starts_with('┏'));
+        assert!(!text.contains("Symbols"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_arabic_digits() {
+        // Arrange
+        let options: TableOptions = TableOptions { digits: DigitSet::ArabicIndic, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+
+        // Assert
+        assert!(text.contains("┃Ops.   ┃\n┣━━━┯━━━┫\n┃   │ ٥ ┃\n┃ x │ ٧ ┃\n"));
+        assert!(text.contains("┃ ٣ │ ٥ ┃ P\n"));
+        assert!(!text.contains("┃   │ 5 ┃"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_order_keeps_the_product() {
+        // Arrange
+        let lsd: TableOptions = TableOptions { order: RowOrder::Lsd, ..TableOptions::default() };
+        let msd: TableOptions = TableOptions { order: RowOrder::Msd, ..TableOptions::default() };
+
+        // Action
+        let text_lsd: String = get_table_with_options(&String::from("123"), &String::from("45"), &lsd);
+        let text_msd: String = get_table_with_options(&String::from("123"), &String::from("45"), &msd);
+
+        // Assert
+        assert_ne!(text_lsd, text_msd);
+        assert_eq!(2, text_msd.matches(" R\n").count());
+        assert!(text_lsd.contains("┃ 0 │ 5 │ 5 │ 3 │ 5 ┃ P\n"));
+        assert!(text_msd.contains("┃ 0 │ 5 │ 5 │ 3 │ 5 ┃ P\n"));
+        assert_eq!(text_lsd.split("┃Sum.").nth(1), text_msd.split("┃Sum.").nth(1));
+    }
+
+    #[test]
+    fn test_get_table_with_options_compact_sum() {
+        // Arrange
+        let full: TableOptions = TableOptions { footer: Footer::None, ..TableOptions::default() };
+        let compact: TableOptions = TableOptions { sum: SumVerbosity::Compact, ..full.clone() };
+
+        // Action
+        let text_full: String = get_table_with_options(&String::from("357"), &String::from("246802468"), &full);
+        let text_compact: String = get_table_with_options(&String::from("357"), &String::from("246802468"), &compact);
+
+        // Assert
+        let sum_lines = |text: &String| -> usize { text.lines().skip_while(|line| !line.starts_with("┃Sum.")).take_while(|line| !line.starts_with("┃Pro.")).count() };
+        assert!(sum_lines(&text_compact) * 4 < sum_lines(&text_full));
+        assert!(!text_compact.contains(" C\n"));
+        let product: &str = "┃Pro.                                           ┃\n\
+                             ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
+                             ┃ 0 │ 8 │ 8 │ 1 │ 0 │ 8 │ 4 │ 8 │ 1 │ 0 │ 7 │ 6 ┃ P\n\
+                             ┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n";
+        assert!(text_full.ends_with(product));
+        assert!(text_compact.ends_with(product));
+    }
+
+    #[test]
+    fn test_get_table_with_options_trim_symbols() {
+        // Arrange
+        let options: TableOptions = TableOptions { trim_symbols: true, ..TableOptions::default() };
+
+        // Action
+        let short: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+        let long: String = get_table_with_options(&String::from("99999"), &String::from("99999"), &options);
+
+        // Assert
+        assert!(short.starts_with("Symbols\n=======\nPos. = Position.\n"));
+        assert!(!short.contains("Sub n."));
+        assert!(short.contains("P = The product of multiplication.\n\n┏"));
+        assert!(long.contains("Sub n. = Subtotal of the last sum.\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_no_author() {
+        // Arrange
+        let options: TableOptions = TableOptions { footer: Footer::None, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("5"), &String::from("7"), &options);
+
+        // Assert
+        assert!(text.ends_with("┛\n"));
+        assert!(!text.contains("Author"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_compact_is_narrower() {
+        // Arrange
+        let normal: TableOptions = TableOptions { symbols: false, footer: Footer::None, ..TableOptions::default() };
+        let layout: LayoutConfig = LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() };
+        let compact: TableOptions = TableOptions { layout, ..normal.clone() };
+
+        // Action
+        let normal: String = get_table_with_options(&String::from("99"), &String::from("99"), &normal);
+        let compact: String = get_table_with_options(&String::from("99"), &String::from("99"), &compact);
+
+        // Assert
+        let width = |text: &String| text.lines().next().unwrap().chars().count();
+        assert_eq!(17, width(&normal));
+        assert_eq!(9, width(&compact));
+        assert_eq!(normal.lines().count(), compact.lines().count());
+        assert!(compact.contains("┃9│8│0│1┃ P\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_optimize_layout_draws_fewer_rows() {
+        // Arrange
+        let multiplicand: String = String::from("7");
+        let multiplier: String = String::from("9876543210123");
+        let options: TableOptions = TableOptions { optimize_layout: true, ..TableOptions::default() };
+
+        // Action
+        let natural: String = get_table_with_options(&multiplicand, &multiplier, &TableOptions::default());
+        let optimized: String = get_table_with_options(&multiplicand, &multiplier, &options);
+
+        // Assert
+        let product = |text: &String| text.lines().find(|line| line.ends_with(" P")).unwrap().to_string();
+        let rows = |text: &String| text.lines().filter(|line| line.ends_with(" R")).count();
+        assert_eq!(product(&natural), product(&optimized));
+        assert_eq!(13, rows(&natural));
+        assert_eq!(1, rows(&optimized));
+        assert!(optimized.contains("┃ (swapped: 7 x 9876543210123)\n"));
+    }
+
+    #[test]
+    fn test_get_table_with_options_optimize_layout_keeps_the_order() {
+        // Arrange
+        let options: TableOptions = TableOptions { optimize_layout: true, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_with_options(&String::from("9876543210123"), &String::from("7"), &options);
+
+        // Assert
+        assert_eq!(get_table("9876543210123", "7").unwrap(), text);
+    }
+
+    #[test]
+    fn test_get_table_leading_zeros() {
+        // Arrange
+        let expected: String = get_table("7", "3").unwrap();
+
+        // Action
+        let text: String = get_table("007", "3").unwrap();
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: write_table
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_write_table_into_a_buffered_writer() {
+        // Arrange
+        let expected: String = get_table("13597", "8642").unwrap();
+        let mut out: BufWriter<Vec<u8>> = BufWriter::new(Vec::new());
+
+        // Action
+        let result: Result<(), MultiplicationError> = write_table("13597", "8642", &mut out);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+        assert_eq!(expected.as_bytes(), out.get_ref().as_slice());
+    }
+
+    #[test]
+    fn test_write_table_invalid_operand() {
+        // Arrange
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        let result: Result<(), MultiplicationError> = write_table("12x", "3", &mut out);
+
+        // Assert
+        assert_eq!(Err(MultiplicationError::NonDigitCharacter('x')), result);
+        assert!(out.is_empty());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: write_table_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_write_table_with_options_drops_the_border_between_sections() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let options: TableOptions = TableOptions { sum_section: false, ..TableOptions::default() };
+        let mut expected: String = get_table_as(&multiplicand, &multiplier, &Text { options: options.clone() });
+        generate::dedupe_borders(&mut expected);
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        write_table_with_options(&multiplicand, &multiplier, &options, &mut out).unwrap();
+
+        // Assert
+        assert_eq!(expected.as_bytes(), out.as_slice());
+        assert!(!String::from_utf8(out).unwrap().contains("┣━━━┷━━━┷━━━┷━━━┫\n┣━━━┷━━━┷━━━┷━━━┫\n"));
+    }
+
+    #[test]
+    fn test_write_table_with_options_widens_the_cells_for_one_hundred_columns() {
+        // Arrange
+        let multiplicand: String = "9".repeat(50);
+        let multiplier: String = "8".repeat(50);
+        let options: TableOptions = TableOptions { symbols: false, footer: Footer::None, ..TableOptions::default() };
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        write_table_with_options(&multiplicand, &multiplier, &options, &mut out).unwrap();
+
+        // Assert
+        let table: String = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        let position: usize = lines.iter().position(|line| line.starts_with("┃ 100│ 99 │")).unwrap();
+        let border: usize = lines[0].chars().count();
+        assert_eq!(border, lines[position].chars().count());
+        assert_eq!(border, lines[position - 1].chars().count());
+        assert_eq!(border, lines[position + 1].chars().count());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: table_lines
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_table_lines_join_back_to_the_table() {
+        // Arrange
+        let expected: String = get_table("25", "3").unwrap();
+
+        // Action
+        let lines: Vec<String> = table_lines("25", "3").collect();
+
+        // Assert
+        assert_eq!(expected, format!("{}\n", lines.join("\n")));
+    }
+
+    #[test]
+    fn test_table_lines_with_invalid_operand() {
+        // Arrange
+        let multiplicand: &str = "2x";
+
+        // Action
+        let lines: Vec<String> = table_lines(multiplicand, "3").collect();
+
+        // Assert
+        assert!(lines.is_empty());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: table_dimensions
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_table_dimensions_with_two_digits() {
+        // Arrange
+        let table: String = get_table("5", "7").unwrap();
+        let columns: usize = table.lines().map(|line| line.chars().count()).max().unwrap();
+        let expected: (usize, usize) = (table.lines().count(), columns);
+
+        // Action
+        let dimensions: (usize, usize) = table_dimensions("5", "7");
+
+        // Assert
+        assert_eq!(expected, dimensions);
+    }
+
+    #[test]
+    fn test_table_dimensions_with_nine_digits() {
+        // Arrange
+        let table: String = get_table("13597", "8642").unwrap();
+        let columns: usize = table.lines().map(|line| line.chars().count()).max().unwrap();
+        let expected: (usize, usize) = (table.lines().count(), columns);
+
+        // Action
+        let dimensions: (usize, usize) = table_dimensions("13597", "8642");
+
+        // Assert
+        assert_eq!(expected, dimensions);
+    }
+
+    #[test]
+    fn test_table_dimensions_with_invalid_operand() {
+        // Arrange
+        let expected: (usize, usize) = (0, 0);
+
+        // Action
+        let dimensions: (usize, usize) = table_dimensions("12a", "3");
+
+        // Assert
+        assert_eq!(expected, dimensions);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: table_dimensions_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_table_dimensions_with_options_match_the_table() {
+        // Arrange
+        let operands: [(&str, &str); 5] = [("5", "7"), ("12345", "678"), ("99", "99999"), ("0", "407"), ("98765432109876", "99")];
+        let layouts: [LayoutConfig; 3] = [
+            LayoutConfig { cell_width: COMPACT_CELL_WIDTH, ..LayoutConfig::default() },
+            LayoutConfig { pad_product_to: Some(20), ..LayoutConfig::default() },
+            LayoutConfig { max_width: Some(72), ..LayoutConfig::default() },
+        ];
+        let mut options: Vec<TableOptions> = vec![
+            TableOptions { symbols: false, sum: SumVerbosity::Compact, footer: Footer::None, ..TableOptions::default() },
+            TableOptions { trim_symbols: true, place_values: true, title: Some(String::from("Title")), ..TableOptions::default() },
+            TableOptions { rows: RowFilter::Even, max_rows: Some(1), explain: true, footer: Footer::Stats, ..TableOptions::default() },
+            TableOptions { carry_style: CarryStyle::Superscript, sum_section: false, step_count: true, verify: true, ..TableOptions::default() },
+            TableOptions { position_header: false, sum_section: false, product_sig: Some(3), optimize_layout: true, ..TableOptions::default() },
+            TableOptions { signs: (true, false), decimals: (2, 1), product_align: ProductAlign::Left, ..TableOptions::default() },
+            TableOptions { order: RowOrder::Msd, style: TableStyle::ascii(), colors: Some(ColorScheme::default()), ..TableOptions::default() },
+        ];
+        options.extend(layouts.map(|layout| TableOptions { layout, explain: true, ..TableOptions::default() }));
+
+        for options in &options {
+            for (multiplicand, multiplier) in operands {
+                let colorless: TableOptions = TableOptions { colors: None, ..options.clone() };
+                let table: String = get_table_with_options(&String::from(multiplicand), &String::from(multiplier), &colorless);
+                let columns: usize = table.lines().map(|line| line.chars().count()).max().unwrap();
+                let expected: (usize, usize) = (table.lines().count(), columns);
+
+                // Action
+                let dimensions: (usize, usize) = table_dimensions_with_options(multiplicand, multiplier, options);
+
+                // Assert
+                assert_eq!(expected, dimensions, "{multiplicand} x {multiplier} with {options:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_dimensions_with_options_base_sixteen() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, explain: true, ..TableOptions::default() };
+        let table: String = get_table_with_options(&String::from("FF"), &String::from("AB"), &options);
+        let columns: usize = table.lines().map(|line| line.chars().count()).max().unwrap();
+        let expected: (usize, usize) = (table.lines().count(), columns);
+
+        // Action
+        let dimensions: (usize, usize) = table_dimensions_with_options("FF", "AB", &options);
+
+        // Assert
+        assert_eq!(expected, dimensions);
+    }
+
+    #[test]
+    fn test_write_table_with_options_without_colors() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let options: TableOptions = TableOptions { colors: None, ..TableOptions::default() };
+        let expected: String = get_table("13", "26").unwrap();
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        write_table_with_options(&multiplicand, &multiplier, &options, &mut out).unwrap();
+
+        // Assert
+        assert_eq!(expected.as_bytes(), out.as_slice());
+    }
+
+    #[test]
+    fn test_write_table_with_options_with_colors() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+        let options: TableOptions = TableOptions { colors: Some(ColorScheme::default()), ..TableOptions::default() };
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        write_table_with_options(&multiplicand, &multiplier, &options, &mut out).unwrap();
+
+        // Assert
+        let table: String = String::from_utf8(out).unwrap();
+        let product: &str = table.lines().find(|line| line.ends_with(" P")).unwrap();
+        assert!(product.contains("\x1b[1;32m3\x1b[0m \x1b[2m│\x1b[0m \x1b[1;32m8\x1b[0m"));
+        assert_eq!(get_table("13", "26").unwrap(), generate::strip_ansi(&table));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_with_style
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_with_style_unicode_is_default() {
+        // Arrange
+        let operands: [(&str, &str); 4] = [("5", "7"), ("951", "46"), ("957", "46"), ("123456789", "987654321")];
+
+        for (multiplicand, multiplier) in operands {
+            // Action
+            let text: String = get_table_with_style(multiplicand, multiplier, &TableStyle::unicode()).unwrap();
+
+            // Assert
+            assert_eq!(get_table(multiplicand, multiplier).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_get_table_with_style_ascii() {
+        // Arrange
+        let style: TableStyle = TableStyle::ascii();
+
+        // Action
+        let text: String = get_table_with_style("957", "46", &style).unwrap();
+
+        // Assert
+        assert!(text.is_ascii());
+        assert!(text.contains("|   |   | 9 | 5 | 7 |\n| x |   |   | 4 | 6 |\n+---+---+---+---+---+\n"));
+        assert!(text.contains("| 4 | 4 | 0 | 2 | 2 | P\n+---+---+---+---+---+\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: validate
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_empty_operand() {
+        // Arrange
+        let multiplicand: &str = "";
+
+        // Action
+        let table: Result<String, MultiplicationError> = get_table(multiplicand, "7");
+
+        // Assert
+        assert_eq!(Err(MultiplicationError::EmptyOperand), table);
+    }
+
+    #[test]
+    fn test_get_table_non_digit_character() {
+        // Arrange
+        let multiplier: &str = "7x";
+
+        // Action
+        let table: Result<String, MultiplicationError> = get_table("5", multiplier);
+
+        // Assert
+        assert_eq!(Err(MultiplicationError::NonDigitCharacter('x')), table);
+        assert_eq!("ERROR: the operands must contain only digits, found 'x'.", table.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_get_table_at_the_digit_limit() {
+        // Arrange
+        let multiplicand: String = "9".repeat(MAX_DIGITS);
+
+        // Action
+        let table: Result<String, MultiplicationError> = get_table(&multiplicand, "7");
+
+        // Assert
+        assert!(table.is_ok());
+    }
+
+    #[test]
+    fn test_get_table_over_the_digit_limit() {
+        // Arrange
+        let multiplicand: String = "9".repeat(1200);
+
+        // Action
+        let table: Result<String, MultiplicationError> = get_table(&multiplicand, "7");
+
+        // Assert
+        assert_eq!(Err(MultiplicationError::TooManyDigits { limit: 500, digits: 1200 }), table);
+        assert_eq!("ERROR: operand exceeds 500 digits (got 1200).", table.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_validate_with_options_raised_limit() {
+        // Arrange
+        let multiplicand: String = "9".repeat(1200);
+        let options: TableOptions = TableOptions { max_digits: 2000, ..TableOptions::default() };
+
+        // Action
+        let result: Result<(), MultiplicationError> = validate_with_options(&multiplicand, "7", &options);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_validate_digits() {
+        // Arrange
+        let multiplicand: &str = "13597";
+        let multiplier: &str = "8642";
+
+        // Action
+        let result: Result<(), MultiplicationError> = validate(multiplicand, multiplier);
+
+        // Assert
+        assert_eq!(Ok(()), result);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: table_hash
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_table_hash_is_stable_and_ordered() {
+        // Arrange
+        let expected: u64 = table_hash("13", "26");
+
+        // Action
+        let hash: u64 = table_hash("13", "26");
+
+        // Assert
+        assert_eq!(expected, hash);
+        assert_ne!(hash, table_hash("26", "13"));
+        assert_ne!(table_hash("1", "23"), table_hash("12", "3"));
+    }
+
+    #[test]
+    fn test_table_hash_is_pinned() {
+        // Arrange
+        // The key of the default options only changes with `TABLE_HASH_VERSION`.
+        let expected: u64 = 0x833a_0515_ba44_cea9;
+
+        // Action
+        let hash: u64 = table_hash("13", "26");
+
+        // Assert
+        assert_eq!(expected, hash);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: table_hash_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_table_hash_with_options_ignores_the_display_state() {
+        // Arrange
+        let options: TableOptions = TableOptions {
+            swapped: true,
+            colors: Some(ColorScheme { highlight_column: Some(2), ..ColorScheme::default() }),
+            max_digits: 20,
+            ..TableOptions::default()
+        };
+
+        // Action
+        let hash: u64 = table_hash_with_options("13", "26", &options);
+
+        // Assert
+        assert_eq!(table_hash("13", "26"), hash);
+    }
+
+    #[test]
+    fn test_table_hash_with_options_follows_the_drawing() {
+        // Arrange
+        let changes: [TableOptions; 4] = [
+            TableOptions { title: Some(String::from("Title")), ..TableOptions::default() },
+            TableOptions { rows: RowFilter::Odd, ..TableOptions::default() },
+            TableOptions { labels: Labels::spanish(), ..TableOptions::default() },
+            TableOptions { layout: LayoutConfig { max_width: Some(40), ..LayoutConfig::default() }, ..TableOptions::default() },
+        ];
+
+        for options in &changes {
+            // Action
+            let hash: u64 = table_hash_with_options("13", "26", options);
+
+            // Assert
+            assert_ne!(table_hash("13", "26"), hash, "{options:?}");
+        }
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: build_model
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_build_model_product_row() {
+        // Arrange
+        let expected: Vec<Cell> = vec![
+            Cell { value: Some(0), kind: CellKind::Product },
+            Cell { value: Some(3), kind: CellKind::Product },
+            Cell { value: Some(3), kind: CellKind::Product },
+            Cell { value: Some(8), kind: CellKind::Product },
+        ];
+
+        // Action
+        let model: TableModel = build_model("13", "26");
+
+        // Assert
+        assert_eq!(expected, model.product());
+        assert_eq!(Some(String::from("P")), model.labels[model.labels.len() - 1]);
+    }
+
+    #[test]
+    fn test_build_model_matches_the_rows_of_the_table() {
+        // Arrange
+        let table: String = get_table("13", "26").unwrap();
+
+        // Action
+        let model: TableModel = build_model("13", "26");
+
+        // Assert
+        assert_eq!(model.grid.len(), model.labels.len());
+        assert!(model.grid.iter().all(|row| row.len() == 4));
+        for (row, label) in model.grid.iter().zip(model.labels.iter()) {
+            let Some(label) = label else { continue };
+            let cells: Vec<String> = row.iter().map(|cell| match cell.value {
+                Some(digit) => format!(" {digit} "),
+                None => String::from("   "),
+            }).collect();
+            let line: String = format!("┃{}┃ {label}", cells.join("│"));
+            assert!(table.contains(&line), "{line}");
+        }
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_forty_digits_product_row() {
+        // Arrange
+        let multiplicand: &str = "1234567890123456789012345678901234567890";
+        let multiplier: &str = "9876543210987654321098765432109876543210";
+        let product: &str = "12193263113702179522618503273386678859448712086533622923332237463801111263526900";
+
+        // Action
+        let text: String = get_table(multiplicand, multiplier).unwrap();
+        let row: String = text.lines().find(|line| line.ends_with(" P")).unwrap().to_string();
+        let digits: String = row.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        // Assert
+        assert_eq!(product, digits);
+        assert_eq!(product, compute_product(multiplicand, multiplier));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_practice_sheet
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_practice_sheet_with_random_pairs() {
+        // Arrange
+        let pairs: Vec<(String, String)> = crate::random::random_pairs(3, 2, 4, 42);
+
+        // Action
+        let text_a: String = get_practice_sheet(&pairs, &TableOptions::default());
+        let text_b: String = get_practice_sheet(&crate::random::random_pairs(3, 2, 4, 42), &TableOptions::default());
+
+        // Assert
+        assert_eq!(text_a, text_b);
+        assert_eq!(4, text_a.matches(" P\n").count());
+        assert_eq!(1, text_a.matches("Symbols\n").count());
+        assert!(text_a.contains("4. "));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_tables_batch_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_tables_batch_with_options_ascii() {
+        // Arrange
+        let pairs: Vec<(String, String)> = vec![(String::from("5"), String::from("7")), (String::from("13"), String::from("26"))];
+        let options: TableOptions = TableOptions { style: TableStyle::ascii(), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_tables_batch_with_options(&pairs, &options);
+
+        // Assert
+        assert!(text.is_ascii());
+        assert_eq!(2, text.matches(" P\n").count());
+        assert_eq!(1, text.matches("\u{c}\n").count());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: times_table
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_times_table_seven() {
+        // Arrange
+        let count: usize = 3;
+
+        // Action
+        let text: String = times_table("7", count);
+
+        // Assert
+        assert!(text.contains("7 × 3 = 21"));
+        assert_eq!(3, text.lines().count());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: times_table_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_times_table_with_options_negative() {
+        // Arrange
+        let options: TableOptions = TableOptions { signs: (true, false), ..TableOptions::default() };
+        let expected: &str = "-7 × 1 = -7\n-7 × 2 = -14\n";
+
+        // Action
+        let text: String = times_table_with_options("7", 2, &options);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_times_table_with_grids_matches_list() {
+        // Arrange
+        let list: String = times_table("12", 4);
+
+        // Action
+        let text: String = times_table_with_grids("12", 4, &TableOptions::default());
+
+        // Assert
+        for line in list.lines() {
+            assert!(text.contains(&format!("{line}\n┏")));
+        }
+        assert_eq!(4, text.matches(" P\n").count());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: wrap_code_fence
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_wrap_code_fence_table() {
+        // Arrange
+        let table: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+
+        // Action
+        let text: String = wrap_code_fence(&table, "text");
+
+        // Assert
+        assert!(text.starts_with("```text\nSymbols\n"));
+        assert!(text.ends_with("long-multiplication-calculator\n```\n"));
+        assert_eq!(format!("```text\n{table}```\n"), text);
+    }
+
+    #[test]
+    fn test_wrap_code_fence_with_backticks() {
+        // Arrange
+        let content: String = String::from("a ```` b\n");
+
+        // Action
+        let text: String = wrap_code_fence(&content, "text");
+
+        // Assert
+        assert_eq!("`````text\na ```` b\n`````\n", text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: step_count
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_step_count_thirteen_by_twenty_six() {
+        // Arrange
+        let expected: usize = 11;
+
+        // Action
+        let steps: usize = step_count("13", "26");
+
+        // Assert
+        assert_eq!(expected, steps);
+        assert_eq!(steps, step_count("13", "26"));
+    }
+
+    #[test]
+    fn test_step_count_without_carries() {
+        // Arrange
+        let expected: usize = 9;
+
+        // Action
+        let steps: usize = step_count("11", "11");
+
+        // Assert
+        assert_eq!(expected, steps);
+        assert!(steps < step_count("99", "99"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: step_count_with_radix
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_step_count_with_radix_hexadecimal_digits() {
+        // Arrange
+        let expected: usize = step_count("5", "7");
+
+        // Action
+        let steps: usize = step_count_with_radix("F", "F", 16);
+
+        // Assert
+        assert_eq!(expected, steps);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_json
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_json_product_cells() {
+        // Arrange
+        let multiplicand: String = String::from("9");
+        let multiplier: String = String::from("3");
+        let expected: &str = "{\"multiplicand\":\"9\",\"multiplier\":\"3\",\"product\":\"27\",\"base\":10,\
+                              \"rows\":[{\"carries\":[2],\"units\":[7]}],\
+                              \"columnSums\":[7,2],\
+                              \"subtotals\":[],\
+                              \"cells\":[\
+                              {\"row\":0,\"col\":0,\"type\":\"carry\",\"value\":2},\
+                              {\"row\":0,\"col\":1,\"type\":\"blank\",\"value\":null},\
+                              {\"row\":1,\"col\":0,\"type\":\"blank\",\"value\":null},\
+                              {\"row\":1,\"col\":1,\"type\":\"unit\",\"value\":7},\
+                              {\"row\":2,\"col\":0,\"type\":\"blank\",\"value\":null},\
+                              {\"row\":2,\"col\":1,\"type\":\"sum\",\"value\":7},\
+                              {\"row\":3,\"col\":0,\"type\":\"sum\",\"value\":2},\
+                              {\"row\":3,\"col\":1,\"type\":\"blank\",\"value\":null},\
+                              {\"row\":4,\"col\":0,\"type\":\"product\",\"value\":2},\
+                              {\"row\":4,\"col\":1,\"type\":\"product\",\"value\":7}\
+                              ]}";
+
+        // Action
+        let text: String = get_table_json(&multiplicand, &multiplier);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    #[test]
+    fn test_get_table_json_multi_digit_sum() {
+        // Arrange
+        let multiplicand: String = String::from("13");
+        let multiplier: String = String::from("26");
+
+        // Action
+        let text: String = get_table_json(&multiplicand, &multiplier);
+
+        // Assert
+        assert!(text.contains("\"product\":\"338\""));
+        assert!(text.contains("{\"row\":5,\"col\":1,\"type\":\"sum\",\"value\":1}"));
+        assert!(text.contains("{\"row\":5,\"col\":2,\"type\":\"sum\",\"value\":3}"));
+        assert!(text.contains("{\"row\":8,\"col\":3,\"type\":\"product\",\"value\":8}"));
+    }
+
+    #[test]
+    fn test_get_table_json_deserialize() {
+        // Arrange
+        let text: String = get_table_json("13", "26");
+
+        // Action
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        // Assert
+        assert_eq!("338", json["product"]);
+        assert_eq!(serde_json::json!([{"carries": [0, 1], "units": [6, 8]}, {"carries": [0, 0], "units": [2, 6]}]), json["rows"]);
+        assert_eq!(serde_json::json!([8, 13, 2, 0]), json["columnSums"]);
+        assert_eq!(serde_json::json!([[8, 3, 3, 0]]), json["subtotals"]);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_json_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_json_with_options_hexadecimal() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_json_with_options("FF", "2", &options);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        // Assert
+        assert_eq!("1FE", json["product"]);
+        assert_eq!(16, json["base"]);
+        assert_eq!(serde_json::json!([{"carries": [1, 1], "units": [14, 14]}]), json["rows"]);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_markdown
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_markdown_table() {
+        // Arrange
+        let expected: &str = "| P | 0 | 3 | 3 | 8 |\n";
+
+        // Action
+        let text: String = get_table_markdown("13", "26");
+
+        // Assert
+        assert!(text.starts_with('|'));
+        assert!(text.lines().nth(1).unwrap().starts_with("| --- |"));
+        assert!(text.contains(expected));
+        assert!(text.ends_with("**Product:** 338\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_markdown_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_markdown_with_options_hexadecimal() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_markdown_with_options("FF", "2", &options);
+
+        // Assert
+        assert!(text.contains("| 1 R |   | E | E |\n"));
+        assert!(text.ends_with("| P | 1 | F | E |\n\n**Product:** 1FE\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_tsv
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_tsv_numeric_grid() {
+        // Arrange
+        let expected: Vec<Vec<u32>> = vec![
+            vec![0, 0, 1, 2],
+            vec![0, 0, 3, 4],
+            vec![0, 0, 4, 8],
+            vec![0, 3, 6, 0],
+            vec![0, 4, 0, 8],
+        ];
+
+        // Action
+        let text: String = get_table_tsv("12", "34");
+        let grid: Vec<Vec<u32>> = text
+            .lines()
+            .map(|line| line.split('\t').map(|cell| cell.parse::<u32>().unwrap()).collect())
+            .collect();
+
+        // Assert
+        assert_eq!(expected, grid);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_tsv_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_tsv_with_options_hexadecimal() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, ..TableOptions::default() };
+        let expected: &str = "0\t0\tA\tB\n0\t0\t1\t2\n0\t1\t5\t6\n0\tA\tB\t0\n0\tC\t0\t6\n";
+
+        // Action
+        let text: String = get_table_tsv_with_options("AB", "12", &options);
+
+        // Assert
+        assert_eq!(expected, text);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_html
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_html_table() {
+        // Arrange
+        let expected: &str = "</table>\n<p>Product: 338</p>\n";
+
+        // Action
+        let text: String = get_table_html("13", "26");
+
+        // Assert
+        assert!(text.contains("<table>\n"));
+        assert!(text.ends_with(expected));
+    }
+
+    #[test]
+    fn test_get_table_html_cell_per_position() {
+        // Arrange
+        let text: String = get_table_html("13", "26");
+
+        // Action
+        let rows: Vec<&str> = text.lines().filter(|line| line.starts_with("<tr class=")).collect();
+
+        // Assert
+        assert_eq!(11, rows.len());
+        for row in rows {
+            assert_eq!(4, row.matches("<td").count());
+        }
+    }
+
+    #[test]
+    fn test_get_table_html_product_row() {
+        // Arrange
+        let expected: &str = "<tr class=\"product\"><th>P</th>\
+                              <td class=\"product\">0</td>\
+                              <td class=\"product\">3</td>\
+                              <td class=\"product\">3</td>\
+                              <td class=\"product\">8</td>\
+                              </tr>";
+
+        // Action
+        let text: String = get_table_html("13", "26");
+        let last_row: &str = text.lines().rfind(|line| line.starts_with("<tr")).unwrap();
+
+        // Assert
+        assert_eq!(expected, last_row);
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_html_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_html_with_options_subtotals() {
+        // Arrange
+        let options: TableOptions = TableOptions::default();
+
+        // Action
+        let text: String = get_table_html_with_options("99999", "99999", &options);
+
+        // Assert
+        assert!(text.contains("<tr><th colspan=\"11\">Ops.</th></tr>\n"));
+        assert!(text.contains("<tr><th colspan=\"11\">Sub 1.</th></tr>\n"));
+        assert!(text.ends_with("<p>Product: 9999800001</p>\n"));
+    }
+
+    #[test]
+    fn test_get_table_html_with_options_hexadecimal_decimals() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, decimals: (1, 0), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_html_with_options("A", "2", &options);
+
+        // Assert
+        assert!(text.contains("<h2>0.A &times; 2</h2>\n"));
+        assert!(text.contains("<td class=\"operand\">A</td>"));
+        assert!(text.ends_with("<p>Product: 1.4</p>\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_operations_only
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_operations_only_without_sum_and_product() {
+        // Arrange
+        let expected: &str = "┃   │ 2 │ 6 │   ┃ 2 R\n┗━━━┷━━━┷━━━┷━━━┛\n";
+
+        // Action
+        let text: String = get_operations_only("13", "26");
+
+        // Assert
+        assert!(text.contains("┃Ops.           ┃\n"));
+        assert!(text.contains(expected));
+        assert!(!text.contains("Sum."));
+        assert!(!text.contains("Pro."));
+        assert!(!text.contains(" C\n"));
+        assert!(!text.contains(" P\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_svg
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_svg_text_per_digit() {
+        // Arrange
+        // The carries, units, sums and product of 13 x 26.
+        let expected: usize = 4 + 4 + 5 + 4;
+
+        // Action
+        let text: String = get_table_svg("13", "26");
+
+        // Assert
+        let digits: usize = text.lines()
+            .filter(|line| line.starts_with("<text class=\"") && !line.starts_with("<text class=\"label\""))
+            .count();
+        assert_eq!(expected, digits);
+        assert!(text.contains("viewBox=\"0 0 144 240\""));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_svg_with_options
+    // # -----------------------------------------------------------------------
     #[test]
-    fn test_get_table_product_nine_digits() {
+    fn test_get_table_svg_with_options_base_sign_and_colors() {
         // Arrange
-        let multiplicand: String = String::from("13597");
-        let multiplier: String = String::from("8642");
-        let expected: &str = "Symbols\n\
-                              =======\n\
-                              Pos. = Position.\n\
-                              Ops. = Operations of the long multiplication.\n\
-                              Sum. = Sum of each column of the multiplication.\n\
-                              Sub n. = Subtotal of the last sum.\n\
-                              Pro. = Product of the multiplication.\n\
-                              n ^ = Carry-over.\n\
-                              n R = The row number.\n\
-                              n C = The column number of the sum of the rows.\n\
-                              * Replace 'n' for a number.\n\
-                              P = The product of multiplication.\n\
-                              \n\
-                              ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓\n\
-                              ┃Pos.                               ┃\n\
-                              ┠┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┬┄┄┄┨\n\
-                              ┃ 9 │ 8 │ 7 │ 6 │ 5 │ 4 │ 3 │ 2 │ 1 ┃\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Ops.                               ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃   │   │   │   │ 1 │ 3 │ 5 │ 9 │ 7 ┃\n\
-                              ┃ x │   │   │   │   │ 8 │ 6 │ 4 │ 2 ┃\n\
-                              ┣━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┿━━━┫\n\
-                              ┃   │   │   │ 0 │ 0 │ 1 │ 1 │ 1 │   ┃ 1 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 2 │ 6 │ 0 │ 8 │ 4 ┃ 1 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │   │ 0 │ 1 │ 2 │ 3 │ 2 │   │   ┃ 2 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 4 │ 2 │ 0 │ 6 │ 8 │   ┃ 2 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃   │ 0 │ 1 │ 3 │ 5 │ 4 │   │   │   ┃ 3 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 6 │ 8 │ 0 │ 4 │ 2 │   │   ┃ 3 R\n\
-                              ┠───┼───┼───┼───┼───┼───┼───┼───┼───┨\n\
-                              ┃ 0 │ 2 │ 4 │ 7 │ 5 │   │   │   │   ┃ 4 ^\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 8 │ 4 │ 0 │ 2 │ 6 │   │   │   ┃ 4 R\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Sum.                               ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃   │   │   │   │   │   │   │   │ 4 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 1 │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 1 │ 1 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │ 2 │ 4 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 1 │ 8 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 2 │ 3 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 1 │ 5 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 1 │ 0 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 0 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Sub 1.                             ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃   │   │   │   │   │   │   │   │ 4 ┃ 1 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │   │ 7 │   ┃ 2 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │   │ 2 │   │   ┃ 3 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │   │   │ 5 │   │   │   ┃ 4 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 1 │ 0 │   │   │   │   ┃ 5 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │   │ 4 │   │   │   │   │   ┃ 6 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │   │ 7 │   │   │   │   │   │   ┃ 7 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃   │ 1 │   │   │   │   │   │   │   ┃ 8 C\n\
-                              ┠┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┼┈┈┈┨\n\
-                              ┃ 1 │   │   │   │   │   │   │   │   ┃ 9 C\n\
-                              ┣━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┫\n\
-                              ┃Pro.                               ┃\n\
-                              ┣━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┫\n\
-                              ┃ 1 │ 1 │ 7 │ 5 │ 0 │ 5 │ 2 │ 7 │ 4 ┃ P\n\
-                              ┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n\
-                              \n\
-                              ---\n\
-                              Author: Israel Roldan\n\
-                              E-mail: israel.alberto.rv@gmail.com\n\
-                              License: GPL-3.0\n\
-                              Project: https://github.com/airvzxf/long-multiplication-calculator\n";
+        let colors: ColorScheme = ColorScheme { carry: "35", ..ColorScheme::default() };
+        let options: TableOptions = TableOptions { radix: 16, signs: (false, true), colors: Some(colors), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_svg_with_options("FF", "3", &options);
+
+        // Assert
+        assert!(text.contains("text.carry { fill: magenta; }\n"));
+        assert!(text.contains("<text class=\"product\" x=\"60\" y=\"156\">-2</text>\n"));
+        assert!(text.contains("<text class=\"product\" x=\"84\" y=\"156\">F</text>\n"));
+        assert!(text.contains("<text class=\"product\" x=\"108\" y=\"156\">D</text>\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_latex
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_latex_array() {
+        // Arrange
+        let expected: &str = "\\hline\n & 0 & 3 & 3 & 8 \\\\\n\\end{array}\n";
+
+        // Action
+        let text: String = get_table_latex("13", "26");
+
+        // Assert
+        assert!(text.contains("\\begin{array}{rcccc}\n"));
+        assert!(text.contains(expected));
+    }
+
+    #[test]
+    fn test_get_table_latex_carries() {
+        // Arrange
+        let expected: &str = " &  & {}^{0} & {}^{1}6 & 8 \\\\\n";
+
+        // Action
+        let text: String = get_table_latex("13", "26");
+
+        // Assert
+        assert!(text.contains(expected));
+    }
+
+    #[test]
+    fn test_get_table_latex_balanced_braces() {
+        // Arrange
+        let text: String = get_table_latex("951", "46");
+
+        // Action
+        let mut depth: i64 = 0;
+        for character in text.chars() {
+            match character {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            assert!(depth >= 0);
+        }
+
+        // Assert
+        assert_eq!(0, depth);
+        assert_eq!(text.matches("\\begin{").count(), text.matches("\\end{").count());
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_latex_with_options
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_latex_with_options_hexadecimal_negative() {
+        // Arrange
+        let options: TableOptions = TableOptions { radix: 16, signs: (false, true), ..TableOptions::default() };
+
+        // Action
+        let text: String = get_table_latex_with_options("FF", "3", &options);
+
+        // Assert
+        assert!(text.contains("\\hline\n & {}^{2} & {}^{2}D & D \\\\\n"));
+        assert!(text.contains("\\hline\n & -2 & F & D \\\\\n\\end{array}\n"));
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: get_table_literal
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_get_table_literal_python() {
+        // Arrange
+        let multiplicand: String = String::from("5");
+        let multiplier: String = String::from("7");
+        let expected: &str = "{\"a\": 5, \"b\": 7, \"product\": 35, \"rows\": [{\"units\": [5], \"carries\": [3]}]}";
+
+        // Action
+        let text: String = get_table_literal(&multiplicand, &multiplier, Literal::Python);
+
+        // Assert
+        assert_eq!(expected, text);
+        assert!(text.contains("\"product\": 35"));
+    }
+
+    #[test]
+    fn test_get_table_literal_javascript() {
+        // Arrange
+        let multiplicand: String = String::from("579");
+        let multiplier: String = String::from("48");
+        let expected: &str = "{a: 579, b: 48, product: 27792, rows: [\
+                              {units: [0, 6, 2], carries: [4, 5, 7]}, \
+                              {units: [0, 8, 6], carries: [2, 2, 3]}\
+                              ]}";
 
         // Action
-        let text: String = get_table(&multiplicand, &multiplier);
+        let text: String = get_table_literal(&multiplicand, &multiplier, Literal::JavaScript);
 
         // Assert
         assert_eq!(expected, text);
     }
 
+    // # -----------------------------------------------------------------------
+    // # Function: display
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_display_writes_the_content_as_is() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let mut out: Vec<u8> = Vec::new();
+
+        // Action
+        display(&content, &mut out).unwrap();
+
+        // Assert
+        assert_eq!(content.as_bytes(), out.as_slice());
+        assert!(!out.ends_with(b"\n\n"));
+    }
+
     // # -----------------------------------------------------------------------
     // # Function: store
     // # -----------------------------------------------------------------------
@@ -482,6 +3982,176 @@ mod tests {
         store(&expected, &file_path);
     }
 
+    #[test]
+    fn test_store_crlf_line_endings() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-03.txt");
+        let bytes: Vec<u8>;
+
+        // Action
+        store(&convert_line_endings(&content, LineEnding::Crlf), &file_path);
+
+        // Assert
+        bytes = std::fs::read(file_path).expect("Unable to read the file.");
+        let text: String = String::from_utf8(bytes).expect("Unable to decode the file.");
+        assert!(text.contains("┃ 3 │ 5 ┃ P\r\n"));
+        assert_eq!(text.matches('\n').count(), text.matches("\r\n").count());
+    }
+
+    #[test]
+    fn test_store_lf_line_endings() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-04.txt");
+        let bytes: Vec<u8>;
+
+        // Action
+        store(&convert_line_endings(&content, LineEnding::Lf), &file_path);
+
+        // Assert
+        bytes = std::fs::read(file_path).expect("Unable to read the file.");
+        let text: String = String::from_utf8(bytes).expect("Unable to decode the file.");
+        assert!(!text.contains("\r\n"));
+        assert_eq!(content, text);
+    }
+
+    #[test]
+    fn test_store_strips_ansi_sequences() {
+        // Arrange
+        let expected: String = String::from("┃ 3 │ 5 ┃ P\n");
+        let colored: String = String::from("┃ \x1b[1;32m3\x1b[0m │ \x1b[1;32m5\x1b[0m ┃ P\n");
+        let file_path: String = String::from("/tmp/test-storage-07.txt");
+        let mut file: File;
+        let mut content: String = String::new();
+
+        // Action
+        store(&colored, &file_path);
+
+        // Assert
+        file = File::open(file_path).expect("Unable to open the file.");
+        file.read_to_string(&mut content).expect("Unable to read the file.");
+        assert_eq!(expected, content);
+        assert!(!content.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_try_store_successful() {
+        // Arrange
+        let expected: String = String::from("This is a text for the content.");
+        let file_path: String = String::from("/tmp/test-storage-05.txt");
+        let mut file: File;
+        let mut content: String = String::new();
+
+        // Action
+        try_store(&expected, &file_path).expect("Unable to store the file.");
+
+        // Assert
+        file = File::open(file_path).expect("Unable to open the file.");
+        file.read_to_string(&mut content).expect("Unable to read the file.");
+        assert_eq!(expected, content);
+    }
+
+    #[test]
+    fn test_try_store_error_file() {
+        // Arrange
+        let expected: String = String::from("This is a text for the content.");
+        let file_path: String = String::from("/tmp/USER_NAME/test-storage-06.txt");
+
+        // Action
+        let result: io::Result<()> = try_store(&expected, &file_path);
+
+        // Assert
+        assert_eq!(io::ErrorKind::NotFound, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_store_with_options_bom() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-08.txt");
+        let options: StoreOptions = StoreOptions { bom: true, ..StoreOptions::default() };
+
+        // Action
+        store_with_options(&content, &file_path, &options);
+
+        // Assert
+        let bytes: Vec<u8> = std::fs::read(file_path).expect("Unable to read the file.");
+        assert_eq!(UTF8_BOM, &bytes[..3]);
+        assert_eq!(content.as_bytes(), &bytes[3..]);
+    }
+
+    #[test]
+    fn test_store_with_options_without_bom() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-09.txt");
+        let options: StoreOptions = StoreOptions::default();
+
+        // Action
+        store_with_options(&content, &file_path, &options);
+
+        // Assert
+        let bytes: Vec<u8> = std::fs::read(file_path).expect("Unable to read the file.");
+        assert_eq!(content.as_bytes(), bytes.as_slice());
+    }
+
+    #[test]
+    fn test_store_with_options_append() {
+        // Arrange
+        let first: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let second: String = get_table(&String::from("13"), &String::from("26")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-10.txt");
+        let options: StoreOptions = StoreOptions { mode: WriteMode::Append, ..StoreOptions::default() };
+        store(&String::new(), &file_path);
+
+        // Action
+        store_with_options(&first, &file_path, &options);
+        store_with_options(&second, &file_path, &options);
+
+        // Assert
+        let content: String = std::fs::read_to_string(file_path).expect("Unable to read the file.");
+        assert_eq!(format!("{first}{second}"), content);
+    }
+
+    #[test]
+    fn test_store_with_options_append_bom_once() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let file_path: String = String::from("/tmp/test-storage-11.txt");
+        let options: StoreOptions = StoreOptions { bom: true, mode: WriteMode::Append, ..StoreOptions::default() };
+        store(&String::new(), &file_path);
+
+        // Action
+        store_with_options(&content, &file_path, &options);
+        store_with_options(&content, &file_path, &options);
+
+        // Assert
+        let bytes: Vec<u8> = std::fs::read(file_path).expect("Unable to read the file.");
+        assert_eq!(UTF8_BOM, &bytes[..3]);
+        assert_eq!(format!("{content}{content}").as_bytes(), &bytes[3..]);
+    }
+
+    #[test]
+    fn test_try_store_with_options_mkdir() {
+        // Arrange
+        let content: String = get_table(&String::from("5"), &String::from("7")).unwrap();
+        let directory: std::path::PathBuf = std::env::temp_dir().join(format!("test-storage-mkdir-{}", std::process::id()));
+        let file_path: String = directory.join("out").join("nested").join("table.txt").display().to_string();
+        let options: StoreOptions = StoreOptions { mkdir: true, ..StoreOptions::default() };
+
+        // Action
+        let missing: io::Result<()> = try_store(&content, &file_path);
+        let result: io::Result<()> = try_store_with_options(&content, &file_path, &options);
+
+        // Assert
+        assert!(missing.is_err());
+        assert!(result.is_ok());
+        let stored: String = std::fs::read_to_string(&file_path).expect("Unable to read the file.");
+        assert_eq!(content, stored);
+        std::fs::remove_dir_all(directory).expect("Unable to remove the directory.");
+    }
+
     // #[test]
     // TODO: Find a way to test the error when write the content.
     // fn test_store_panic_write_content() {
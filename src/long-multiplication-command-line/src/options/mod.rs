@@ -0,0 +1,793 @@
+use crate::multiplication::MAX_DIGITS;
+
+/// The width of the cells, in characters: ` n `.
+pub const CELL_WIDTH: usize = 3;
+
+/// The width of the compact cells, in characters: `n`.
+pub const COMPACT_CELL_WIDTH: usize = 1;
+
+/// The footer appended after the table of the long multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Footer {
+    // The author, e-mail, license and project information.
+    Author,
+
+    // The lengths, product digits, subtotal rounds and table bytes.
+    Stats,
+
+    // No footer, the content ends with the bottom border.
+    None,
+}
+
+/// The line ending used when the table is stored in a file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineEnding {
+    // Line feed, `\n`.
+    Lf,
+
+    // Carriage return and line feed, `\r\n`.
+    Crlf,
+}
+
+/// How the output file is opened when the table is stored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WriteMode {
+    // Create the file, or empty it when it exists.
+    Truncate,
+
+    // Create the file, or write after its content when it exists.
+    Append,
+}
+
+/// The options to store the table of the long multiplication in a file.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::{StoreOptions, WriteMode};
+/// let options: StoreOptions = StoreOptions::default();
+///
+/// assert_eq!(false, options.bom);
+/// assert_eq!(WriteMode::Truncate, options.mode);
+/// assert_eq!(false, options.mkdir);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StoreOptions {
+    // Prepend the UTF-8 byte-order mark, for the Windows editors.
+    pub bom: bool,
+
+    // Empty the output file or append to it.
+    pub mode: WriteMode,
+
+    // Create the missing parent directories of the output file.
+    pub mkdir: bool,
+}
+
+impl Default for StoreOptions {
+    fn default() -> Self {
+        return StoreOptions {
+            bom: false,
+            mode: WriteMode::Truncate,
+            mkdir: false,
+        };
+    }
+}
+
+/// The alignment of the product row of the long multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProductAlign {
+    // Flush-left, without leading zeros and with trailing blanks.
+    Left,
+
+    // Flush-right, filled with leading zeros.
+    Right,
+}
+
+/// The language of the literal of the long multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Literal {
+    // A Python dictionary.
+    Python,
+
+    // A JavaScript object.
+    JavaScript,
+}
+
+/// The glyphs of the digits in the cells of the table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DigitSet {
+    // The ASCII digits: `0123456789`.
+    Ascii,
+
+    // The Arabic-Indic digits: `٠١٢٣٤٥٦٧٨٩`.
+    ArabicIndic,
+}
+
+impl DigitSet {
+    /// The glyph of a digit from 0 to 9.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// Example #1
+    /// ```rust
+    /// use long_multiplication_command_line::options::DigitSet;
+    ///
+    /// assert_eq!('7', DigitSet::Ascii.digit_glyph(7));
+    /// assert_eq!('٧', DigitSet::ArabicIndic.digit_glyph(7));
+    /// ```
+    pub fn digit_glyph(&self, d: u8) -> char {
+        let glyphs: [char; 10] = match self {
+            DigitSet::Ascii => ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            DigitSet::ArabicIndic => ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        };
+        return glyphs[d as usize];
+    }
+}
+
+/// The partial-product rows displayed in the operations section.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowFilter {
+    // Every row.
+    All,
+
+    // Only the odd rows: 1 R, 3 R, ...
+    Odd,
+
+    // Only the even rows: 2 R, 4 R, ...
+    Even,
+}
+
+impl RowFilter {
+    /// Check if the row number (starting at one) is displayed.
+    pub fn includes(&self, row: usize) -> bool {
+        return match self {
+            RowFilter::All => true,
+            RowFilter::Odd => !row.is_multiple_of(2),
+            RowFilter::Even => row.is_multiple_of(2),
+        };
+    }
+}
+
+/// The order of the partial-product rows in the operations section.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowOrder {
+    // The least-significant digit of the multiplier first: 1 R, 2 R, ...
+    Lsd,
+
+    // The most-significant digit of the multiplier first: ..., 2 R, 1 R.
+    Msd,
+}
+
+/// The display of the carries of the operations section.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CarryStyle {
+    // A row of carries, `n ^`, above each row of units.
+    Row,
+
+    // The carries as superscripts in the cell of the units, on the left.
+    Superscript,
+}
+
+/// The operand drawn on the first row of the multiplication block.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TopOperand {
+    // The multiplicand on top and the multiplier below the `x`.
+    Multiplicand,
+
+    // The multiplier on top and the multiplicand below the `x`.
+    Multiplier,
+}
+
+/// The rows of the sum section of the long multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SumVerbosity {
+    // One row per column with the sum of its digits: 1 C, 2 C, ...
+    Full,
+
+    // One row per place of the sums: the units, the tens, ...
+    Compact,
+}
+
+/// The validated layout of the table of the long multiplication.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::LayoutConfig;
+/// let layout: LayoutConfig = LayoutConfig::default();
+///
+/// assert_eq!(3, layout.cell_width);
+/// assert_eq!(None, layout.max_width);
+/// assert_eq!(None, layout.pad_product_to);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutConfig {
+    // The width of each cell, in characters.
+    pub cell_width: usize,
+
+    // The maximum width of the table, in characters; the longer lines are cut.
+    pub max_width: Option<usize>,
+
+    // The number of columns of the product row, and of every section to align them.
+    pub pad_product_to: Option<usize>,
+}
+
+impl LayoutConfig {
+    /// Widen the cells to hold the largest position number.
+    ///
+    /// The position numbers keep at least one blank, like ` 99`,
+    /// so the cells grow when the table has 100 columns or more.
+    /// The compact cells are not widened, their positions keep
+    /// only the last digit, as in a ruler.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// Example #1
+    /// ```rust
+    /// use long_multiplication_command_line::options::LayoutConfig;
+    /// let layout: LayoutConfig = LayoutConfig::default();
+    ///
+    /// assert_eq!(3, layout.fit_positions(99).cell_width);
+    /// assert_eq!(4, layout.fit_positions(100).cell_width);
+    /// ```
+    pub fn fit_positions(&self, columns: usize) -> LayoutConfig {
+        if self.cell_width == COMPACT_CELL_WIDTH {
+            return *self;
+        }
+
+        let position_width: usize = columns.to_string().len() + 1;
+        let layout: LayoutConfig = LayoutConfig {
+            cell_width: self.cell_width.max(position_width),
+            ..*self
+        };
+        return layout;
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        return LayoutConfig {
+            cell_width: CELL_WIDTH,
+            max_width: None,
+            pad_product_to: None,
+        };
+    }
+}
+
+/// The ANSI colors of the table displayed in a terminal.
+///
+/// Each field is the parameter of the Select Graphic Rendition
+/// sequence, like `1;33` in `ESC [ 1;33 m`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::ColorScheme;
+/// let scheme: ColorScheme = ColorScheme::default();
+///
+/// assert_eq!("1;32", scheme.product);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorScheme {
+    // The digits of the carry rows: bold yellow.
+    pub carry: &'static str,
+
+    // The digits of the product row: bold green.
+    pub product: &'static str,
+
+    // The border glyphs: dimmed.
+    pub border: &'static str,
+
+    // The cells of the highlighted column: reverse video.
+    pub highlight: &'static str,
+
+    // The position of the highlighted column, `None` for no column.
+    pub highlight_column: Option<usize>,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        return ColorScheme {
+            carry: "1;33",
+            product: "1;32",
+            border: "2",
+            highlight: "7",
+            highlight_column: None,
+        };
+    }
+}
+
+/// The glyphs of the table of the long multiplication.
+///
+/// It holds the vertical, horizontal, corner and joint glyphs
+/// of the borders. The fills of the horizontal lines are set per
+/// line type: `heavy` for the section borders, `light` between
+/// the operation rows, `carry_fill` between the carries and their
+/// row and `dotted` between the rows of the sums. `TableStyle::unicode` uses the box-drawing characters,
+/// and `TableStyle::ascii` uses only `+`, `-`, `|` and `:` for
+/// the terminals and logs that mangle them.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::TableStyle;
+/// let unicode: TableStyle = TableStyle::unicode();
+/// let ascii: TableStyle = TableStyle::ascii();
+///
+/// assert_eq!('┏', unicode.top_left);
+/// assert_eq!('+', ascii.top_left);
+/// assert_eq!(unicode, TableStyle::default());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TableStyle {
+    // The corners of the table: `┏`, `┓`, `┗` and `┛`.
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+
+    // The vertical outer border: `┃`.
+    pub outer: char,
+
+    // The vertical line between the cells: `│`.
+    pub inner: char,
+
+    // The vertical guide every third column of the product row: `╏`.
+    pub guide: char,
+
+    // The horizontal line of the section borders: `━`.
+    pub heavy: char,
+
+    // The horizontal line between the operation rows: `─`.
+    pub light: char,
+
+    // The horizontal line below the position title: `┄`.
+    pub dashed: char,
+
+    // The horizontal line between the rows of the sums: `┈`.
+    pub dotted: char,
+
+    // The horizontal line between the carries and their row: `┈`.
+    pub carry_fill: char,
+
+    // The joints of the section borders with the outer border: `┣` and `┫`.
+    pub heavy_left: char,
+    pub heavy_right: char,
+
+    // The joints of the light lines with the outer border: `┠` and `┨`.
+    pub light_left: char,
+    pub light_right: char,
+
+    // The joints of the section borders with the inner lines: `┯`, `┷` and `┿`.
+    pub heavy_down: char,
+    pub heavy_up: char,
+    pub heavy_cross: char,
+
+    // The joints of the light lines with the inner lines: `┬` and `┼`.
+    pub light_down: char,
+    pub light_cross: char,
+
+    // The diagonal of the lattice cells: `╱`.
+    pub diagonal: char,
+}
+
+impl TableStyle {
+    /// The Unicode box-drawing glyphs, the default style.
+    pub fn unicode() -> Self {
+        return TableStyle {
+            top_left: '┏',
+            top_right: '┓',
+            bottom_left: '┗',
+            bottom_right: '┛',
+            outer: '┃',
+            inner: '│',
+            guide: '╏',
+            heavy: '━',
+            light: '─',
+            dashed: '┄',
+            dotted: '┈',
+            carry_fill: '┈',
+            heavy_left: '┣',
+            heavy_right: '┫',
+            light_left: '┠',
+            light_right: '┨',
+            heavy_down: '┯',
+            heavy_up: '┷',
+            heavy_cross: '┿',
+            light_down: '┬',
+            light_cross: '┼',
+            diagonal: '╱',
+        };
+    }
+
+    /// The ASCII-only glyphs, for the terminals without box-drawing characters.
+    pub fn ascii() -> Self {
+        return TableStyle {
+            top_left: '+',
+            top_right: '+',
+            bottom_left: '+',
+            bottom_right: '+',
+            outer: '|',
+            inner: '|',
+            guide: ':',
+            heavy: '-',
+            light: '-',
+            dashed: '-',
+            dotted: '-',
+            carry_fill: '-',
+            heavy_left: '+',
+            heavy_right: '+',
+            light_left: '+',
+            light_right: '+',
+            heavy_down: '+',
+            heavy_up: '+',
+            heavy_cross: '+',
+            light_down: '+',
+            light_cross: '+',
+            diagonal: '/',
+        };
+    }
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        return TableStyle::unicode();
+    }
+}
+
+/// A line of the symbols legend, see `Labels::legend`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LegendEntry {
+    // The section titles: `Pos.`, `Ops.`, `Sum.`, `Sub n.` and `Pro.`.
+    Position,
+    Operations,
+    Sum,
+    Subtotal,
+    Product,
+
+    // The row labels: `n ^`, `n R` and `n C`.
+    Carry,
+    Row,
+    Column,
+
+    // The note of the `n` in the lines above.
+    Number,
+
+    // The label of the product row: `P`.
+    ProductRow,
+}
+
+/// The labels of the sections and the symbols legend.
+///
+/// `Labels::english` holds the original labels, and
+/// `Labels::spanish` their translation. The section titles are
+/// padded or cut to the width of the table, so the columns stay
+/// aligned whatever the length of the labels.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::Labels;
+/// let english: Labels = Labels::english();
+/// let spanish: Labels = Labels::spanish();
+///
+/// assert_eq!("Pos.", english.position);
+/// assert_eq!("Posic.", spanish.position);
+/// assert_eq!(english, Labels::default());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Labels {
+    // The section titles: `Pos.`, `Ops.`, `Sum.` and `Pro.`.
+    pub position: &'static str,
+    pub operations: &'static str,
+    pub sum: &'static str,
+    pub product: &'static str,
+
+    // The word before the number of the subtotal titles: `Sub`.
+    pub subtotal: &'static str,
+
+    // The heading of the symbols legend: `Symbols`.
+    pub symbols: &'static str,
+
+    // The lines of the symbols legend, below its heading, with their entry.
+    pub legend: [(LegendEntry, &'static str); 10],
+}
+
+impl Labels {
+    /// The English labels, the default.
+    pub fn english() -> Self {
+        return Labels {
+            position: "Pos.",
+            operations: "Ops.",
+            sum: "Sum.",
+            product: "Pro.",
+            subtotal: "Sub",
+            symbols: "Symbols",
+            legend: [
+                (LegendEntry::Position, "Pos. = Position."),
+                (LegendEntry::Operations, "Ops. = Operations of the long multiplication."),
+                (LegendEntry::Sum, "Sum. = Sum of each column of the multiplication."),
+                (LegendEntry::Subtotal, "Sub n. = Subtotal of the last sum."),
+                (LegendEntry::Product, "Pro. = Product of the multiplication."),
+                (LegendEntry::Carry, "n ^ = Carry-over."),
+                (LegendEntry::Row, "n R = The row number."),
+                (LegendEntry::Column, "n C = The column number of the sum of the rows."),
+                (LegendEntry::Number, "* Replace 'n' for a number."),
+                (LegendEntry::ProductRow, "P = The product of multiplication."),
+            ],
+        };
+    }
+
+    /// The Spanish labels.
+    pub fn spanish() -> Self {
+        return Labels {
+            position: "Posic.",
+            operations: "Oper.",
+            sum: "Suma",
+            product: "Prod.",
+            subtotal: "Subt",
+            symbols: "Símbolos",
+            legend: [
+                (LegendEntry::Position, "Posic. = Posición."),
+                (LegendEntry::Operations, "Oper. = Operaciones de la multiplicación larga."),
+                (LegendEntry::Sum, "Suma = Suma de cada columna de la multiplicación."),
+                (LegendEntry::Subtotal, "Subt n. = Subtotal de la última suma."),
+                (LegendEntry::Product, "Prod. = Producto de la multiplicación."),
+                (LegendEntry::Carry, "n ^ = Acarreo."),
+                (LegendEntry::Row, "n R = El número de renglón."),
+                (LegendEntry::Column, "n C = El número de columna de la suma de los renglones."),
+                (LegendEntry::Number, "* Reemplazar 'n' por un número."),
+                (LegendEntry::ProductRow, "P = El producto de la multiplicación."),
+            ],
+        };
+    }
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        return Labels::english();
+    }
+}
+
+/// The sections of a table that have a line in the symbols legend.
+///
+/// The legend of `generate::symbols_with_context` omits the lines
+/// of the sections that are `false`, see `LegendContext::includes`.
+/// The default has them all.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::{LegendContext, LegendEntry};
+/// let context: LegendContext = LegendContext::default();
+///
+/// assert_eq!(true, context.subtotals);
+/// assert_eq!(true, context.includes(LegendEntry::Carry));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LegendContext {
+    // The position header: `Pos.`.
+    pub position: bool,
+
+    // The sum section: `Sum.`.
+    pub sum: bool,
+
+    // The subtotal sections: `Sub n.`.
+    pub subtotals: bool,
+
+    // The rows of the sum of each column: `n C`.
+    pub columns: bool,
+
+    // The carry rows of the partial products: `n ^`.
+    pub carries: bool,
+
+    // The rows of the partial products: `n R`.
+    pub rows: bool,
+}
+
+impl LegendContext {
+    /// Check if the line of the legend entry is kept.
+    pub fn includes(&self, entry: LegendEntry) -> bool {
+        return match entry {
+            LegendEntry::Position => self.position,
+            LegendEntry::Sum => self.sum,
+            LegendEntry::Subtotal => self.subtotals,
+            LegendEntry::Carry => self.carries,
+            LegendEntry::Row => self.rows,
+            LegendEntry::Column => self.columns,
+            // The note explains the `n` of the other lines.
+            LegendEntry::Number => self.subtotals || self.carries || self.rows || self.columns,
+            LegendEntry::Operations | LegendEntry::Product | LegendEntry::ProductRow => true,
+        };
+    }
+}
+
+impl Default for LegendContext {
+    fn default() -> Self {
+        return LegendContext {
+            position: true,
+            sum: true,
+            subtotals: true,
+            columns: true,
+            carries: true,
+            rows: true,
+        };
+    }
+}
+
+/// The options to customize the table of the long multiplication.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::options::{Footer, TableOptions};
+/// let options: TableOptions = TableOptions::default();
+///
+/// assert_eq!(Footer::Author, options.footer);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableOptions {
+    // Display the symbols legend above the table.
+    pub symbols: bool,
+
+    // Keep only the legend lines of the sections in the table.
+    pub trim_symbols: bool,
+
+    // The footer appended after the table.
+    pub footer: Footer,
+
+    // The alignment of the product row.
+    pub product_align: ProductAlign,
+
+    // The validated layout of the table.
+    pub layout: LayoutConfig,
+
+    // The title centered above the table.
+    pub title: Option<String>,
+
+    // Draw a guide every third column of the product row.
+    pub group_guides: bool,
+
+    // The number of significant digits of the product annotation.
+    pub product_sig: Option<usize>,
+
+    // The partial-product rows displayed in the operations section.
+    pub rows: RowFilter,
+
+    // The order of the partial-product rows.
+    pub order: RowOrder,
+
+    // The maximum number of partial-product rows displayed, `None` for all.
+    pub max_rows: Option<usize>,
+
+    // The display of the carries.
+    pub carry_style: CarryStyle,
+
+    // The operand on the first row of the multiplication block.
+    pub top: TopOperand,
+
+    // Display the position header.
+    pub position_header: bool,
+
+    // Display the sum section.
+    pub sum_section: bool,
+
+    // The rows of the sum section.
+    pub sum: SumVerbosity,
+
+    // List the place value of each column above the table.
+    pub place_values: bool,
+
+    // Display the number of teaching steps below the table.
+    pub step_count: bool,
+
+    // Recompute the product from the partial products below the table.
+    pub verify: bool,
+
+    // List each product of the operations section below the table.
+    pub explain: bool,
+
+    // The base of the digits of the long multiplication.
+    pub radix: u32,
+
+    // The maximum digits of an operand, see `multiplication::validate_with_options`.
+    pub max_digits: usize,
+
+    // The glyphs of the table.
+    pub style: TableStyle,
+
+    // The signs of the multiplicand and the multiplier, `true` when negative.
+    pub signs: (bool, bool),
+
+    // The fractional digits of the multiplicand and the multiplier.
+    pub decimals: (usize, usize),
+
+    // Use the operand with fewer digits as the multiplier, to draw fewer rows.
+    pub optimize_layout: bool,
+
+    // The operands were swapped by `optimize_layout`, noted in the Ops. section.
+    pub swapped: bool,
+
+    // The colors of the table, `None` for the plain text.
+    pub colors: Option<ColorScheme>,
+
+    // The glyphs of the digits in the cells.
+    pub digits: DigitSet,
+
+    // The labels of the sections and the symbols legend.
+    pub labels: Labels,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        return TableOptions {
+            symbols: true,
+            trim_symbols: false,
+            footer: Footer::Author,
+            product_align: ProductAlign::Right,
+            layout: LayoutConfig::default(),
+            title: None,
+            group_guides: false,
+            product_sig: None,
+            rows: RowFilter::All,
+            order: RowOrder::Lsd,
+            max_rows: None,
+            carry_style: CarryStyle::Row,
+            top: TopOperand::Multiplicand,
+            position_header: true,
+            sum_section: true,
+            sum: SumVerbosity::Full,
+            place_values: false,
+            step_count: false,
+            verify: false,
+            explain: false,
+            radix: 10,
+            max_digits: MAX_DIGITS,
+            style: TableStyle::unicode(),
+            signs: (false, false),
+            decimals: (0, 0),
+            optimize_layout: false,
+            swapped: false,
+            colors: None,
+            digits: DigitSet::Ascii,
+            labels: Labels::english(),
+        };
+    }
+}
+
+impl TableOptions {
+    /// Get the number of fractional digits of the product.
+    ///
+    /// The product has the fractional digits of both operands,
+    /// e.g. `1.5 * 2.25` has three.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// Example #1
+    /// ```rust
+    /// use long_multiplication_command_line::options::TableOptions;
+    /// let options: TableOptions = TableOptions { decimals: (1, 2), ..TableOptions::default() };
+    ///
+    /// assert_eq!(3, options.product_decimals());
+    /// ```
+    pub fn product_decimals(&self) -> usize {
+        return self.decimals.0 + self.decimals.1;
+    }
+}
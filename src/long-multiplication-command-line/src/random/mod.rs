@@ -0,0 +1,200 @@
+/// A deterministic pseudo-random number generator.
+///
+/// It implements the SplitMix64 algorithm, so the same seed
+/// always produces the same sequence of numbers.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::random::Random;
+/// let mut random_a: Random = Random::new(42);
+/// let mut random_b: Random = Random::new(42);
+///
+/// assert_eq!(random_a.next_u64(), random_b.next_u64());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Random {
+    // The current state of the generator.
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        return Random { state: seed };
+    }
+
+    /// Get the next pseudo-random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        return z ^ (z >> 31);
+    }
+
+    /// Get the next pseudo-random digit between `min` and `max` (inclusive).
+    pub fn next_digit(&mut self, min: u64, max: u64) -> u64 {
+        return min + self.next_u64() % (max - min + 1);
+    }
+}
+
+/// Get a random number with the given number of digits.
+///
+/// The first digit is never zero, unless the number has only
+/// one digit.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::random::{Random, random_number};
+/// let mut random: Random = Random::new(7);
+/// let number: String = random_number(&mut random, 4);
+///
+/// assert_eq!(4, number.len());
+/// assert!(!number.starts_with('0'));
+/// ```
+pub fn random_number(random: &mut Random, digits: usize) -> String {
+    let mut number: String = String::with_capacity(digits);
+
+    for n in 0..digits {
+        let mut min: u64 = 0;
+        if n == 0 && digits > 1 {
+            min = 1;
+        }
+        let digit: u64 = random.next_digit(min, 9);
+        number.push_str(&digit.to_string());
+    }
+
+    return number;
+}
+
+/// Get random pairs of operands for the long multiplication.
+///
+/// It generates `count` pairs of multiplicand and multiplier
+/// with the given number of digits. The same seed always
+/// produces the same pairs.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::random::random_pairs;
+/// let pairs: Vec<(String, String)> = random_pairs(3, 2, 5, 42);
+///
+/// assert_eq!(5, pairs.len());
+/// assert_eq!(pairs, random_pairs(3, 2, 5, 42));
+/// ```
+pub fn random_pairs(multiplicand_digits: usize, multiplier_digits: usize, count: usize, seed: u64) -> Vec<(String, String)> {
+    let mut random: Random = Random::new(seed);
+
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for _ in 0..count {
+        let multiplicand: String = random_number(&mut random, multiplicand_digits);
+        let multiplier: String = random_number(&mut random, multiplier_digits);
+        pairs.push((multiplicand, multiplier));
+    }
+
+    return pairs;
+}
+
+/// Get random pairs of operands with the same number of digits.
+///
+/// It generates `count` problems of `digits` by `digits`, like
+/// the practice sheets of `--random --random-digits 3 --count 10`
+/// or `--random 3 --count 10`. The same seed always produces the
+/// same pairs.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::random::{generate_random_pairs, random_pairs};
+/// let pairs: Vec<(String, String)> = generate_random_pairs(10, 3, 42);
+///
+/// assert_eq!(10, pairs.len());
+/// assert_eq!(random_pairs(3, 3, 10, 42), pairs);
+/// ```
+pub fn generate_random_pairs(count: usize, digits: usize, seed: u64) -> Vec<(String, String)> {
+    return random_pairs(digits, digits, count, seed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // # -----------------------------------------------------------------------
+    // # Function: random_pairs
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_random_pairs_same_seed() {
+        // Arrange
+        let seed: u64 = 2024;
+
+        // Action
+        let pairs_a: Vec<(String, String)> = random_pairs(4, 3, 10, seed);
+        let pairs_b: Vec<(String, String)> = random_pairs(4, 3, 10, seed);
+
+        // Assert
+        assert_eq!(pairs_a, pairs_b);
+    }
+
+    #[test]
+    fn test_random_pairs_different_seed() {
+        // Arrange
+        let seed_a: u64 = 1;
+        let seed_b: u64 = 2;
+
+        // Action
+        let pairs_a: Vec<(String, String)> = random_pairs(6, 6, 3, seed_a);
+        let pairs_b: Vec<(String, String)> = random_pairs(6, 6, 3, seed_b);
+
+        // Assert
+        assert_ne!(pairs_a, pairs_b);
+    }
+
+    #[test]
+    fn test_random_pairs_digit_sizes() {
+        // Arrange
+        let seed: u64 = 99;
+
+        // Action
+        let pairs: Vec<(String, String)> = random_pairs(3, 2, 50, seed);
+
+        // Assert
+        for (multiplicand, multiplier) in pairs {
+            assert_eq!(3, multiplicand.len());
+            assert_eq!(2, multiplier.len());
+            assert!(!multiplicand.starts_with('0'));
+            assert!(!multiplier.starts_with('0'));
+            assert!(multiplicand.chars().all(|c| c.is_ascii_digit()));
+        }
+    }
+
+    // # -----------------------------------------------------------------------
+    // # Function: generate_random_pairs
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_generate_random_pairs_same_seed_and_digits() {
+        // Arrange
+        let seed: u64 = 7;
+
+        // Action
+        let pairs_a: Vec<(String, String)> = generate_random_pairs(10, 3, seed);
+        let pairs_b: Vec<(String, String)> = generate_random_pairs(10, 3, seed);
+
+        // Assert
+        assert_eq!(pairs_a, pairs_b);
+        assert_eq!(10, pairs_a.len());
+        for (multiplicand, multiplier) in pairs_a {
+            assert_eq!(3, multiplicand.len());
+            assert_eq!(3, multiplier.len());
+        }
+    }
+}
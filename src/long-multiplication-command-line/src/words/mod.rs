@@ -0,0 +1,160 @@
+/// The names of the numbers from zero to nineteen.
+const UNITS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+/// The names of the tens, from twenty to ninety.
+const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+/// The names of the groups of three digits, up to the decillions.
+const SCALES: [&str; 12] = [
+    "", "thousand", "million", "billion", "trillion", "quadrillion",
+    "quintillion", "sextillion", "septillion", "octillion", "nonillion", "decillion",
+];
+
+/// Get the English words of a number.
+///
+/// Given the digits of a whole number, this function returns the
+/// number written in words, in the short scale and without `and`,
+/// e.g. `338` is `three hundred thirty-eight`. The leading zeros
+/// are ignored, and the empty string is zero. It handles up to 36
+/// digits, the hundreds of decillions. Beyond that, it returns the
+/// name of each digit, e.g. `one two three ...`.
+///
+/// Examples
+/// --------
+///
+/// Example #1
+/// ```rust
+/// use long_multiplication_command_line::words::number_to_words;
+///
+/// assert_eq!("three hundred thirty-eight", number_to_words("338"));
+/// assert_eq!("twelve million five", number_to_words("12000005"));
+/// ```
+pub fn number_to_words(digits: &str) -> String {
+    let values: Vec<usize> = digits
+        .trim_start_matches('0')
+        .chars()
+        .map(|digit| digit.to_digit(10).unwrap_or(0) as usize)
+        .collect();
+
+    if values.is_empty() {
+        return String::from(UNITS[0]);
+    }
+    if values.len() > SCALES.len() * 3 {
+        let names: Vec<&str> = values.iter().map(|value| UNITS[*value]).collect();
+        return names.join(" ");
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    let first: usize = values.len() % 3;
+    let mut groups: Vec<&[usize]> = Vec::new();
+    if first > 0 {
+        groups.push(&values[..first]);
+    }
+    groups.extend(values[first..].chunks(3));
+
+    for (index, group) in groups.iter().enumerate() {
+        let value: usize = group.iter().fold(0, |total, digit| total * 10 + digit);
+        if value == 0 {
+            continue;
+        }
+        let scale: &str = SCALES[groups.len() - 1 - index];
+        words.push(hundreds_to_words(value));
+        if !scale.is_empty() {
+            words.push(scale.to_string());
+        }
+    }
+    return words.join(" ");
+}
+
+fn hundreds_to_words(value: usize) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let hundreds: usize = value / 100;
+    let rest: usize = value % 100;
+
+    if hundreds > 0 {
+        words.push(format!("{} hundred", UNITS[hundreds]));
+    }
+    match (rest / 10, rest % 10) {
+        (0, 0) => {}
+        (0 | 1, _) => words.push(UNITS[rest].to_string()),
+        (tens, 0) => words.push(TENS[tens].to_string()),
+        (tens, unit) => words.push(format!("{}-{}", TENS[tens], UNITS[unit])),
+    }
+    return words.join(" ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // # -----------------------------------------------------------------------
+    // # Function: number_to_words
+    // # -----------------------------------------------------------------------
+    #[test]
+    fn test_number_to_words_zero() {
+        // Arrange
+        let digits: &str = "0";
+        let expected: &str = "zero";
+
+        // Action
+        let words: String = number_to_words(digits);
+
+        // Assert
+        assert_eq!(expected, words);
+    }
+
+    #[test]
+    fn test_number_to_words_hundreds() {
+        // Arrange
+        let digits: &str = "338";
+        let expected: &str = "three hundred thirty-eight";
+
+        // Action
+        let words: String = number_to_words(digits);
+
+        // Assert
+        assert_eq!(expected, words);
+    }
+
+    #[test]
+    fn test_number_to_words_thousand() {
+        // Arrange
+        let digits: &str = "1000";
+        let expected: &str = "one thousand";
+
+        // Action
+        let words: String = number_to_words(digits);
+
+        // Assert
+        assert_eq!(expected, words);
+    }
+
+    #[test]
+    fn test_number_to_words_million() {
+        // Arrange
+        let digits: &str = "1000000";
+        let expected: &str = "one million";
+
+        // Action
+        let words: String = number_to_words(digits);
+
+        // Assert
+        assert_eq!(expected, words);
+    }
+
+    #[test]
+    fn test_number_to_words_twelve_digits() {
+        // Arrange
+        let digits: &str = "0999000000019";
+        let expected: &str = "nine hundred ninety-nine billion nineteen";
+
+        // Action
+        let words: String = number_to_words(digits);
+
+        // Assert
+        assert_eq!(expected, words);
+    }
+}
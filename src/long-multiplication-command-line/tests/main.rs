@@ -0,0 +1,583 @@
+use std::process::{Command, Output};
+
+const BINARY: &str = env!("CARGO_BIN_EXE_long-multiplication-command-line");
+
+// # -----------------------------------------------------------------------
+// # Binary: output
+// # -----------------------------------------------------------------------
+#[test]
+fn test_output_both_displays_when_store_fails() {
+    // Arrange
+    let file_path: &str = "/tmp/user_name/test-main-01.txt";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["5", "7", "--output", "both", "--file", file_path])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout.contains("┃ 3 │ 5 ┃ P\n"));
+    assert!(stderr.starts_with("WARNING: the file '/tmp/user_name/test-main-01.txt' cannot be stored."));
+}
+
+#[test]
+fn test_output_store_line_ending_crlf() {
+    // Arrange
+    let file_path: &str = "/tmp/test-main-02.txt";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["13", "26", "--output", "store", "--file", file_path, "--line-ending", "crlf"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let text: String = std::fs::read_to_string(file_path).expect("Unable to read the file.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(text.contains("┃ 0 │ 3 │ 3 │ 8 ┃ P\r\n"));
+    assert_eq!(text.matches('\n').count(), text.matches("\r\n").count());
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: auto-answer-threshold
+// # -----------------------------------------------------------------------
+#[test]
+fn test_auto_answer_threshold_displays_only_the_product() {
+    // Arrange
+    let multiplicand: String = "9".repeat(300);
+    let product: String = format!("{}8{}1\n", "9".repeat(299), "0".repeat(299));
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args([multiplicand.as_str(), multiplicand.as_str()])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(product, stdout);
+    assert!(stderr.starts_with("NOTE: an operand has more than 200 digits"));
+}
+
+#[test]
+fn test_auto_answer_threshold_displays_the_product_in_the_base() {
+    // Arrange
+    let expected: &str = "-AA55\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--base", "16", "--auto-answer-threshold", "1", "--", "FF", "-AB"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_auto_answer_threshold_displays_the_table() {
+    // Arrange
+    let multiplicand: &str = "1234567890";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args([multiplicand, "3"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("┃Pos."));
+    assert!(stdout.contains(" P\n"));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: signs
+// # -----------------------------------------------------------------------
+#[test]
+fn test_negative_operand_notes_the_signs() {
+    // Arrange
+    let multiplicand: &str = "-12";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args([multiplicand, "34"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("┃Ops.           ┃ (-) x (+)\n"));
+    assert!(stdout.contains("┃Pro.           ┃\n┣━━━┯━━━┯━━━┯━━━┫\n┃ - │ 4 │ 0 │ 8 ┃ P\n"));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: method
+// # -----------------------------------------------------------------------
+#[test]
+fn test_method_lattice_draws_the_grid() {
+    // Arrange
+    let expected: &str = "9 ┃╱   8│╱   8┃\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["27", "34", "--method", "lattice"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains(expected));
+    assert!(!stdout.contains("Pro."));
+}
+
+#[test]
+fn test_method_lattice_draws_the_ascii_grid_and_the_sign() {
+    // Arrange
+    let expected: &str = "= -91.8\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--method", "lattice", "--ascii", "--", "2.7", "-34"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("  +-----+-----+\n"));
+    assert!(!stdout.contains('┏'));
+    assert!(stdout.ends_with(expected));
+}
+
+#[test]
+fn test_method_peasant_sums_the_kept_rows() {
+    // Arrange
+    let expected: &str = "50 + 400 = 450\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["18", "25", "--method", "peasant"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("┃  9 │  50 ┃ +\n"));
+    assert!(stdout.ends_with(expected));
+}
+
+#[test]
+fn test_method_plain_has_no_grid() {
+    // Arrange
+    let expected: &str = " 25\n× 3\n---\n 75\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["25", "3", "--method", "plain"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_method_plain_rejects_the_decimal_operands() {
+    // Arrange
+    let expected: &str = "ERROR: the 'plain' method only draws whole numbers without a sign.\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["1.5", "7", "--method", "plain"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(2), output.status.code());
+    assert_eq!(expected, stderr);
+}
+
+#[test]
+fn test_method_karatsuba_recombines_the_sub_products() {
+    // Arrange
+    let expected: &str = "= 7006652\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["1234", "5678", "--method", "karatsuba"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("(a + b)(c + d) = 46 x 134 = 6164\n"));
+    assert!(stdout.ends_with(expected));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: quiet
+// # -----------------------------------------------------------------------
+#[test]
+fn test_quiet_displays_only_the_product() {
+    // Arrange
+    let expected: &str = "144\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["12", "12", "--quiet"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_quiet_displays_the_product_in_the_base() {
+    // Arrange
+    let expected: &str = "1001\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["11", "11", "--base", "2", "--quiet"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_quiet_displays_the_sign_of_the_product() {
+    // Arrange
+    let expected: &str = "-36\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--quiet", "--", "-12", "3"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_quiet_stores_only_the_product() {
+    // Arrange
+    let file_path: &str = "/tmp/test-main-quiet-01.txt";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["12", "12", "-q", "--output", "store", "--file", file_path])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let content: String = std::fs::read_to_string(file_path).expect("Unable to read the file.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(output.stdout.is_empty());
+    assert_eq!("144\n", content);
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: batch
+// # -----------------------------------------------------------------------
+#[test]
+fn test_batch_displays_each_table() {
+    // Arrange
+    let file_path: &str = "/tmp/test-main-batch-01.txt";
+    std::fs::write(file_path, "5 7\n13 26\n123 45\n").expect("Unable to write the file.");
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--batch", file_path])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(3, stdout.matches("┃Pro.").count());
+    assert!(stdout.contains("┃ 3 │ 5 ┃ P\n"));
+    assert!(stdout.contains("┃ 0 │ 3 │ 3 │ 8 ┃ P\n"));
+    assert!(stdout.contains("┃ 0 │ 5 │ 5 │ 3 │ 5 ┃ P\n"));
+}
+
+#[test]
+fn test_batch_skips_the_malformed_lines() {
+    // Arrange
+    let file_path: &str = "/tmp/test-main-batch-02.txt";
+    std::fs::write(file_path, "5 7\n1 2 3\n12a 3\n").expect("Unable to write the file.");
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--batch", file_path])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(1, stdout.matches("┃Pro.").count());
+    assert!(stderr.contains("WARNING: the line 2 of the batch file is skipped."));
+    assert!(stderr.contains("WARNING: the line 3 of the batch file is skipped."));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: operands
+// # -----------------------------------------------------------------------
+#[test]
+fn test_operands_with_thousands_separators() {
+    // Arrange
+    let expected: &str = "12340\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["1,234", "1_0", "--quiet"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(expected, stdout);
+}
+
+#[test]
+fn test_operands_chain_of_three() {
+    // Arrange
+    let expected: &str = "┃ 2 │ 4 ┃ P\n┗━━━┷━━━┛\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["2", "3", "4", "--no-author"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(2, stdout.matches("┃Pos.").count());
+    assert!(stdout.contains("Step 2: 6 × 4 = 24\n"));
+    assert!(stdout.ends_with(expected));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: format
+// # -----------------------------------------------------------------------
+#[test]
+fn test_format_tsv_rejects_the_signed_operands() {
+    // Arrange
+    let expected: &str = "ERROR: the 'tsv' format only draws whole numbers without a sign.\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--format", "tsv", "--", "12", "-34"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(2), output.status.code());
+    assert_eq!(expected, stderr);
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: lang
+// # -----------------------------------------------------------------------
+#[test]
+fn test_lang_spanish_keeps_the_table_aligned() {
+    // Arrange
+    let expected: &str = "Símbolos\n========\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["5", "7", "--lang", "es", "--no-author"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.starts_with(expected));
+    assert!(stdout.contains("┏━━━━━━━┓\n┃Posic. ┃\n┠┄┄┄┬┄┄┄┨\n"));
+    assert!(!stdout.contains("┃Pos."));
+    assert!(stdout.contains("┃Prod.  ┃\n"));
+
+    // The right border of every line is below the corner of the top border.
+    let table: Vec<&str> = stdout.lines().filter(|line| line.starts_with(['┏', '┃', '┠', '┣', '┗'])).collect();
+    for line in table {
+        let right_border: Option<char> = line.chars().nth(8);
+        assert!(right_border.is_some_and(|character| "┓┃┨┫┛".contains(character)), "{line}");
+    }
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: whitespace
+// # -----------------------------------------------------------------------
+#[test]
+fn test_whitespace_no_line_ends_with_a_space() {
+    // Arrange
+    let cases: [&[&str]; 5] = [
+        &["13597", "8642"],
+        &["13", "26", "--ascii", "--title", "Title"],
+        &["13", "26", "--lang", "es", "--trim-symbols"],
+        &["13", "26", "--stats-footer", "--verify", "--step-count", "--explain"],
+        &["13", "26", "--method", "plain"],
+    ];
+
+    for args in cases {
+        // Action
+        let output: Output = Command::new(BINARY)
+            .args(args)
+            .output()
+            .expect("Unable to run the binary.");
+
+        // Assert
+        let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+        assert_eq!(Some(0), output.status.code());
+        for line in stdout.lines() {
+            assert!(!line.ends_with(char::is_whitespace), "{args:?}: '{line}'");
+        }
+    }
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: highlight-column
+// # -----------------------------------------------------------------------
+#[test]
+fn test_highlight_column_never_keeps_the_layout() {
+    // Arrange
+    let plain: Output = Command::new(BINARY)
+        .args(["13", "26", "--color", "never"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Action
+    let highlighted: Output = Command::new(BINARY)
+        .args(["13", "26", "--color", "never", "--highlight-column", "2"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    assert_eq!(Some(0), highlighted.status.code());
+    assert_eq!(plain.stdout, highlighted.stdout);
+}
+
+#[test]
+fn test_highlight_column_always_tints_only_the_column() {
+    // Arrange
+    let expected: usize = 12;
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["13", "26", "--color", "always", "--highlight-column", "2", "--no-symbols", "--no-author"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    for line in stdout.lines().filter(|line| line.contains("\x1b[7m")) {
+        // The tinted cell is the third one of the row, the position 2 of 4.
+        let cells: Vec<&str> = line.split("\x1b[2m│\x1b[0m").collect();
+        assert_eq!(4, cells.len(), "{line}");
+        assert!(cells[2].starts_with("\x1b[7m") && cells[2].ends_with("\x1b[0m"), "{line}");
+        assert_eq!(1, line.matches("\x1b[7m").count(), "{line}");
+    }
+    assert_eq!(expected, stdout.matches("\x1b[7m").count());
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: words
+// # -----------------------------------------------------------------------
+#[test]
+fn test_words_appends_the_product_in_words() {
+    // Arrange
+    let expected: &str = "Product in words: three hundred thirty-eight\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["13", "26", "--words", "--color", "never"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert!(stdout.contains("┃ 0 │ 3 │ 3 │ 8 ┃ P"));
+    assert!(stdout.ends_with(expected));
+}
+
+// # -----------------------------------------------------------------------
+// # Binary: random
+// # -----------------------------------------------------------------------
+#[test]
+fn test_random_without_a_value_uses_the_random_digits() {
+    // Arrange
+    let args: [&str; 7] = ["--random", "--count", "10", "--random-digits", "3", "--seed", "42"];
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(args)
+        .output()
+        .expect("Unable to run the binary.");
+    let again: Output = Command::new(BINARY)
+        .args(args)
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stdout: String = String::from_utf8(output.stdout).expect("Unable to decode the output.");
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(stdout.as_bytes(), again.stdout.as_slice());
+    let problems: Vec<&str> = stdout.lines().filter(|line| line.starts_with("Problem ")).collect();
+    assert_eq!(10, problems.len());
+    for problem in problems {
+        let (_, operands): (&str, &str) = problem.split_once(": ").unwrap();
+        let (multiplicand, multiplier): (&str, &str) = operands.split_once(" x ").unwrap();
+        assert_eq!((3, 3), (multiplicand.len(), multiplier.len()), "{problem}");
+    }
+}
+
+#[test]
+fn test_random_rejects_the_digits_beyond_the_max_digits() {
+    // Arrange
+    let expected: &str = "ERROR: operand exceeds 500 digits (got 600).\n";
+
+    // Action
+    let output: Output = Command::new(BINARY)
+        .args(["--random", "600x1", "--seed", "1"])
+        .output()
+        .expect("Unable to run the binary.");
+
+    // Assert
+    let stderr: String = String::from_utf8(output.stderr).expect("Unable to decode the error.");
+    assert_eq!(Some(2), output.status.code());
+    assert_eq!(expected, stderr);
+}